@@ -1,103 +1,301 @@
 use std::env;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-const DLSS_LIBRARY_PATH: &str = "DLSS/lib/Linux_x86_64";
+/// The environment variables which may point at the root directory of
+/// the NGX (DLSS) SDK, checked in this order.
+const SDK_PATH_ENVIRONMENT_VARIABLES: &[&str] = &["NGX_SDK_PATH", "DLSS_SDK_DIR"];
+/// The conventional locations of the SDK root directory, checked when
+/// none of the [`SDK_PATH_ENVIRONMENT_VARIABLES`] is set. Relative paths
+/// are relative to the crate root.
+const SDK_PATH_PREFIXES: &[&str] = &[
+    "DLSS",
+    "/usr/local/DLSS",
+    "/usr/local/share/DLSS",
+    "/usr/share/DLSS",
+    "/opt/DLSS",
+    "/opt/nvidia/DLSS",
+];
+/// The header which must exist for a directory to be the SDK root.
+const SDK_HEADER_FILE_PATH: &str = "include/nvsdk_ngx_vk.h";
+#[cfg(feature = "generate-bindings")]
+const SDK_INCLUDE_PATH: &str = "include";
+const SDK_LIBRARY_PATH: &str = "lib/Linux_x86_64";
+#[cfg(feature = "generate-bindings")]
 const HEADER_FILE_PATH: &str = "src/bindings.h";
-const SOURCE_FILE_PATH: &str = "src/bindings.c";
-
-fn library_path() -> String {
-    // let path = match DLSS_LIBRARY_TYPE {
-    //     DlssLibraryType::Development => "dev",
-    //     DlssLibraryType::Release => "rel",
-    // };
-    // let path = format!("{DLSS_LIBRARY_PATH}/{path}/");
-    let path = DLSS_LIBRARY_PATH.to_owned();
-    PathBuf::from(path)
-        .canonicalize()
-        .expect("cannot canonicalize path")
-        .to_str()
-        .unwrap()
-        .to_owned()
+/// The bindings generated from the headers of the SDK pinned by the
+/// `DLSS` git submodule, used unless the `generate-bindings` feature is
+/// enabled.
+const PREGENERATED_BINDINGS_FILE_PATH: &str = "src/bindings.rs";
+/// The file in `$OUT_DIR` with the typed keys of the parameters,
+/// generated from the bindings.
+const PARAMETER_KEYS_FILE_NAME: &str = "parameter_keys.rs";
+/// The prefix of the parameter names in the bindings.
+const PARAMETER_NAME_PREFIX: &str = "NVSDK_NGX_Parameter_";
+/// The types of the values of the parameters, by the name without the
+/// [`PARAMETER_NAME_PREFIX`]. The headers only declare the names, so the
+/// types are the ones the SDK helpers use to set them.
+const PARAMETER_TYPES: &[(&str, &str)] = &[
+    ("Hint_UseFireflySwatter", "bool"),
+    ("FreeMemOnReleaseFeature", "bool"),
+    ("IsDevSnippetBranch", "bool"),
+    ("DLSS_Enable_Output_Subrects", "bool"),
+    ("Reset", "i32"),
+    ("PerfQualityValue", "i32"),
+    ("RTXValue", "i32"),
+    ("DLSSMode", "i32"),
+    ("Denoise", "i32"),
+    ("DLSS_Feature_Create_Flags", "i32"),
+    ("DLSS_Checkerboard_Jitter_Hack", "i32"),
+    ("DLSS_Indicator_Invert_X_Axis", "i32"),
+    ("DLSS_Indicator_Invert_Y_Axis", "i32"),
+    ("DLSS_Denoise_Mode", "i32"),
+    ("DLSS_Roughness_Mode", "i32"),
+    ("DLSS_Use_HW_Depth", "i32"),
+    ("Sharpness", "f32"),
+    ("BlendFactor", "f32"),
+    ("MV_Scale_X", "f32"),
+    ("MV_Scale_Y", "f32"),
+    ("MV_Offset_X", "f32"),
+    ("MV_Offset_Y", "f32"),
+    ("Jitter_Offset_X", "f32"),
+    ("Jitter_Offset_Y", "f32"),
+    ("FrameTimeDeltaInMsec", "f32"),
+    ("DLSS_Pre_Exposure", "f32"),
+    ("DLSS_Exposure_Scale", "f32"),
+    ("DLSS_SuperSampling_ScaleFactor", "f32"),
+    ("SuperSampling_ScaleFactor", "f32"),
+    ("OptLevel", "u32"),
+    ("Width", "u32"),
+    ("Height", "u32"),
+    ("OutWidth", "u32"),
+    ("OutHeight", "u32"),
+    ("Scale", "u32"),
+    ("NumFrames", "u32"),
+    ("Model", "u32"),
+    ("Format", "u32"),
+    ("Input1_Format", "u32"),
+    ("Input2_Format", "u32"),
+    ("Color_Format", "u32"),
+    ("Rect_X", "u32"),
+    ("Rect_Y", "u32"),
+    ("Rect_W", "u32"),
+    ("Rect_H", "u32"),
+    ("CreationNodeMask", "u32"),
+    ("VisibilityNodeMask", "u32"),
+    ("Resource_Width", "u32"),
+    ("Resource_Height", "u32"),
+    ("TonemapperType", "u32"),
+    ("ImageSignalProcessing_ScaleFactor", "u32"),
+    ("DeepDVC_Strength", "u32"),
+    ("FI_Mode", "u32"),
+    ("FI_OF_Preset", "u32"),
+    ("FI_OF_GridSize", "u32"),
+    ("DLSS_Render_Subrect_Dimensions_Width", "u32"),
+    ("DLSS_Render_Subrect_Dimensions_Height", "u32"),
+    ("Scratch", POINTER_PARAMETER_TYPE),
+    ("Input1", POINTER_PARAMETER_TYPE),
+    ("Input2", POINTER_PARAMETER_TYPE),
+    ("Color", POINTER_PARAMETER_TYPE),
+    ("FI_Color1", POINTER_PARAMETER_TYPE),
+    ("FI_Color2", POINTER_PARAMETER_TYPE),
+    ("FI_Output1", POINTER_PARAMETER_TYPE),
+    ("FI_Output2", POINTER_PARAMETER_TYPE),
+    ("FI_Output3", POINTER_PARAMETER_TYPE),
+    ("FI_MotionVectors1", POINTER_PARAMETER_TYPE),
+    ("FI_MotionVectors2", POINTER_PARAMETER_TYPE),
+    ("FI_Depth1", POINTER_PARAMETER_TYPE),
+    ("FI_Depth2", POINTER_PARAMETER_TYPE),
+    ("Albedo", POINTER_PARAMETER_TYPE),
+    ("Output", POINTER_PARAMETER_TYPE),
+    ("MotionVectors", POINTER_PARAMETER_TYPE),
+    ("Depth", POINTER_PARAMETER_TYPE),
+    ("TransparencyMask", POINTER_PARAMETER_TYPE),
+    ("ExposureTexture", POINTER_PARAMETER_TYPE),
+    ("MotionVectors3D", POINTER_PARAMETER_TYPE),
+    ("IsParticleMask", POINTER_PARAMETER_TYPE),
+    ("AnimatedTextureMask", POINTER_PARAMETER_TYPE),
+    ("DepthHighRes", POINTER_PARAMETER_TYPE),
+    ("Position_ViewSpace", POINTER_PARAMETER_TYPE),
+    ("RayTracingHitDistance", POINTER_PARAMETER_TYPE),
+    ("MotionVectorsReflection", POINTER_PARAMETER_TYPE),
+    ("ResourceAllocCallback", POINTER_PARAMETER_TYPE),
+    ("BufferAllocCallback", POINTER_PARAMETER_TYPE),
+    ("Tex2DAllocCallback", POINTER_PARAMETER_TYPE),
+    ("ResourceReleaseCallback", POINTER_PARAMETER_TYPE),
+    ("DLSSOptimalSettingsCallback", POINTER_PARAMETER_TYPE),
+    ("DLSSGetStatsCallback", POINTER_PARAMETER_TYPE),
+    ("DLSSDOptimalSettingsCallback", POINTER_PARAMETER_TYPE),
+    ("DLSSDGetStatsCallback", POINTER_PARAMETER_TYPE),
+    ("DLSS_Input_Bias_Current_Color_Mask", POINTER_PARAMETER_TYPE),
+    ("DLSS_WorldToViewMatrix", POINTER_PARAMETER_TYPE),
+    ("DLSS_ViewToClipMatrix", POINTER_PARAMETER_TYPE),
+    ("DLSS_INV_VIEW_PROJECTION_MATRIX", POINTER_PARAMETER_TYPE),
+    ("DLSS_CLIP_TO_PREV_CLIP_MATRIX", POINTER_PARAMETER_TYPE),
+    ("DLSS_DisocclusionMask", POINTER_PARAMETER_TYPE),
+    ("DLSS_TransparencyLayer", POINTER_PARAMETER_TYPE),
+    ("DLSS_TransparencyLayerOpacity", POINTER_PARAMETER_TYPE),
+    ("DLSS_TransparencyLayerMvecs", POINTER_PARAMETER_TYPE),
+    ("DLSS_Input_Normals", POINTER_PARAMETER_TYPE),
+    ("DLSS_Input_DiffuseAlbedo", POINTER_PARAMETER_TYPE),
+    ("DLSS_Input_SpecularAlbedo", POINTER_PARAMETER_TYPE),
+    ("DLSS_Input_Roughness", POINTER_PARAMETER_TYPE),
+];
+/// The types of the values of the parameters not in the
+/// [`PARAMETER_TYPES`], by the prefix and the suffix of the name. The
+/// names matching none of them are reported with a warning, see
+/// [`UNKNOWN_PARAMETER_TYPE`].
+const PARAMETER_TYPE_PATTERNS: &[(&str, &str, &str)] = &[
+    ("", "_Available", "bool"),
+    ("", "_NeedsUpdatedDriver", "bool"),
+    ("", "_FeatureInitResult", "i32"),
+    ("", "_MinDriverVersionMajor", "u32"),
+    ("", "_MinDriverVersionMinor", "u32"),
+    ("", "SizeInBytes", "u64"),
+    ("", "Base_X", "u32"),
+    ("", "Base_Y", "u32"),
+    ("ImageSuperResolution_ScaleFactor_", "", "u32"),
+    ("DLSS_Get_Dynamic_", "", "u32"),
+    ("DLSS_Hint_Render_Preset_", "", "u32"),
+    ("GBuffer_", "", POINTER_PARAMETER_TYPE),
+];
+/// The type of the parameters in neither the [`PARAMETER_TYPES`] nor
+/// the [`PARAMETER_TYPE_PATTERNS`], most likely added by a newer SDK.
+const UNKNOWN_PARAMETER_TYPE: &str = "u32";
+/// The type of the parameters with resources, buffers and callbacks.
+const POINTER_PARAMETER_TYPE: &str = "*mut c_void";
+/// The environment variable selecting the [`DlssLibraryType`].
+const DLSS_LIBRARY_TYPE_ENVIRONMENT_VARIABLE: &str = "NVNGX_DLSS_LIBRARY_TYPE";
+/// If set while generating the bindings, the generated bindings
+/// overwrite the [`PREGENERATED_BINDINGS_FILE_PATH`].
+const UPDATE_BINDINGS_ENVIRONMENT_VARIABLE: &str = "NVNGX_UPDATE_BINDINGS";
+
+/// The flavour of the DLSS snippet libraries shipped with the SDK.
+#[derive(Debug, Copy, Clone)]
+enum DlssLibraryType {
+    /// The snippets with the on-screen indicator and verbose logging,
+    /// for the development.
+    Development,
+    /// The snippets to ship.
+    Release,
 }
 
-fn compile_helpers() {
-    // This is the directory where the `c` library is located.
-    let libdir_path = PathBuf::from("./")
-        // Canonicalize the path as `rustc-link-search` requires an absolute
-        // path.
-        .canonicalize()
-        .expect("cannot canonicalize path");
-    // This is the path to the intermediate object file for our library.
-    let obj_path = libdir_path.join("target/ngx_helpers.o");
-    // This is the path to the static library file.
-    let lib_path = libdir_path.join("target/libngx_helpers.a");
-
-    let git_submodule_update_job = std::process::Command::new("git")
-        .arg("submodule")
-        .arg("update")
-        .arg("--init")
-        .arg("--recursive")
-        .arg("--depth")
-        .arg("1")
-        .output()
-        .expect("run git successfully");
-
-    if !git_submodule_update_job.status.success() {
-        let stdout = String::from_utf8(git_submodule_update_job.stdout).unwrap();
-        let stderr = String::from_utf8(git_submodule_update_job.stderr).unwrap();
-        panic!("could not checkout the submodules.\nStdout:\n{stdout}\n\nStderr:\n{stderr}");
-    }
+impl DlssLibraryType {
+    /// Returns the type set in the
+    /// [`DLSS_LIBRARY_TYPE_ENVIRONMENT_VARIABLE`], which is the release
+    /// one if the variable isn't set.
+    fn from_environment() -> Self {
+        println!("cargo:rerun-if-env-changed={DLSS_LIBRARY_TYPE_ENVIRONMENT_VARIABLE}");
 
-    // Run `clang` to compile the source code file into an object file.
-    let compile_job = std::process::Command::new("clang")
-        .arg("-g")
-        .arg("-G0")
-        .arg("-c")
-        .arg("-o")
-        .arg(&obj_path)
-        .arg(libdir_path.join(SOURCE_FILE_PATH))
-        .output()
-        .expect("compile using `clang`");
-
-    if !compile_job.status.success() {
-        let stdout = String::from_utf8(compile_job.stdout).unwrap();
-        let stderr = String::from_utf8(compile_job.stderr).unwrap();
-        panic!("could not compile object file.\nStdout:\n{stdout}\n\nStderr:\n{stderr}");
+        let Some(value) = env::var_os(DLSS_LIBRARY_TYPE_ENVIRONMENT_VARIABLE) else {
+            return Self::Release;
+        };
+        match value.to_string_lossy().to_lowercase().as_str() {
+            "development" | "dev" => Self::Development,
+            "release" | "rel" => Self::Release,
+            _ => panic!(
+                "{DLSS_LIBRARY_TYPE_ENVIRONMENT_VARIABLE} must be either \"development\" or \"release\", not {value:?}."
+            ),
+        }
     }
 
-    // Run `ar` to generate the static library.
-    if !std::process::Command::new("ar")
-        .arg("rcs")
-        .arg(lib_path)
-        .arg(obj_path)
-        .output()
-        .expect("could not spawn `ar`")
-        .status
-        .success()
-    {
-        // Panic if the command was not successful.
-        panic!("could not emit library file");
+    /// Returns the name the crate knows the type by.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Development => "development",
+            Self::Release => "release",
+        }
     }
+}
 
-    // Link against the built helpers wrapper.
-    println!(
-        "cargo:rustc-link-search={}",
-        libdir_path.join("target/").to_str().unwrap()
-    );
-    println!("cargo:rustc-link-lib=ngx_helpers");
+/// The located NGX SDK.
+#[derive(Debug, Clone)]
+struct Sdk {
+    /// The directory with the SDK headers.
+    #[cfg(feature = "generate-bindings")]
+    include_path: PathBuf,
+    /// The directory with the SDK libraries.
+    library_path: PathBuf,
 }
 
-fn main() {
-    compile_helpers();
+impl Sdk {
+    /// Returns the SDK rooted at `root`, if it has the headers.
+    fn from_root(root: &Path) -> Option<Self> {
+        if !root.join(SDK_HEADER_FILE_PATH).is_file() {
+            return None;
+        }
 
-    // Tell cargo to look for shared libraries in the specified directory
-    println!("cargo:rustc-link-search={}", library_path());
+        let root = root.canonicalize().ok()?;
+        Some(Self {
+            #[cfg(feature = "generate-bindings")]
+            include_path: root.join(SDK_INCLUDE_PATH),
+            library_path: root.join(SDK_LIBRARY_PATH),
+        })
+    }
 
-    // Tell cargo to tell rustc to link to the libraries.
-    println!("cargo:rustc-link-lib=nvsdk_ngx");
-    println!("cargo:rustc-link-lib=stdc++");
-    println!("cargo:rustc-link-lib=dl");
+    /// Locates the SDK: first through the
+    /// [`SDK_PATH_ENVIRONMENT_VARIABLES`], then in the
+    /// [`SDK_PATH_PREFIXES`]. The error names every path tried.
+    fn locate() -> Result<Self, String> {
+        for variable in SDK_PATH_ENVIRONMENT_VARIABLES {
+            println!("cargo:rerun-if-env-changed={variable}");
+        }
+
+        let mut tried = Vec::new();
 
+        for variable in SDK_PATH_ENVIRONMENT_VARIABLES {
+            if let Some(root) = env::var_os(variable) {
+                let root = PathBuf::from(root);
+                // An explicitly set path is never ignored in favour of
+                // the conventional ones.
+                return Self::from_root(&root).ok_or_else(|| {
+                    format!(
+                        "The NGX SDK wasn't found at {} set in {variable}: there is no {}.",
+                        root.display(),
+                        root.join(SDK_HEADER_FILE_PATH).display()
+                    )
+                });
+            }
+            tried.push(format!("${variable} (not set)"));
+        }
+
+        for prefix in SDK_PATH_PREFIXES {
+            let root = PathBuf::from(prefix);
+            if let Some(sdk) = Self::from_root(&root) {
+                return Ok(sdk);
+            }
+            tried.push(root.display().to_string());
+        }
+
+        Err(format!(
+            "Couldn't locate the NGX SDK. Set {} to the SDK root (the directory containing {}). Tried: {}.",
+            SDK_PATH_ENVIRONMENT_VARIABLES.join(" or "),
+            SDK_HEADER_FILE_PATH,
+            tried.join(", ")
+        ))
+    }
+
+    fn library_path(&self) -> String {
+        self.library_path.to_str().unwrap().to_owned()
+    }
+
+    /// Returns the argument for the compiler to search for the SDK
+    /// headers.
+    #[cfg(feature = "generate-bindings")]
+    fn include_argument(&self) -> String {
+        format!("-I{}", self.include_path.display())
+    }
+}
+
+/// Returns `true` if the NGX library is linked, rather than replaced
+/// with the fake one (the `mock` feature). The `detect-driver` feature
+/// links it too, as the SDK only ships the static library.
+fn is_library_linked() -> bool {
+    env::var_os("CARGO_FEATURE_MOCK").is_none()
+}
+
+/// Generates the bindings from the SDK headers into `$OUT_DIR`.
+#[cfg(feature = "generate-bindings")]
+fn generate_bindings(sdk: &Sdk) {
     // Tell cargo to invalidate the built crate whenever the wrapper changes
     println!("cargo:rerun-if-changed={HEADER_FILE_PATH}");
 
@@ -108,6 +306,8 @@ fn main() {
         // The input header we would like to generate
         // bindings for.
         .header(HEADER_FILE_PATH)
+        // The SDK headers are included by their names.
+        .clang_arg(sdk.include_argument())
         // Tell cargo to invalidate the built crate whenever any of the
         // included header files changed.
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
@@ -116,6 +316,11 @@ fn main() {
         .prepend_enum_name(false)
         .generate_inline_functions(true)
         .bitfield_enum("NVSDK_NGX_DLSS_Feature_Flags")
+        // The values returned by the driver may be unknown to the
+        // headers, which is undefined behaviour for a Rust enum.
+        .newtype_enum("NVSDK_NGX_Result")
+        .newtype_enum("NVSDK_NGX_Feature")
+        .bitfield_enum("NVSDK_NGX_Feature_Support_Result")
         // .generate_cstr(true)
         // .bitfield_enum("NVSDK_NGX_DLSS_Feature_Flags")
         // .bitfield_enum("NVSDK_NGX_Result")
@@ -130,8 +335,136 @@ fn main() {
         .expect("Unable to generate bindings");
 
     // Write the bindings to the $OUT_DIR/bindings.rs file.
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("bindings.rs");
     bindings
-        .write_to_file(out_path.join("bindings.rs"))
+        .write_to_file(&out_path)
         .expect("Couldn't write bindings!");
+
+    println!("cargo:rerun-if-env-changed={UPDATE_BINDINGS_ENVIRONMENT_VARIABLE}");
+    if env::var_os(UPDATE_BINDINGS_ENVIRONMENT_VARIABLE).is_some() {
+        std::fs::copy(&out_path, PREGENERATED_BINDINGS_FILE_PATH)
+            .expect("Couldn't update the pre-generated bindings!");
+    } else if std::fs::read(&out_path).ok() != std::fs::read(PREGENERATED_BINDINGS_FILE_PATH).ok() {
+        println!(
+            "cargo:warning={PREGENERATED_BINDINGS_FILE_PATH} differs from the bindings generated from the SDK headers. Set {UPDATE_BINDINGS_ENVIRONMENT_VARIABLE}=1 to update it."
+        );
+    }
+}
+
+/// Returns the type of the value of the parameter `name` (without the
+/// [`PARAMETER_NAME_PREFIX`]), if it is known.
+fn parameter_type(name: &str) -> Option<&'static str> {
+    if let Some((_, typ)) = PARAMETER_TYPES.iter().find(|(n, _)| *n == name) {
+        return Some(typ);
+    }
+    PARAMETER_TYPE_PATTERNS
+        .iter()
+        .find(|(prefix, suffix, _)| name.starts_with(prefix) && name.ends_with(suffix))
+        .map(|(_, _, typ)| *typ)
+}
+
+/// Generates the typed keys of every parameter name declared in the
+/// `bindings` into the [`PARAMETER_KEYS_FILE_NAME`] in `$OUT_DIR`, as
+/// an invocation of the `parameter_keys!` macro of the crate.
+fn generate_parameter_keys(bindings: &Path) {
+    let bindings = std::fs::read_to_string(bindings).expect("Couldn't read the bindings!");
+    let declaration = format!("pub const {PARAMETER_NAME_PREFIX}");
+
+    let mut keys = String::from("parameter_keys! {\n");
+    for (start, _) in bindings.match_indices(&declaration) {
+        let declaration = &bindings[start + "pub const ".len()..];
+        let Some((name, rest)) = declaration.split_once(':') else {
+            continue;
+        };
+        // Only the names are byte strings, the rest of the constants
+        // with the prefix, if any, aren't parameters.
+        if !rest.trim_start().starts_with("&[u8;") {
+            continue;
+        }
+        let typ = parameter_type(&name[PARAMETER_NAME_PREFIX.len()..]).unwrap_or_else(|| {
+            println!(
+                "cargo:warning={name} has no type in the PARAMETER_TYPES of build.rs, its key is typed as {UNKNOWN_PARAMETER_TYPE}."
+            );
+            UNKNOWN_PARAMETER_TYPE
+        });
+        keys.push_str(&format!("    {name}: {typ},\n"));
+    }
+    keys.push_str("}\n");
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join(PARAMETER_KEYS_FILE_NAME);
+    std::fs::write(out_path, keys).expect("Couldn't write the parameter keys!");
+}
+
+/// Makes sure the pre-generated bindings are there.
+#[cfg(not(feature = "generate-bindings"))]
+fn check_pregenerated_bindings() {
+    println!("cargo:rerun-if-changed={PREGENERATED_BINDINGS_FILE_PATH}");
+
+    if !std::path::Path::new(PREGENERATED_BINDINGS_FILE_PATH).is_file() {
+        panic!(
+            "{PREGENERATED_BINDINGS_FILE_PATH} is missing. Generate it with `{UPDATE_BINDINGS_ENVIRONMENT_VARIABLE}=1 cargo build --features generate-bindings`."
+        );
+    }
+}
+
+fn main() {
+    // docs.rs has neither the SDK nor the NGX library, and none of those
+    // is needed to document the crate with the pre-generated bindings.
+    if env::var_os("DOCS_RS").is_some() {
+        generate_parameter_keys(Path::new(PREGENERATED_BINDINGS_FILE_PATH));
+        return;
+    }
+
+    let sdk = match Sdk::locate() {
+        Ok(sdk) => Some(sdk),
+        // The bindings can't be generated without the SDK headers.
+        Err(e) if cfg!(feature = "generate-bindings") => panic!("{e}"),
+        Err(e) => {
+            if is_library_linked() {
+                println!(
+                    "cargo:warning={e} The NGX library is searched for in the default paths only."
+                );
+            }
+            None
+        }
+    };
+
+    // The crate passes the directory of the snippets selected to NGX, so
+    // that it loads the features from there.
+    let library_type = DlssLibraryType::from_environment();
+    println!(
+        "cargo:rustc-env={DLSS_LIBRARY_TYPE_ENVIRONMENT_VARIABLE}={}",
+        library_type.name()
+    );
+    if let Some(sdk) = &sdk {
+        println!(
+            "cargo:rustc-env=NVNGX_SDK_LIBRARY_PATH={}",
+            sdk.library_path()
+        );
+    }
+
+    // In the mock mode the library isn't used at all, so nothing is
+    // linked.
+    if is_library_linked() {
+        // Tell cargo to look for shared libraries in the specified directory
+        if let Some(sdk) = &sdk {
+            println!("cargo:rustc-link-search={}", sdk.library_path());
+        }
+
+        // Tell cargo to tell rustc to link to the libraries.
+        println!("cargo:rustc-link-lib=nvsdk_ngx");
+        println!("cargo:rustc-link-lib=stdc++");
+    }
+    println!("cargo:rustc-link-lib=dl");
+
+    #[cfg(feature = "generate-bindings")]
+    {
+        generate_bindings(sdk.as_ref().expect("the SDK is located"));
+        generate_parameter_keys(&PathBuf::from(env::var("OUT_DIR").unwrap()).join("bindings.rs"));
+    }
+    #[cfg(not(feature = "generate-bindings"))]
+    {
+        check_pregenerated_bindings();
+        generate_parameter_keys(Path::new(PREGENERATED_BINDINGS_FILE_PATH));
+    }
 }
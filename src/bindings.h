@@ -2,45 +2,12 @@
 #define BINDINGS_H
 
 #include <vulkan/vulkan.h>
-#include "../DLSS/include/nvsdk_ngx_vk.h"
-#include "../DLSS/include/nvsdk_ngx_helpers.h"
-#include "../DLSS/include/nvsdk_ngx_helpers_vk.h"
-
-NVSDK_NGX_Resource_VK HELPERS_NVSDK_NGX_Create_ImageView_Resource_VK(
-    VkImageView imageView,
-    VkImage image,
-    VkImageSubresourceRange subresourceRange,
-    VkFormat format,
-    unsigned int width,
-    unsigned int height,
-    bool readWrite);
-
-NVSDK_NGX_Result HELPERS_NGX_DLSS_GET_OPTIMAL_SETTINGS(
-    NVSDK_NGX_Parameter *pInParams,
-    unsigned int InUserSelectedWidth,
-    unsigned int InUserSelectedHeight,
-    NVSDK_NGX_PerfQuality_Value InPerfQualityValue,
-    unsigned int *pOutRenderOptimalWidth,
-    unsigned int *pOutRenderOptimalHeight,
-    unsigned int *pOutRenderMaxWidth,
-    unsigned int *pOutRenderMaxHeight,
-    unsigned int *pOutRenderMinWidth,
-    unsigned int *pOutRenderMinHeight,
-    float *pOutSharpness);
-
-NVSDK_NGX_Result HELPERS_NGX_VULKAN_CREATE_DLSS_EXT1(
-    VkDevice InDevice,
-    VkCommandBuffer InCmdList,
-    unsigned int InCreationNodeMask,
-    unsigned int InVisibilityNodeMask,
-    NVSDK_NGX_Handle **ppOutHandle,
-    NVSDK_NGX_Parameter *pInParams,
-    NVSDK_NGX_DLSS_Create_Params *pInDlssCreateParams);
-
-NVSDK_NGX_Result HELPERS_NGX_VULKAN_EVALUATE_DLSS_EXT(
-    VkCommandBuffer InCmdList,
-    NVSDK_NGX_Handle *pInHandle,
-    NVSDK_NGX_Parameter *pInParams,
-    NVSDK_NGX_VK_DLSS_Eval_Params *pInDlssEvalParams);
+// The SDK include directory is located by build.rs.
+#include <nvsdk_ngx_vk.h>
+#include <nvsdk_ngx_helpers.h>
+#include <nvsdk_ngx_helpers_vk.h>
+#include <nvsdk_ngx_defs_dlssd.h>
+#include <nvsdk_ngx_helpers_dlssd.h>
+#include <nvsdk_ngx_helpers_dlssd_vk.h>
 
 #endif // BINDINGS_H
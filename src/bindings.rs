@@ -0,0 +1,1178 @@
+/* automatically generated by rust-bindgen 0.69.4 */
+
+pub type wchar_t = ::std::os::raw::c_int;
+pub type VkFlags = u32;
+pub type VkImageAspectFlags = VkFlags;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkBuffer_T {
+    _unused: [u8; 0],
+}
+pub type VkBuffer = *mut VkBuffer_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkImage_T {
+    _unused: [u8; 0],
+}
+pub type VkImage = *mut VkImage_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkInstance_T {
+    _unused: [u8; 0],
+}
+pub type VkInstance = *mut VkInstance_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkPhysicalDevice_T {
+    _unused: [u8; 0],
+}
+pub type VkPhysicalDevice = *mut VkPhysicalDevice_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkDevice_T {
+    _unused: [u8; 0],
+}
+pub type VkDevice = *mut VkDevice_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkCommandBuffer_T {
+    _unused: [u8; 0],
+}
+pub type VkCommandBuffer = *mut VkCommandBuffer_T;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct VkImageView_T {
+    _unused: [u8; 0],
+}
+pub type VkImageView = *mut VkImageView_T;
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum VkFormat {
+    VK_FORMAT_UNDEFINED = 0,
+    VK_FORMAT_R8G8B8A8_UNORM = 37,
+    VK_FORMAT_R8G8B8A8_SRGB = 43,
+    VK_FORMAT_B8G8R8A8_UNORM = 44,
+    VK_FORMAT_B8G8R8A8_SRGB = 50,
+    VK_FORMAT_A2B10G10R10_UNORM_PACK32 = 64,
+    VK_FORMAT_R16G16_SFLOAT = 83,
+    VK_FORMAT_R16G16B16A16_SFLOAT = 97,
+    VK_FORMAT_R32_SFLOAT = 100,
+    VK_FORMAT_R32G32_SFLOAT = 103,
+    VK_FORMAT_R32G32B32A32_SFLOAT = 109,
+    VK_FORMAT_B10G11R11_UFLOAT_PACK32 = 122,
+    VK_FORMAT_D32_SFLOAT = 126,
+    VK_FORMAT_D24_UNORM_S8_UINT = 129,
+    VK_FORMAT_D32_SFLOAT_S8_UINT = 130,
+    VK_FORMAT_MAX_ENUM = 2147483647,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VkExtensionProperties {
+    pub extensionName: [::std::os::raw::c_char; 256usize],
+    pub specVersion: u32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VkImageSubresourceRange {
+    pub aspectMask: VkImageAspectFlags,
+    pub baseMipLevel: u32,
+    pub levelCount: u32,
+    pub baseArrayLayer: u32,
+    pub layerCount: u32,
+}
+pub type PFN_vkVoidFunction = ::std::option::Option<unsafe extern "C" fn()>;
+pub type PFN_vkGetInstanceProcAddr = ::std::option::Option<
+    unsafe extern "C" fn(
+        instance: VkInstance,
+        pName: *const ::std::os::raw::c_char,
+    ) -> PFN_vkVoidFunction,
+>;
+pub type PFN_vkGetDeviceProcAddr = ::std::option::Option<
+    unsafe extern "C" fn(device: VkDevice, pName: *const ::std::os::raw::c_char) -> PFN_vkVoidFunction,
+>;
+pub const NVSDK_NGX_VERSION_API_MACRO: u32 = 21;
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_Version {
+    NVSDK_NGX_Version_API = 21,
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_Success: NVSDK_NGX_Result = NVSDK_NGX_Result(1);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_Fail: NVSDK_NGX_Result = NVSDK_NGX_Result(3134193664);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_FeatureNotSupported: NVSDK_NGX_Result =
+        NVSDK_NGX_Result(3134193665);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_PlatformError: NVSDK_NGX_Result = NVSDK_NGX_Result(3134193666);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_FeatureAlreadyExists: NVSDK_NGX_Result =
+        NVSDK_NGX_Result(3134193667);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_FeatureNotFound: NVSDK_NGX_Result =
+        NVSDK_NGX_Result(3134193668);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_InvalidParameter: NVSDK_NGX_Result =
+        NVSDK_NGX_Result(3134193669);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_ScratchBufferTooSmall: NVSDK_NGX_Result =
+        NVSDK_NGX_Result(3134193670);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_NotInitialized: NVSDK_NGX_Result = NVSDK_NGX_Result(3134193671);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_UnsupportedInputFormat: NVSDK_NGX_Result =
+        NVSDK_NGX_Result(3134193672);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_RWFlagMissing: NVSDK_NGX_Result = NVSDK_NGX_Result(3134193673);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_MissingInput: NVSDK_NGX_Result = NVSDK_NGX_Result(3134193674);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_UnableToInitializeFeature: NVSDK_NGX_Result =
+        NVSDK_NGX_Result(3134193675);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_OutOfDate: NVSDK_NGX_Result = NVSDK_NGX_Result(3134193676);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_OutOfGPUMemory: NVSDK_NGX_Result = NVSDK_NGX_Result(3134193677);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_UnsupportedFormat: NVSDK_NGX_Result =
+        NVSDK_NGX_Result(3134193678);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_UnableToWriteToAppDataPath: NVSDK_NGX_Result =
+        NVSDK_NGX_Result(3134193679);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_UnsupportedParameter: NVSDK_NGX_Result =
+        NVSDK_NGX_Result(3134193680);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_Denied: NVSDK_NGX_Result = NVSDK_NGX_Result(3134193681);
+}
+impl NVSDK_NGX_Result {
+    pub const NVSDK_NGX_Result_FAIL_NotImplemented: NVSDK_NGX_Result = NVSDK_NGX_Result(3134193682);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct NVSDK_NGX_Result(pub ::std::os::raw::c_uint);
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_Buffer_Format {
+    NVSDK_NGX_Buffer_Format_Unknown = 0,
+    NVSDK_NGX_Buffer_Format_RGB8UI = 1,
+    NVSDK_NGX_Buffer_Format_RGB16F = 2,
+    NVSDK_NGX_Buffer_Format_RGB32F = 3,
+    NVSDK_NGX_Buffer_Format_RGBA8UI = 4,
+    NVSDK_NGX_Buffer_Format_RGBA16F = 5,
+    NVSDK_NGX_Buffer_Format_RGBA32F = 6,
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_Reserved0: NVSDK_NGX_Feature = NVSDK_NGX_Feature(0);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_SuperSampling: NVSDK_NGX_Feature = NVSDK_NGX_Feature(1);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_InPainting: NVSDK_NGX_Feature = NVSDK_NGX_Feature(2);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_ImageSuperResolution: NVSDK_NGX_Feature = NVSDK_NGX_Feature(3);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_SlowMotion: NVSDK_NGX_Feature = NVSDK_NGX_Feature(4);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_VideoSuperResolution: NVSDK_NGX_Feature = NVSDK_NGX_Feature(5);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_Reserved1: NVSDK_NGX_Feature = NVSDK_NGX_Feature(6);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_Reserved2: NVSDK_NGX_Feature = NVSDK_NGX_Feature(7);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_Reserved3: NVSDK_NGX_Feature = NVSDK_NGX_Feature(8);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_ImageSignalProcessing: NVSDK_NGX_Feature = NVSDK_NGX_Feature(9);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_DeepResolve: NVSDK_NGX_Feature = NVSDK_NGX_Feature(10);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_FrameGeneration: NVSDK_NGX_Feature = NVSDK_NGX_Feature(11);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_DeepDVC: NVSDK_NGX_Feature = NVSDK_NGX_Feature(12);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_RayReconstruction: NVSDK_NGX_Feature = NVSDK_NGX_Feature(13);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_Count: NVSDK_NGX_Feature = NVSDK_NGX_Feature(14);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_Reserved_SDK: NVSDK_NGX_Feature = NVSDK_NGX_Feature(32764);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_Reserved_Core: NVSDK_NGX_Feature = NVSDK_NGX_Feature(32765);
+}
+impl NVSDK_NGX_Feature {
+    pub const NVSDK_NGX_Feature_Reserved_Unknown: NVSDK_NGX_Feature = NVSDK_NGX_Feature(32766);
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct NVSDK_NGX_Feature(pub ::std::os::raw::c_uint);
+impl NVSDK_NGX_Feature_Support_Result {
+    pub const NVSDK_NGX_FeatureSupportResult_Supported: NVSDK_NGX_Feature_Support_Result =
+        NVSDK_NGX_Feature_Support_Result(0);
+}
+impl NVSDK_NGX_Feature_Support_Result {
+    pub const NVSDK_NGX_FeatureSupportResult_CheckNotPresent: NVSDK_NGX_Feature_Support_Result =
+        NVSDK_NGX_Feature_Support_Result(1);
+}
+impl NVSDK_NGX_Feature_Support_Result {
+    pub const NVSDK_NGX_FeatureSupportResult_DriverVersionUnsupported:
+        NVSDK_NGX_Feature_Support_Result = NVSDK_NGX_Feature_Support_Result(2);
+}
+impl NVSDK_NGX_Feature_Support_Result {
+    pub const NVSDK_NGX_FeatureSupportResult_AdapterUnsupported: NVSDK_NGX_Feature_Support_Result =
+        NVSDK_NGX_Feature_Support_Result(4);
+}
+impl NVSDK_NGX_Feature_Support_Result {
+    pub const NVSDK_NGX_FeatureSupportResult_OSVersionBelowMinimumSupported:
+        NVSDK_NGX_Feature_Support_Result = NVSDK_NGX_Feature_Support_Result(8);
+}
+impl NVSDK_NGX_Feature_Support_Result {
+    pub const NVSDK_NGX_FeatureSupportResult_NotImplemented: NVSDK_NGX_Feature_Support_Result =
+        NVSDK_NGX_Feature_Support_Result(16);
+}
+impl ::std::ops::BitOr<NVSDK_NGX_Feature_Support_Result> for NVSDK_NGX_Feature_Support_Result {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        NVSDK_NGX_Feature_Support_Result(self.0 | other.0)
+    }
+}
+impl ::std::ops::BitOrAssign for NVSDK_NGX_Feature_Support_Result {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: NVSDK_NGX_Feature_Support_Result) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd<NVSDK_NGX_Feature_Support_Result> for NVSDK_NGX_Feature_Support_Result {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        NVSDK_NGX_Feature_Support_Result(self.0 & other.0)
+    }
+}
+impl ::std::ops::BitAndAssign for NVSDK_NGX_Feature_Support_Result {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: NVSDK_NGX_Feature_Support_Result) {
+        self.0 &= rhs.0;
+    }
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct NVSDK_NGX_Feature_Support_Result(pub ::std::os::raw::c_uint);
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_PerfQuality_Value {
+    NVSDK_NGX_PerfQuality_Value_MaxPerf = 0,
+    NVSDK_NGX_PerfQuality_Value_Balanced = 1,
+    NVSDK_NGX_PerfQuality_Value_MaxQuality = 2,
+    NVSDK_NGX_PerfQuality_Value_UltraPerformance = 3,
+    NVSDK_NGX_PerfQuality_Value_UltraQuality = 4,
+    NVSDK_NGX_PerfQuality_Value_DLAA = 5,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_RTX_Value {
+    NVSDK_NGX_RTX_Value_Off = 0,
+    NVSDK_NGX_RTX_Value_On = 1,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_DLSS_Mode {
+    NVSDK_NGX_DLSS_Mode_Off = 0,
+    NVSDK_NGX_DLSS_Mode_DLSS_DLISP = 1,
+    NVSDK_NGX_DLSS_Mode_DLISP_Only = 2,
+    NVSDK_NGX_DLSS_Mode_DLSS = 3,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_DLSS_Hint_Render_Preset {
+    NVSDK_NGX_DLSS_Hint_Render_Preset_Default = 0,
+    NVSDK_NGX_DLSS_Hint_Render_Preset_A = 1,
+    NVSDK_NGX_DLSS_Hint_Render_Preset_B = 2,
+    NVSDK_NGX_DLSS_Hint_Render_Preset_C = 3,
+    NVSDK_NGX_DLSS_Hint_Render_Preset_D = 4,
+    NVSDK_NGX_DLSS_Hint_Render_Preset_E = 5,
+    NVSDK_NGX_DLSS_Hint_Render_Preset_F = 6,
+    NVSDK_NGX_DLSS_Hint_Render_Preset_G = 7,
+}
+impl NVSDK_NGX_DLSS_Feature_Flags {
+    pub const NVSDK_NGX_DLSS_Feature_Flags_IsInvalid: NVSDK_NGX_DLSS_Feature_Flags =
+        NVSDK_NGX_DLSS_Feature_Flags(-2147483648);
+}
+impl NVSDK_NGX_DLSS_Feature_Flags {
+    pub const NVSDK_NGX_DLSS_Feature_Flags_None: NVSDK_NGX_DLSS_Feature_Flags =
+        NVSDK_NGX_DLSS_Feature_Flags(0);
+}
+impl NVSDK_NGX_DLSS_Feature_Flags {
+    pub const NVSDK_NGX_DLSS_Feature_Flags_IsHDR: NVSDK_NGX_DLSS_Feature_Flags =
+        NVSDK_NGX_DLSS_Feature_Flags(1);
+}
+impl NVSDK_NGX_DLSS_Feature_Flags {
+    pub const NVSDK_NGX_DLSS_Feature_Flags_MVLowRes: NVSDK_NGX_DLSS_Feature_Flags =
+        NVSDK_NGX_DLSS_Feature_Flags(2);
+}
+impl NVSDK_NGX_DLSS_Feature_Flags {
+    pub const NVSDK_NGX_DLSS_Feature_Flags_MVJittered: NVSDK_NGX_DLSS_Feature_Flags =
+        NVSDK_NGX_DLSS_Feature_Flags(4);
+}
+impl NVSDK_NGX_DLSS_Feature_Flags {
+    pub const NVSDK_NGX_DLSS_Feature_Flags_DepthInverted: NVSDK_NGX_DLSS_Feature_Flags =
+        NVSDK_NGX_DLSS_Feature_Flags(8);
+}
+impl NVSDK_NGX_DLSS_Feature_Flags {
+    pub const NVSDK_NGX_DLSS_Feature_Flags_Reserved_0: NVSDK_NGX_DLSS_Feature_Flags =
+        NVSDK_NGX_DLSS_Feature_Flags(16);
+}
+impl NVSDK_NGX_DLSS_Feature_Flags {
+    pub const NVSDK_NGX_DLSS_Feature_Flags_DoSharpening: NVSDK_NGX_DLSS_Feature_Flags =
+        NVSDK_NGX_DLSS_Feature_Flags(32);
+}
+impl NVSDK_NGX_DLSS_Feature_Flags {
+    pub const NVSDK_NGX_DLSS_Feature_Flags_AutoExposure: NVSDK_NGX_DLSS_Feature_Flags =
+        NVSDK_NGX_DLSS_Feature_Flags(64);
+}
+impl NVSDK_NGX_DLSS_Feature_Flags {
+    pub const NVSDK_NGX_DLSS_Feature_Flags_AlphaUpscaling: NVSDK_NGX_DLSS_Feature_Flags =
+        NVSDK_NGX_DLSS_Feature_Flags(128);
+}
+impl ::std::ops::BitOr<NVSDK_NGX_DLSS_Feature_Flags> for NVSDK_NGX_DLSS_Feature_Flags {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, other: Self) -> Self {
+        NVSDK_NGX_DLSS_Feature_Flags(self.0 | other.0)
+    }
+}
+impl ::std::ops::BitOrAssign for NVSDK_NGX_DLSS_Feature_Flags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: NVSDK_NGX_DLSS_Feature_Flags) {
+        self.0 |= rhs.0;
+    }
+}
+impl ::std::ops::BitAnd<NVSDK_NGX_DLSS_Feature_Flags> for NVSDK_NGX_DLSS_Feature_Flags {
+    type Output = Self;
+    #[inline]
+    fn bitand(self, other: Self) -> Self {
+        NVSDK_NGX_DLSS_Feature_Flags(self.0 & other.0)
+    }
+}
+impl ::std::ops::BitAndAssign for NVSDK_NGX_DLSS_Feature_Flags {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: NVSDK_NGX_DLSS_Feature_Flags) {
+        self.0 &= rhs.0;
+    }
+}
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct NVSDK_NGX_DLSS_Feature_Flags(pub ::std::os::raw::c_int);
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_ToneMapperType {
+    NVSDK_NGX_TONEMAPPER_STRING = 0,
+    NVSDK_NGX_TONEMAPPER_REINHARD = 1,
+    NVSDK_NGX_TONEMAPPER_ONEOVERLUMA = 2,
+    NVSDK_NGX_TONEMAPPER_ACES = 3,
+    NVSDK_NGX_TONEMAPPERTYPE_NUM = 4,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_GBufferType {
+    NVSDK_NGX_GBUFFER_ALBEDO = 0,
+    NVSDK_NGX_GBUFFER_ROUGHNESS = 1,
+    NVSDK_NGX_GBUFFER_METALLIC = 2,
+    NVSDK_NGX_GBUFFER_SPECULAR = 3,
+    NVSDK_NGX_GBUFFER_SUBSURFACE = 4,
+    NVSDK_NGX_GBUFFER_NORMALS = 5,
+    NVSDK_NGX_GBUFFER_SHADINGMODELID = 6,
+    NVSDK_NGX_GBUFFER_MATERIALID = 7,
+    NVSDK_NGX_GBUFFER_SPECULAR_ALBEDO = 8,
+    NVSDK_NGX_GBUFFER_INDIRECT_ALBEDO = 9,
+    NVSDK_NGX_GBUFFER_SPECULAR_MVEC = 10,
+    NVSDK_NGX_GBUFFER_DISOCCL_MASK = 11,
+    NVSDK_NGX_GBUFFER_EMISSIVE = 12,
+    NVSDK_NGX_GBUFFERTYPE_NUM = 16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_Coordinates {
+    pub X: ::std::os::raw::c_uint,
+    pub Y: ::std::os::raw::c_uint,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_Dimensions {
+    pub Width: ::std::os::raw::c_uint,
+    pub Height: ::std::os::raw::c_uint,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_PathListInfo {
+    pub Path: *const *const wchar_t,
+    pub Length: ::std::os::raw::c_uint,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_Logging_Level {
+    NVSDK_NGX_LOGGING_LEVEL_OFF = 0,
+    NVSDK_NGX_LOGGING_LEVEL_ON = 1,
+    NVSDK_NGX_LOGGING_LEVEL_VERBOSE = 2,
+    NVSDK_NGX_LOGGING_LEVEL_NUM = 3,
+}
+pub type NVSDK_NGX_AppLogCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        message: *const ::std::os::raw::c_char,
+        loggingLevel: NVSDK_NGX_Logging_Level,
+        sourceComponent: NVSDK_NGX_Feature,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_LoggingInfo {
+    pub LoggingCallback: NVSDK_NGX_AppLogCallback,
+    pub MinimumLoggingLevel: NVSDK_NGX_Logging_Level,
+    pub DisableOtherLoggingSinks: bool,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NVSDK_NGX_FeatureCommonInfo_Internal {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_FeatureCommonInfo {
+    pub PathListInfo: NVSDK_NGX_PathListInfo,
+    pub InternalData: *mut NVSDK_NGX_FeatureCommonInfo_Internal,
+    pub LoggingInfo: NVSDK_NGX_LoggingInfo,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_Resource_VK_Type {
+    NVSDK_NGX_RESOURCE_VK_TYPE_VK_IMAGEVIEW = 0,
+    NVSDK_NGX_RESOURCE_VK_TYPE_VK_BUFFER = 1,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_Opt_Level {
+    NVSDK_NGX_OPT_LEVEL_UNDEFINED = 0,
+    NVSDK_NGX_OPT_LEVEL_DEBUG = 20,
+    NVSDK_NGX_OPT_LEVEL_DEVELOP = 30,
+    NVSDK_NGX_OPT_LEVEL_RELEASE = 40,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_EXE_Type {
+    NVSDK_NGX_EXE_TYPE_CUSTOM = 0,
+    NVSDK_NGX_EXE_TYPE_TEST = 1,
+    NVSDK_NGX_EXE_TYPE_BENCH = 2,
+    NVSDK_NGX_EXE_TYPE_GAME = 3,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_EngineType {
+    NVSDK_NGX_ENGINE_TYPE_CUSTOM = 0,
+    NVSDK_NGX_ENGINE_TYPE_UNREAL = 1,
+    NVSDK_NGX_ENGINE_TYPE_UNITY = 2,
+    NVSDK_NGX_ENGINE_TYPE_OMNIVERSE = 3,
+    NVSDK_NGX_ENGINE_COUNT = 4,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_Application_Identifier_Type {
+    NVSDK_NGX_Application_Identifier_Type_Application_Id = 0,
+    NVSDK_NGX_Application_Identifier_Type_Project_Id = 1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_ProjectIdDescription {
+    pub ProjectId: *const ::std::os::raw::c_char,
+    pub EngineType: NVSDK_NGX_EngineType,
+    pub EngineVersion: *const ::std::os::raw::c_char,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct NVSDK_NGX_Application_Identifier {
+    pub IdentifierType: NVSDK_NGX_Application_Identifier_Type,
+    pub v: NVSDK_NGX_Application_Identifier_v,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union NVSDK_NGX_Application_Identifier_v {
+    pub ProjectDesc: NVSDK_NGX_ProjectIdDescription,
+    pub ApplicationId: ::std::os::raw::c_ulonglong,
+}
+impl ::std::fmt::Debug for NVSDK_NGX_Application_Identifier_v {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "NVSDK_NGX_Application_Identifier_v {{ union }}")
+    }
+}
+impl ::std::fmt::Debug for NVSDK_NGX_Application_Identifier {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(
+            f,
+            "NVSDK_NGX_Application_Identifier {{ IdentifierType: {:?}, v: {:?} }}",
+            self.IdentifierType, self.v
+        )
+    }
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NVSDK_NGX_FeatureDiscoveryInfo {
+    pub SDKVersion: NVSDK_NGX_Version,
+    pub FeatureID: NVSDK_NGX_Feature,
+    pub Identifier: NVSDK_NGX_Application_Identifier,
+    pub ApplicationDataPath: *const wchar_t,
+    pub FeatureInfo: *const NVSDK_NGX_FeatureCommonInfo,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_FeatureRequirement {
+    pub FeatureSupported: NVSDK_NGX_Feature_Support_Result,
+    pub MinHWArchitecture: ::std::os::raw::c_uint,
+    pub MinOSVersion: [::std::os::raw::c_char; 255usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_Handle {
+    pub Id: ::std::os::raw::c_uint,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NVSDK_NGX_Parameter {
+    _unused: [u8; 0],
+}
+pub type PFN_NVSDK_NGX_ProgressCallback_C = ::std::option::Option<
+    unsafe extern "C" fn(InCurrentProgress: f32, OutShouldCancel: *mut bool),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_Feature_Create_Params {
+    pub InWidth: ::std::os::raw::c_uint,
+    pub InHeight: ::std::os::raw::c_uint,
+    pub InTargetWidth: ::std::os::raw::c_uint,
+    pub InTargetHeight: ::std::os::raw::c_uint,
+    pub InPerfQualityValue: NVSDK_NGX_PerfQuality_Value,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_DLSS_Create_Params {
+    pub Feature: NVSDK_NGX_Feature_Create_Params,
+    pub InFeatureCreateFlags: ::std::os::raw::c_int,
+    pub InEnableOutputSubrects: bool,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_ImageViewInfo_VK {
+    pub ImageView: VkImageView,
+    pub Image: VkImage,
+    pub SubresourceRange: VkImageSubresourceRange,
+    pub Format: VkFormat,
+    pub Width: ::std::os::raw::c_uint,
+    pub Height: ::std::os::raw::c_uint,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_BufferInfo_VK {
+    pub Buffer: VkBuffer,
+    pub SizeInBytes: ::std::os::raw::c_uint,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct NVSDK_NGX_Resource_VK {
+    pub Resource: NVSDK_NGX_Resource_VK__bindgen_ty_1,
+    pub Type: NVSDK_NGX_Resource_VK_Type,
+    pub ReadWrite: bool,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union NVSDK_NGX_Resource_VK__bindgen_ty_1 {
+    pub ImageViewInfo: NVSDK_NGX_ImageViewInfo_VK,
+    pub BufferInfo: NVSDK_NGX_BufferInfo_VK,
+}
+impl ::std::fmt::Debug for NVSDK_NGX_Resource_VK__bindgen_ty_1 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "NVSDK_NGX_Resource_VK__bindgen_ty_1 {{ union }}")
+    }
+}
+impl ::std::fmt::Debug for NVSDK_NGX_Resource_VK {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(
+            f,
+            "NVSDK_NGX_Resource_VK {{ Resource: {:?}, Type: {:?}, ReadWrite: {:?} }}",
+            self.Resource, self.Type, self.ReadWrite
+        )
+    }
+}
+pub const NVSDK_NGX_Parameter_OptLevel: &[u8; 17] = b"Snippet.OptLevel\0";
+pub const NVSDK_NGX_Parameter_IsDevSnippetBranch: &[u8; 20] = b"Snippet.IsDevBranch\0";
+pub const NVSDK_NGX_Parameter_SuperSampling_ScaleFactor: &[u8; 26] = b"SuperSampling.ScaleFactor\0";
+pub const NVSDK_NGX_Parameter_ImageSignalProcessing_ScaleFactor: &[u8; 34] = b"ImageSignalProcessing.ScaleFactor\0";
+pub const NVSDK_NGX_Parameter_SuperSampling_Available: &[u8; 24] = b"SuperSampling.Available\0";
+pub const NVSDK_NGX_Parameter_InPainting_Available: &[u8; 21] = b"InPainting.Available\0";
+pub const NVSDK_NGX_Parameter_ImageSuperResolution_Available: &[u8; 31] = b"ImageSuperResolution.Available\0";
+pub const NVSDK_NGX_Parameter_SlowMotion_Available: &[u8; 21] = b"SlowMotion.Available\0";
+pub const NVSDK_NGX_Parameter_VideoSuperResolution_Available: &[u8; 31] = b"VideoSuperResolution.Available\0";
+pub const NVSDK_NGX_Parameter_ImageSignalProcessing_Available: &[u8; 32] = b"ImageSignalProcessing.Available\0";
+pub const NVSDK_NGX_Parameter_DeepResolve_Available: &[u8; 22] = b"DeepResolve.Available\0";
+pub const NVSDK_NGX_Parameter_DeepDVC_Available: &[u8; 18] = b"DeepDVC.Available\0";
+pub const NVSDK_NGX_Parameter_SuperSampling_NeedsUpdatedDriver: &[u8; 33] = b"SuperSampling.NeedsUpdatedDriver\0";
+pub const NVSDK_NGX_Parameter_InPainting_NeedsUpdatedDriver: &[u8; 30] = b"InPainting.NeedsUpdatedDriver\0";
+pub const NVSDK_NGX_Parameter_ImageSuperResolution_NeedsUpdatedDriver: &[u8; 40] = b"ImageSuperResolution.NeedsUpdatedDriver\0";
+pub const NVSDK_NGX_Parameter_SlowMotion_NeedsUpdatedDriver: &[u8; 30] = b"SlowMotion.NeedsUpdatedDriver\0";
+pub const NVSDK_NGX_Parameter_VideoSuperResolution_NeedsUpdatedDriver: &[u8; 40] = b"VideoSuperResolution.NeedsUpdatedDriver\0";
+pub const NVSDK_NGX_Parameter_ImageSignalProcessing_NeedsUpdatedDriver: &[u8; 41] = b"ImageSignalProcessing.NeedsUpdatedDriver\0";
+pub const NVSDK_NGX_Parameter_DeepResolve_NeedsUpdatedDriver: &[u8; 31] = b"DeepResolve.NeedsUpdatedDriver\0";
+pub const NVSDK_NGX_Parameter_DeepDVC_NeedsUpdatedDriver: &[u8; 27] = b"DeepDVC.NeedsUpdatedDriver\0";
+pub const NVSDK_NGX_Parameter_FrameInterpolation_NeedsUpdatedDriver: &[u8; 38] = b"FrameInterpolation.NeedsUpdatedDriver\0";
+pub const NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMajor: &[u8; 36] = b"SuperSampling.MinDriverVersionMajor\0";
+pub const NVSDK_NGX_Parameter_InPainting_MinDriverVersionMajor: &[u8; 33] = b"InPainting.MinDriverVersionMajor\0";
+pub const NVSDK_NGX_Parameter_ImageSuperResolution_MinDriverVersionMajor: &[u8; 43] = b"ImageSuperResolution.MinDriverVersionMajor\0";
+pub const NVSDK_NGX_Parameter_SlowMotion_MinDriverVersionMajor: &[u8; 33] = b"SlowMotion.MinDriverVersionMajor\0";
+pub const NVSDK_NGX_Parameter_VideoSuperResolution_MinDriverVersionMajor: &[u8; 43] = b"VideoSuperResolution.MinDriverVersionMajor\0";
+pub const NVSDK_NGX_Parameter_ImageSignalProcessing_MinDriverVersionMajor: &[u8; 44] = b"ImageSignalProcessing.MinDriverVersionMajor\0";
+pub const NVSDK_NGX_Parameter_DeepResolve_MinDriverVersionMajor: &[u8; 34] = b"DeepResolve.MinDriverVersionMajor\0";
+pub const NVSDK_NGX_Parameter_DeepDVC_MinDriverVersionMajor: &[u8; 30] = b"DeepDVC.MinDriverVersionMajor\0";
+pub const NVSDK_NGX_Parameter_FrameInterpolation_MinDriverVersionMajor: &[u8; 41] = b"FrameInterpolation.MinDriverVersionMajor\0";
+pub const NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMinor: &[u8; 36] = b"SuperSampling.MinDriverVersionMinor\0";
+pub const NVSDK_NGX_Parameter_InPainting_MinDriverVersionMinor: &[u8; 33] = b"InPainting.MinDriverVersionMinor\0";
+pub const NVSDK_NGX_Parameter_ImageSuperResolution_MinDriverVersionMinor: &[u8; 43] = b"ImageSuperResolution.MinDriverVersionMinor\0";
+pub const NVSDK_NGX_Parameter_SlowMotion_MinDriverVersionMinor: &[u8; 33] = b"SlowMotion.MinDriverVersionMinor\0";
+pub const NVSDK_NGX_Parameter_VideoSuperResolution_MinDriverVersionMinor: &[u8; 43] = b"VideoSuperResolution.MinDriverVersionMinor\0";
+pub const NVSDK_NGX_Parameter_ImageSignalProcessing_MinDriverVersionMinor: &[u8; 44] = b"ImageSignalProcessing.MinDriverVersionMinor\0";
+pub const NVSDK_NGX_Parameter_DeepResolve_MinDriverVersionMinor: &[u8; 34] = b"DeepResolve.MinDriverVersionMinor\0";
+pub const NVSDK_NGX_Parameter_DeepDVC_MinDriverVersionMinor: &[u8; 30] = b"DeepDVC.MinDriverVersionMinor\0";
+pub const NVSDK_NGX_Parameter_FrameInterpolation_MinDriverVersionMinor: &[u8; 41] = b"FrameInterpolation.MinDriverVersionMinor\0";
+pub const NVSDK_NGX_Parameter_SuperSampling_FeatureInitResult: &[u8; 32] = b"SuperSampling.FeatureInitResult\0";
+pub const NVSDK_NGX_Parameter_InPainting_FeatureInitResult: &[u8; 29] = b"InPainting.FeatureInitResult\0";
+pub const NVSDK_NGX_Parameter_ImageSuperResolution_FeatureInitResult: &[u8; 39] = b"ImageSuperResolution.FeatureInitResult\0";
+pub const NVSDK_NGX_Parameter_SlowMotion_FeatureInitResult: &[u8; 29] = b"SlowMotion.FeatureInitResult\0";
+pub const NVSDK_NGX_Parameter_VideoSuperResolution_FeatureInitResult: &[u8; 39] = b"VideoSuperResolution.FeatureInitResult\0";
+pub const NVSDK_NGX_Parameter_ImageSignalProcessing_FeatureInitResult: &[u8; 40] = b"ImageSignalProcessing.FeatureInitResult\0";
+pub const NVSDK_NGX_Parameter_DeepResolve_FeatureInitResult: &[u8; 30] = b"DeepResolve.FeatureInitResult\0";
+pub const NVSDK_NGX_Parameter_DeepDVC_FeatureInitResult: &[u8; 26] = b"DeepDVC.FeatureInitResult\0";
+pub const NVSDK_NGX_Parameter_FrameInterpolation_FeatureInitResult: &[u8; 37] = b"FrameInterpolation.FeatureInitResult\0";
+pub const NVSDK_NGX_Parameter_ImageSuperResolution_ScaleFactor_2_1: &[u8; 37] = b"ImageSuperResolution.ScaleFactor.2.1\0";
+pub const NVSDK_NGX_Parameter_ImageSuperResolution_ScaleFactor_3_1: &[u8; 37] = b"ImageSuperResolution.ScaleFactor.3.1\0";
+pub const NVSDK_NGX_Parameter_ImageSuperResolution_ScaleFactor_3_2: &[u8; 37] = b"ImageSuperResolution.ScaleFactor.3.2\0";
+pub const NVSDK_NGX_Parameter_ImageSuperResolution_ScaleFactor_4_3: &[u8; 37] = b"ImageSuperResolution.ScaleFactor.4.3\0";
+pub const NVSDK_NGX_Parameter_DeepDVC_Strength: &[u8; 17] = b"DeepDVC.Strength\0";
+pub const NVSDK_NGX_Parameter_NumFrames: &[u8; 10] = b"NumFrames\0";
+pub const NVSDK_NGX_Parameter_Scale: &[u8; 6] = b"Scale\0";
+pub const NVSDK_NGX_Parameter_Width: &[u8; 6] = b"Width\0";
+pub const NVSDK_NGX_Parameter_Height: &[u8; 7] = b"Height\0";
+pub const NVSDK_NGX_Parameter_OutWidth: &[u8; 9] = b"OutWidth\0";
+pub const NVSDK_NGX_Parameter_OutHeight: &[u8; 10] = b"OutHeight\0";
+pub const NVSDK_NGX_Parameter_Sharpness: &[u8; 10] = b"Sharpness\0";
+pub const NVSDK_NGX_Parameter_Scratch: &[u8; 8] = b"Scratch\0";
+pub const NVSDK_NGX_Parameter_Scratch_SizeInBytes: &[u8; 20] = b"Scratch.SizeInBytes\0";
+pub const NVSDK_NGX_Parameter_Input1: &[u8; 7] = b"Input1\0";
+pub const NVSDK_NGX_Parameter_Input1_Format: &[u8; 14] = b"Input1.Format\0";
+pub const NVSDK_NGX_Parameter_Input1_SizeInBytes: &[u8; 19] = b"Input1.SizeInBytes\0";
+pub const NVSDK_NGX_Parameter_Input2: &[u8; 7] = b"Input2\0";
+pub const NVSDK_NGX_Parameter_Input2_Format: &[u8; 14] = b"Input2.Format\0";
+pub const NVSDK_NGX_Parameter_Input2_SizeInBytes: &[u8; 19] = b"Input2.SizeInBytes\0";
+pub const NVSDK_NGX_Parameter_Color: &[u8; 6] = b"Color\0";
+pub const NVSDK_NGX_Parameter_Color_Format: &[u8; 13] = b"Color.Format\0";
+pub const NVSDK_NGX_Parameter_Color_SizeInBytes: &[u8; 18] = b"Color.SizeInBytes\0";
+pub const NVSDK_NGX_Parameter_FI_Color1: &[u8; 7] = b"Color1\0";
+pub const NVSDK_NGX_Parameter_FI_Color2: &[u8; 7] = b"Color2\0";
+pub const NVSDK_NGX_Parameter_Albedo: &[u8; 7] = b"Albedo\0";
+pub const NVSDK_NGX_Parameter_Output: &[u8; 7] = b"Output\0";
+pub const NVSDK_NGX_Parameter_Output_SizeInBytes: &[u8; 19] = b"Output.SizeInBytes\0";
+pub const NVSDK_NGX_Parameter_FI_Output1: &[u8; 8] = b"Output1\0";
+pub const NVSDK_NGX_Parameter_FI_Output2: &[u8; 8] = b"Output2\0";
+pub const NVSDK_NGX_Parameter_FI_Output3: &[u8; 8] = b"Output3\0";
+pub const NVSDK_NGX_Parameter_Reset: &[u8; 6] = b"Reset\0";
+pub const NVSDK_NGX_Parameter_BlendFactor: &[u8; 12] = b"BlendFactor\0";
+pub const NVSDK_NGX_Parameter_MotionVectors: &[u8; 14] = b"MotionVectors\0";
+pub const NVSDK_NGX_Parameter_FI_MotionVectors1: &[u8; 15] = b"MotionVectors1\0";
+pub const NVSDK_NGX_Parameter_FI_MotionVectors2: &[u8; 15] = b"MotionVectors2\0";
+pub const NVSDK_NGX_Parameter_Rect_X: &[u8; 7] = b"Rect.X\0";
+pub const NVSDK_NGX_Parameter_Rect_Y: &[u8; 7] = b"Rect.Y\0";
+pub const NVSDK_NGX_Parameter_Rect_W: &[u8; 7] = b"Rect.W\0";
+pub const NVSDK_NGX_Parameter_Rect_H: &[u8; 7] = b"Rect.H\0";
+pub const NVSDK_NGX_Parameter_MV_Scale_X: &[u8; 11] = b"MV.Scale.X\0";
+pub const NVSDK_NGX_Parameter_MV_Scale_Y: &[u8; 11] = b"MV.Scale.Y\0";
+pub const NVSDK_NGX_Parameter_Model: &[u8; 6] = b"Model\0";
+pub const NVSDK_NGX_Parameter_Format: &[u8; 7] = b"Format\0";
+pub const NVSDK_NGX_Parameter_SizeInBytes: &[u8; 12] = b"SizeInBytes\0";
+pub const NVSDK_NGX_Parameter_ResourceAllocCallback: &[u8; 22] = b"ResourceAllocCallback\0";
+pub const NVSDK_NGX_Parameter_BufferAllocCallback: &[u8; 20] = b"BufferAllocCallback\0";
+pub const NVSDK_NGX_Parameter_Tex2DAllocCallback: &[u8; 19] = b"Tex2DAllocCallback\0";
+pub const NVSDK_NGX_Parameter_ResourceReleaseCallback: &[u8; 24] = b"ResourceReleaseCallback\0";
+pub const NVSDK_NGX_Parameter_CreationNodeMask: &[u8; 17] = b"CreationNodeMask\0";
+pub const NVSDK_NGX_Parameter_VisibilityNodeMask: &[u8; 19] = b"VisibilityNodeMask\0";
+pub const NVSDK_NGX_Parameter_MV_Offset_X: &[u8; 12] = b"MV.Offset.X\0";
+pub const NVSDK_NGX_Parameter_MV_Offset_Y: &[u8; 12] = b"MV.Offset.Y\0";
+pub const NVSDK_NGX_Parameter_Hint_UseFireflySwatter: &[u8; 23] = b"Hint.UseFireflySwatter\0";
+pub const NVSDK_NGX_Parameter_Resource_Width: &[u8; 14] = b"ResourceWidth\0";
+pub const NVSDK_NGX_Parameter_Resource_Height: &[u8; 15] = b"ResourceHeight\0";
+pub const NVSDK_NGX_Parameter_Depth: &[u8; 6] = b"Depth\0";
+pub const NVSDK_NGX_Parameter_FI_Depth1: &[u8; 7] = b"Depth1\0";
+pub const NVSDK_NGX_Parameter_FI_Depth2: &[u8; 7] = b"Depth2\0";
+pub const NVSDK_NGX_Parameter_DLSSOptimalSettingsCallback: &[u8; 28] = b"DLSSOptimalSettingsCallback\0";
+pub const NVSDK_NGX_Parameter_DLSSGetStatsCallback: &[u8; 21] = b"DLSSGetStatsCallback\0";
+pub const NVSDK_NGX_Parameter_PerfQualityValue: &[u8; 17] = b"PerfQualityValue\0";
+pub const NVSDK_NGX_Parameter_RTXValue: &[u8; 9] = b"RTXValue\0";
+pub const NVSDK_NGX_Parameter_DLSSMode: &[u8; 9] = b"DLSSMode\0";
+pub const NVSDK_NGX_Parameter_FI_Mode: &[u8; 7] = b"FIMode\0";
+pub const NVSDK_NGX_Parameter_FI_OF_Preset: &[u8; 11] = b"FIOFPreset\0";
+pub const NVSDK_NGX_Parameter_FI_OF_GridSize: &[u8; 13] = b"FIOFGridSize\0";
+pub const NVSDK_NGX_Parameter_Jitter_Offset_X: &[u8; 16] = b"Jitter.Offset.X\0";
+pub const NVSDK_NGX_Parameter_Jitter_Offset_Y: &[u8; 16] = b"Jitter.Offset.Y\0";
+pub const NVSDK_NGX_Parameter_Denoise: &[u8; 8] = b"Denoise\0";
+pub const NVSDK_NGX_Parameter_TransparencyMask: &[u8; 17] = b"TransparencyMask\0";
+pub const NVSDK_NGX_Parameter_ExposureTexture: &[u8; 16] = b"ExposureTexture\0";
+pub const NVSDK_NGX_Parameter_DLSS_Feature_Create_Flags: &[u8; 26] = b"DLSS.Feature.Create.Flags\0";
+pub const NVSDK_NGX_Parameter_DLSS_Checkerboard_Jitter_Hack: &[u8; 30] = b"DLSS.Checkerboard.Jitter.Hack\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Normals: &[u8; 16] = b"GBuffer.Normals\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Albedo: &[u8; 15] = b"GBuffer.Albedo\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Roughness: &[u8; 18] = b"GBuffer.Roughness\0";
+pub const NVSDK_NGX_Parameter_GBuffer_DiffuseAlbedo: &[u8; 22] = b"GBuffer.DiffuseAlbedo\0";
+pub const NVSDK_NGX_Parameter_GBuffer_SpecularAlbedo: &[u8; 23] = b"GBuffer.SpecularAlbedo\0";
+pub const NVSDK_NGX_Parameter_GBuffer_IndirectAlbedo: &[u8; 23] = b"GBuffer.IndirectAlbedo\0";
+pub const NVSDK_NGX_Parameter_GBuffer_SpecularMvec: &[u8; 21] = b"GBuffer.SpecularMvec\0";
+pub const NVSDK_NGX_Parameter_GBuffer_DisocclusionMask: &[u8; 25] = b"GBuffer.DisocclusionMask\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Metallic: &[u8; 17] = b"GBuffer.Metallic\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Specular: &[u8; 17] = b"GBuffer.Specular\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Subsurface: &[u8; 19] = b"GBuffer.Subsurface\0";
+pub const NVSDK_NGX_Parameter_GBuffer_ShadingModelId: &[u8; 23] = b"GBuffer.ShadingModelId\0";
+pub const NVSDK_NGX_Parameter_GBuffer_MaterialId: &[u8; 19] = b"GBuffer.MaterialId\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Atrrib_8: &[u8; 17] = b"GBuffer.Attrib.8\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Atrrib_9: &[u8; 17] = b"GBuffer.Attrib.9\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Atrrib_10: &[u8; 18] = b"GBuffer.Attrib.10\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Atrrib_11: &[u8; 18] = b"GBuffer.Attrib.11\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Atrrib_12: &[u8; 18] = b"GBuffer.Attrib.12\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Atrrib_13: &[u8; 18] = b"GBuffer.Attrib.13\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Atrrib_14: &[u8; 18] = b"GBuffer.Attrib.14\0";
+pub const NVSDK_NGX_Parameter_GBuffer_Atrrib_15: &[u8; 18] = b"GBuffer.Attrib.15\0";
+pub const NVSDK_NGX_Parameter_TonemapperType: &[u8; 15] = b"TonemapperType\0";
+pub const NVSDK_NGX_Parameter_FreeMemOnReleaseFeature: &[u8; 24] = b"FreeMemOnReleaseFeature\0";
+pub const NVSDK_NGX_Parameter_MotionVectors3D: &[u8; 16] = b"MotionVectors3D\0";
+pub const NVSDK_NGX_Parameter_IsParticleMask: &[u8; 15] = b"IsParticleMask\0";
+pub const NVSDK_NGX_Parameter_AnimatedTextureMask: &[u8; 20] = b"AnimatedTextureMask\0";
+pub const NVSDK_NGX_Parameter_DepthHighRes: &[u8; 13] = b"DepthHighRes\0";
+pub const NVSDK_NGX_Parameter_Position_ViewSpace: &[u8; 19] = b"Position.ViewSpace\0";
+pub const NVSDK_NGX_Parameter_FrameTimeDeltaInMsec: &[u8; 21] = b"FrameTimeDeltaInMsec\0";
+pub const NVSDK_NGX_Parameter_RayTracingHitDistance: &[u8; 22] = b"RayTracingHitDistance\0";
+pub const NVSDK_NGX_Parameter_MotionVectorsReflection: &[u8; 24] = b"MotionVectorsReflection\0";
+pub const NVSDK_NGX_Parameter_DLSS_Enable_Output_Subrects: &[u8; 28] = b"DLSS.Enable.Output.Subrects\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Color_Subrect_Base_X: &[u8; 32] = b"DLSS.Input.Color.Subrect.Base.X\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Color_Subrect_Base_Y: &[u8; 32] = b"DLSS.Input.Color.Subrect.Base.Y\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Depth_Subrect_Base_X: &[u8; 32] = b"DLSS.Input.Depth.Subrect.Base.X\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Depth_Subrect_Base_Y: &[u8; 32] = b"DLSS.Input.Depth.Subrect.Base.Y\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_MV_SubrectBase_X: &[u8; 29] = b"DLSS.Input.MV.Subrect.Base.X\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_MV_SubrectBase_Y: &[u8; 29] = b"DLSS.Input.MV.Subrect.Base.Y\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Translucency_SubrectBase_X: &[u8; 39] = b"DLSS.Input.Translucency.Subrect.Base.X\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Translucency_SubrectBase_Y: &[u8; 39] = b"DLSS.Input.Translucency.Subrect.Base.Y\0";
+pub const NVSDK_NGX_Parameter_DLSS_Output_Subrect_Base_X: &[u8; 27] = b"DLSS.Output.Subrect.Base.X\0";
+pub const NVSDK_NGX_Parameter_DLSS_Output_Subrect_Base_Y: &[u8; 27] = b"DLSS.Output.Subrect.Base.Y\0";
+pub const NVSDK_NGX_Parameter_DLSS_Render_Subrect_Dimensions_Width: &[u8; 37] = b"DLSS.Render.Subrect.Dimensions.Width\0";
+pub const NVSDK_NGX_Parameter_DLSS_Render_Subrect_Dimensions_Height: &[u8; 38] = b"DLSS.Render.Subrect.Dimensions.Height\0";
+pub const NVSDK_NGX_Parameter_DLSS_Pre_Exposure: &[u8; 18] = b"DLSS.Pre.Exposure\0";
+pub const NVSDK_NGX_Parameter_DLSS_Exposure_Scale: &[u8; 20] = b"DLSS.Exposure.Scale\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Bias_Current_Color_Mask: &[u8; 35] = b"DLSS.Input.Bias.Current.Color.Mask\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Bias_Current_Color_SubrectBase_X: &[u8; 45] = b"DLSS.Input.Bias.Current.Color.Subrect.Base.X\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Bias_Current_Color_SubrectBase_Y: &[u8; 45] = b"DLSS.Input.Bias.Current.Color.Subrect.Base.Y\0";
+pub const NVSDK_NGX_Parameter_DLSS_Indicator_Invert_Y_Axis: &[u8; 29] = b"DLSS.Indicator.Invert.Y.Axis\0";
+pub const NVSDK_NGX_Parameter_DLSS_Indicator_Invert_X_Axis: &[u8; 29] = b"DLSS.Indicator.Invert.X.Axis\0";
+pub const NVSDK_NGX_Parameter_DLSS_INV_VIEW_PROJECTION_MATRIX: &[u8; 24] = b"InvViewProjectionMatrix\0";
+pub const NVSDK_NGX_Parameter_DLSS_CLIP_TO_PREV_CLIP_MATRIX: &[u8; 21] = b"ClipToPrevClipMatrix\0";
+pub const NVSDK_NGX_Parameter_DLSS_TransparencyLayer: &[u8; 23] = b"DLSS.TransparencyLayer\0";
+pub const NVSDK_NGX_Parameter_DLSS_TransparencyLayerOpacity: &[u8; 30] = b"DLSS.TransparencyLayerOpacity\0";
+pub const NVSDK_NGX_Parameter_DLSS_TransparencyLayerMvecs: &[u8; 28] = b"DLSS.TransparencyLayerMvecs\0";
+pub const NVSDK_NGX_Parameter_DLSS_DisocclusionMask: &[u8; 22] = b"DLSS.DisocclusionMask\0";
+pub const NVSDK_NGX_Parameter_DLSS_Get_Dynamic_Max_Render_Width: &[u8; 34] = b"DLSS.Get.Dynamic.Max.Render.Width\0";
+pub const NVSDK_NGX_Parameter_DLSS_Get_Dynamic_Max_Render_Height: &[u8; 35] = b"DLSS.Get.Dynamic.Max.Render.Height\0";
+pub const NVSDK_NGX_Parameter_DLSS_Get_Dynamic_Min_Render_Width: &[u8; 34] = b"DLSS.Get.Dynamic.Min.Render.Width\0";
+pub const NVSDK_NGX_Parameter_DLSS_Get_Dynamic_Min_Render_Height: &[u8; 35] = b"DLSS.Get.Dynamic.Min.Render.Height\0";
+pub const NVSDK_NGX_Parameter_DLSS_Hint_Render_Preset_DLAA: &[u8; 29] = b"DLSS.Hint.Render.Preset.DLAA\0";
+pub const NVSDK_NGX_Parameter_DLSS_Hint_Render_Preset_Quality: &[u8; 32] = b"DLSS.Hint.Render.Preset.Quality\0";
+pub const NVSDK_NGX_Parameter_DLSS_Hint_Render_Preset_Balanced: &[u8; 33] = b"DLSS.Hint.Render.Preset.Balanced\0";
+pub const NVSDK_NGX_Parameter_DLSS_Hint_Render_Preset_Performance: &[u8; 36] = b"DLSS.Hint.Render.Preset.Performance\0";
+pub const NVSDK_NGX_Parameter_DLSS_Hint_Render_Preset_UltraPerformance: &[u8; 41] = b"DLSS.Hint.Render.Preset.UltraPerformance\0";
+pub const NVSDK_NGX_Parameter_DLSS_Hint_Render_Preset_UltraQuality: &[u8; 37] = b"DLSS.Hint.Render.Preset.UltraQuality\0";
+pub const NVSDK_NGX_Parameter_FrameInterpolation_Available: &[u8; 29] = b"FrameInterpolation.Available\0";
+pub const NVSDK_NGX_Parameter_FrameGeneration_Available: &[u8; 26] = b"FrameGeneration.Available\0";
+pub const NVSDK_NGX_Parameter_SuperSamplingDenoising_Available: &[u8; 33] = b"SuperSamplingDenoising.Available\0";
+pub const NVSDK_NGX_Parameter_SuperSamplingDenoising_NeedsUpdatedDriver: &[u8; 42] = b"SuperSamplingDenoising.NeedsUpdatedDriver\0";
+pub const NVSDK_NGX_Parameter_SuperSamplingDenoising_MinDriverVersionMajor: &[u8; 45] = b"SuperSamplingDenoising.MinDriverVersionMajor\0";
+pub const NVSDK_NGX_Parameter_SuperSamplingDenoising_MinDriverVersionMinor: &[u8; 45] = b"SuperSamplingDenoising.MinDriverVersionMinor\0";
+pub const NVSDK_NGX_Parameter_SuperSamplingDenoising_FeatureInitResult: &[u8; 41] = b"SuperSamplingDenoising.FeatureInitResult\0";
+pub const NVSDK_NGX_Parameter_DLSS_Denoise_Mode: &[u8; 18] = b"DLSS.Denoise.Mode\0";
+pub const NVSDK_NGX_Parameter_DLSS_Roughness_Mode: &[u8; 20] = b"DLSS.Roughness.Mode\0";
+pub const NVSDK_NGX_Parameter_DLSS_Use_HW_Depth: &[u8; 18] = b"DLSS.Use.HW.Depth\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_DiffuseAlbedo: &[u8; 25] = b"DLSS.Input.DiffuseAlbedo\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_SpecularAlbedo: &[u8; 26] = b"DLSS.Input.SpecularAlbedo\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Normals: &[u8; 19] = b"DLSS.Input.Normals\0";
+pub const NVSDK_NGX_Parameter_DLSS_Input_Roughness: &[u8; 21] = b"DLSS.Input.Roughness\0";
+pub const NVSDK_NGX_Parameter_DLSS_WorldToViewMatrix: &[u8; 18] = b"WorldToViewMatrix\0";
+pub const NVSDK_NGX_Parameter_DLSS_ViewToClipMatrix: &[u8; 17] = b"ViewToClipMatrix\0";
+pub const NVSDK_NGX_Parameter_DLSSDOptimalSettingsCallback: &[u8; 29] = b"DLSSDOptimalSettingsCallback\0";
+pub const NVSDK_NGX_Parameter_DLSSDGetStatsCallback: &[u8; 22] = b"DLSSDGetStatsCallback\0";
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NVSDK_NGX_VK_Feature_Eval_Params {
+    pub pInColor: *mut NVSDK_NGX_Resource_VK,
+    pub pInOutput: *mut NVSDK_NGX_Resource_VK,
+    pub InSharpness: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NVSDK_NGX_VK_GBuffer {
+    pub pInAttrib: [*mut NVSDK_NGX_Resource_VK; 16usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NVSDK_NGX_VK_DLSS_Eval_Params {
+    pub Feature: NVSDK_NGX_VK_Feature_Eval_Params,
+    pub pInDepth: *mut NVSDK_NGX_Resource_VK,
+    pub pInMotionVectors: *mut NVSDK_NGX_Resource_VK,
+    pub InJitterOffsetX: f32,
+    pub InJitterOffsetY: f32,
+    pub InRenderSubrectDimensions: NVSDK_NGX_Dimensions,
+    pub InReset: ::std::os::raw::c_int,
+    pub InMVScaleX: f32,
+    pub InMVScaleY: f32,
+    pub pInTransparencyMask: *mut NVSDK_NGX_Resource_VK,
+    pub pInExposureTexture: *mut NVSDK_NGX_Resource_VK,
+    pub pInBiasCurrentColorMask: *mut NVSDK_NGX_Resource_VK,
+    pub InColorSubrectBase: NVSDK_NGX_Coordinates,
+    pub InDepthSubrectBase: NVSDK_NGX_Coordinates,
+    pub InMVSubrectBase: NVSDK_NGX_Coordinates,
+    pub InTranslucencySubrectBase: NVSDK_NGX_Coordinates,
+    pub InBiasCurrentColorSubrectBase: NVSDK_NGX_Coordinates,
+    pub InOutputSubrectBase: NVSDK_NGX_Coordinates,
+    pub InPreExposure: f32,
+    pub InExposureScale: f32,
+    pub InIndicatorInvertXAxis: ::std::os::raw::c_int,
+    pub InIndicatorInvertYAxis: ::std::os::raw::c_int,
+    pub GBufferSurface: NVSDK_NGX_VK_GBuffer,
+    pub InToneMapperType: NVSDK_NGX_ToneMapperType,
+    pub pInMotionVectors3D: *mut NVSDK_NGX_Resource_VK,
+    pub pInIsParticleMask: *mut NVSDK_NGX_Resource_VK,
+    pub pInAnimatedTextureMask: *mut NVSDK_NGX_Resource_VK,
+    pub pInDepthHighRes: *mut NVSDK_NGX_Resource_VK,
+    pub pInPositionViewSpace: *mut NVSDK_NGX_Resource_VK,
+    pub InFrameTimeDeltaInMsec: f32,
+    pub pInRayTracingHitDistance: *mut NVSDK_NGX_Resource_VK,
+    pub pInMotionVectorsReflections: *mut NVSDK_NGX_Resource_VK,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_DLSS_Denoise_Mode {
+    NVSDK_NGX_DLSS_Denoise_Mode_Off = 0,
+    NVSDK_NGX_DLSS_Denoise_Mode_DLUnified = 1,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_DLSS_Roughness_Mode {
+    NVSDK_NGX_DLSS_Roughness_Mode_Unpacked = 0,
+    NVSDK_NGX_DLSS_Roughness_Mode_Packed = 1,
+}
+#[repr(u32)]
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum NVSDK_NGX_DLSS_Depth_Type {
+    NVSDK_NGX_DLSS_Depth_Type_Linear = 0,
+    NVSDK_NGX_DLSS_Depth_Type_HW = 1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NVSDK_NGX_DLSSD_Create_Params {
+    pub InDenoiseMode: NVSDK_NGX_DLSS_Denoise_Mode,
+    pub InRoughnessMode: NVSDK_NGX_DLSS_Roughness_Mode,
+    pub InUseHWDepth: NVSDK_NGX_DLSS_Depth_Type,
+    pub InWidth: ::std::os::raw::c_uint,
+    pub InHeight: ::std::os::raw::c_uint,
+    pub InTargetWidth: ::std::os::raw::c_uint,
+    pub InTargetHeight: ::std::os::raw::c_uint,
+    pub InPerfQualityValue: NVSDK_NGX_PerfQuality_Value,
+    pub InFeatureCreateFlags: ::std::os::raw::c_int,
+    pub InEnableOutputSubrects: bool,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NVSDK_NGX_VK_DLSSD_Eval_Params {
+    pub pInDiffuseAlbedo: *mut NVSDK_NGX_Resource_VK,
+    pub pInSpecularAlbedo: *mut NVSDK_NGX_Resource_VK,
+    pub pInNormals: *mut NVSDK_NGX_Resource_VK,
+    pub pInRoughness: *mut NVSDK_NGX_Resource_VK,
+    pub pInColor: *mut NVSDK_NGX_Resource_VK,
+    pub pInAlpha: *mut NVSDK_NGX_Resource_VK,
+    pub pInOutput: *mut NVSDK_NGX_Resource_VK,
+    pub pInOutputAlpha: *mut NVSDK_NGX_Resource_VK,
+    pub pInDepth: *mut NVSDK_NGX_Resource_VK,
+    pub pInMotionVectors: *mut NVSDK_NGX_Resource_VK,
+    pub InJitterOffsetX: f32,
+    pub InJitterOffsetY: f32,
+    pub InRenderSubrectDimensions: NVSDK_NGX_Dimensions,
+    pub InReset: ::std::os::raw::c_int,
+    pub InMVScaleX: f32,
+    pub InMVScaleY: f32,
+    pub pInTransparencyMask: *mut NVSDK_NGX_Resource_VK,
+    pub pInExposureTexture: *mut NVSDK_NGX_Resource_VK,
+    pub pInBiasCurrentColorMask: *mut NVSDK_NGX_Resource_VK,
+    pub InColorSubrectBase: NVSDK_NGX_Coordinates,
+    pub InAlphaSubrectBase: NVSDK_NGX_Coordinates,
+    pub InOutputAlphaSubrectBase: NVSDK_NGX_Coordinates,
+    pub InDepthSubrectBase: NVSDK_NGX_Coordinates,
+    pub InMVSubrectBase: NVSDK_NGX_Coordinates,
+    pub InTranslucencySubrectBase: NVSDK_NGX_Coordinates,
+    pub InBiasCurrentColorSubrectBase: NVSDK_NGX_Coordinates,
+    pub InOutputSubrectBase: NVSDK_NGX_Coordinates,
+    pub InPreExposure: f32,
+    pub InExposureScale: f32,
+    pub InIndicatorInvertXAxis: ::std::os::raw::c_int,
+    pub InIndicatorInvertYAxis: ::std::os::raw::c_int,
+    pub GBufferSurface: NVSDK_NGX_VK_GBuffer,
+    pub InToneMapperType: NVSDK_NGX_ToneMapperType,
+    pub pInMotionVectors3D: *mut NVSDK_NGX_Resource_VK,
+    pub pInIsParticleMask: *mut NVSDK_NGX_Resource_VK,
+    pub pInAnimatedTextureMask: *mut NVSDK_NGX_Resource_VK,
+    pub pInDepthHighRes: *mut NVSDK_NGX_Resource_VK,
+    pub pInPositionViewSpace: *mut NVSDK_NGX_Resource_VK,
+    pub InFrameTimeDeltaInMsec: f32,
+    pub pInRayTracingHitDistance: *mut NVSDK_NGX_Resource_VK,
+    pub pInMotionVectorsReflections: *mut NVSDK_NGX_Resource_VK,
+    pub pInTransparencyLayer: *mut NVSDK_NGX_Resource_VK,
+    pub InTransparencyLayerSubrectBase: NVSDK_NGX_Coordinates,
+    pub pInTransparencyLayerOpacity: *mut NVSDK_NGX_Resource_VK,
+    pub InTransparencyLayerOpacitySubrectBase: NVSDK_NGX_Coordinates,
+    pub pInTransparencyLayerMvecs: *mut NVSDK_NGX_Resource_VK,
+    pub InTransparencyLayerMvecsSubrectBase: NVSDK_NGX_Coordinates,
+    pub pInDisocclusionMask: *mut NVSDK_NGX_Resource_VK,
+    pub InDisocclusionMaskSubrectBase: NVSDK_NGX_Coordinates,
+    pub pInWorldToViewMatrix: *mut f32,
+    pub pInViewToClipMatrix: *mut f32,
+}
+extern "C" {
+    pub fn GetNGXResultAsString(InNGXResult: NVSDK_NGX_Result) -> *const wchar_t;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_RequiredExtensions(
+        OutInstanceExtCount: *mut ::std::os::raw::c_uint,
+        OutInstanceExts: *mut *mut *const ::std::os::raw::c_char,
+        OutDeviceExtCount: *mut ::std::os::raw::c_uint,
+        OutDeviceExts: *mut *mut *const ::std::os::raw::c_char,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_Init_with_ProjectID(
+        InProjectId: *const ::std::os::raw::c_char,
+        InEngineType: NVSDK_NGX_EngineType,
+        InEngineVersion: *const ::std::os::raw::c_char,
+        InApplicationDataPath: *const wchar_t,
+        InInstance: VkInstance,
+        InPD: VkPhysicalDevice,
+        InDevice: VkDevice,
+        InGIPA: PFN_vkGetInstanceProcAddr,
+        InGDPA: PFN_vkGetDeviceProcAddr,
+        InFeatureInfo: *const NVSDK_NGX_FeatureCommonInfo,
+        InSDKVersion: NVSDK_NGX_Version,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_Shutdown1(InDevice: VkDevice) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_GetParameters(
+        OutParameters: *mut *mut NVSDK_NGX_Parameter,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_AllocateParameters(
+        OutParameters: *mut *mut NVSDK_NGX_Parameter,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_GetCapabilityParameters(
+        OutParameters: *mut *mut NVSDK_NGX_Parameter,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_DestroyParameters(
+        InParameters: *mut NVSDK_NGX_Parameter,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_GetScratchBufferSize(
+        InFeatureId: NVSDK_NGX_Feature,
+        InParameters: *const NVSDK_NGX_Parameter,
+        OutSizeInBytes: *mut usize,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_CreateFeature1(
+        InDevice: VkDevice,
+        InCmdList: VkCommandBuffer,
+        InFeatureID: NVSDK_NGX_Feature,
+        InParameters: *mut NVSDK_NGX_Parameter,
+        OutHandle: *mut *mut NVSDK_NGX_Handle,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_ReleaseFeature(InHandle: *mut NVSDK_NGX_Handle) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_GetFeatureRequirements(
+        Instance: VkInstance,
+        PhysicalDevice: VkPhysicalDevice,
+        FeatureDiscoveryInfo: *const NVSDK_NGX_FeatureDiscoveryInfo,
+        OutSupported: *mut NVSDK_NGX_FeatureRequirement,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_GetFeatureInstanceExtensionRequirements(
+        FeatureDiscoveryInfo: *const NVSDK_NGX_FeatureDiscoveryInfo,
+        OutExtensionCount: *mut u32,
+        OutExtensionProperties: *mut *mut VkExtensionProperties,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_GetFeatureDeviceExtensionRequirements(
+        Instance: VkInstance,
+        PhysicalDevice: VkPhysicalDevice,
+        FeatureDiscoveryInfo: *const NVSDK_NGX_FeatureDiscoveryInfo,
+        OutExtensionCount: *mut u32,
+        OutExtensionProperties: *mut *mut VkExtensionProperties,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_VULKAN_EvaluateFeature_C(
+        InCmdList: VkCommandBuffer,
+        InFeatureHandle: *const NVSDK_NGX_Handle,
+        InParameters: *const NVSDK_NGX_Parameter,
+        InCallback: PFN_NVSDK_NGX_ProgressCallback_C,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_SetULL(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        InValue: ::std::os::raw::c_ulonglong,
+    );
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_SetF(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        InValue: f32,
+    );
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_SetD(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        InValue: f64,
+    );
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_SetUI(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        InValue: ::std::os::raw::c_uint,
+    );
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_SetI(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        InValue: ::std::os::raw::c_int,
+    );
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_SetVoidPointer(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        InValue: *mut ::std::os::raw::c_void,
+    );
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_GetULL(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        OutValue: *mut ::std::os::raw::c_ulonglong,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_GetF(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        OutValue: *mut f32,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_GetD(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        OutValue: *mut f64,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_GetUI(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        OutValue: *mut ::std::os::raw::c_uint,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_GetI(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        OutValue: *mut ::std::os::raw::c_int,
+    ) -> NVSDK_NGX_Result;
+}
+extern "C" {
+    pub fn NVSDK_NGX_Parameter_GetVoidPointer(
+        InParameter: *mut NVSDK_NGX_Parameter,
+        InName: *const ::std::os::raw::c_char,
+        OutValue: *mut *mut ::std::os::raw::c_void,
+    ) -> NVSDK_NGX_Result;
+}
@@ -0,0 +1,395 @@
+//! A typed snapshot of the NGX capability parameters.
+
+use crate::bindings::{NVSDK_NGX_Feature, NVSDK_NGX_Result};
+use crate::{keys, ParameterKey, ParameterMap, Result, System};
+
+/// A version of the NVIDIA driver.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DriverVersion {
+    /// The major version.
+    pub major: u32,
+    /// The minor version.
+    pub minor: u32,
+}
+
+/// The capabilities of a single NGX feature. Every field is [`None`] if
+/// the driver didn't report it.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureCapability {
+    /// Whether the feature is available.
+    pub available: Option<bool>,
+    /// Whether the driver must be updated for the feature.
+    pub needs_updated_driver: Option<bool>,
+    /// The minimum driver version the feature requires.
+    pub min_driver_version: Option<DriverVersion>,
+    /// The result of the initialisation of the feature.
+    #[cfg_attr(feature = "serde", serde(with = "result_code"))]
+    pub init_result: Option<NVSDK_NGX_Result>,
+}
+
+impl FeatureCapability {
+    /// Reads the capability from the `parameters`, with the parameter
+    /// names of the feature.
+    fn read(parameters: &ParameterMap, names: &CapabilityParameterNames) -> Self {
+        let min_driver_version =
+            parameters
+                .get(&names.min_driver_version_major)
+                .and_then(|major| {
+                    parameters
+                        .get(&names.min_driver_version_minor)
+                        .map(|minor| DriverVersion { major, minor })
+                });
+        Self {
+            available: parameters
+                .get(&names.available)
+                .or_else(|e| match &names.fallback_available {
+                    Some(fallback_available) => parameters.get(fallback_available),
+                    None => Err(e),
+                })
+                .ok(),
+            needs_updated_driver: parameters.get(&names.needs_updated_driver).ok(),
+            min_driver_version: min_driver_version.ok(),
+            init_result: parameters
+                .get(&names.init_result)
+                .ok()
+                .map(|code| NVSDK_NGX_Result(code as u32)),
+        }
+    }
+
+    /// Returns `true` if the feature is available and could be
+    /// initialised, so that it can be created.
+    pub fn is_usable(&self) -> bool {
+        self.available == Some(true)
+            && self.needs_updated_driver != Some(true)
+            && !matches!(self.init_result, Some(result) if !result.is_success())
+    }
+}
+
+/// (De)serialises the [`NVSDK_NGX_Result`] as its code.
+#[cfg(feature = "serde")]
+mod result_code {
+    use crate::bindings::NVSDK_NGX_Result;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        result: &Option<NVSDK_NGX_Result>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        result.map(|result| result.0).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<NVSDK_NGX_Result>, D::Error> {
+        Ok(Option::<u32>::deserialize(deserializer)?.map(NVSDK_NGX_Result))
+    }
+}
+
+/// The names of the capability parameters of a feature.
+struct CapabilityParameterNames {
+    available: ParameterKey<bool>,
+    /// The name of the availability read when the driver doesn't report
+    /// the [`Self::available`] one.
+    fallback_available: Option<ParameterKey<bool>>,
+    needs_updated_driver: ParameterKey<bool>,
+    min_driver_version_major: ParameterKey<u32>,
+    min_driver_version_minor: ParameterKey<u32>,
+    init_result: ParameterKey<i32>,
+}
+
+/// Returns the capability parameter names of the `feature`, if it has
+/// any. The frame generation reports its availability under its own
+/// name or, with older drivers, under the frame interpolation name,
+/// which is the only name of the rest of its capabilities. The ray
+/// reconstruction reports them under the super sampling denoising.
+fn capability_parameter_names(feature: NVSDK_NGX_Feature) -> Option<CapabilityParameterNames> {
+    Some(match feature {
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling => CapabilityParameterNames {
+            available: keys::NVSDK_NGX_Parameter_SuperSampling_Available,
+            fallback_available: None,
+            needs_updated_driver: keys::NVSDK_NGX_Parameter_SuperSampling_NeedsUpdatedDriver,
+            min_driver_version_major: keys::NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMajor,
+            min_driver_version_minor: keys::NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMinor,
+            init_result: keys::NVSDK_NGX_Parameter_SuperSampling_FeatureInitResult,
+        },
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_InPainting => CapabilityParameterNames {
+            available: keys::NVSDK_NGX_Parameter_InPainting_Available,
+            fallback_available: None,
+            needs_updated_driver: keys::NVSDK_NGX_Parameter_InPainting_NeedsUpdatedDriver,
+            min_driver_version_major: keys::NVSDK_NGX_Parameter_InPainting_MinDriverVersionMajor,
+            min_driver_version_minor: keys::NVSDK_NGX_Parameter_InPainting_MinDriverVersionMinor,
+            init_result: keys::NVSDK_NGX_Parameter_InPainting_FeatureInitResult,
+        },
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_ImageSuperResolution => CapabilityParameterNames {
+            available: keys::NVSDK_NGX_Parameter_ImageSuperResolution_Available,
+            fallback_available: None,
+            needs_updated_driver: keys::NVSDK_NGX_Parameter_ImageSuperResolution_NeedsUpdatedDriver,
+            min_driver_version_major:
+                keys::NVSDK_NGX_Parameter_ImageSuperResolution_MinDriverVersionMajor,
+            min_driver_version_minor:
+                keys::NVSDK_NGX_Parameter_ImageSuperResolution_MinDriverVersionMinor,
+            init_result: keys::NVSDK_NGX_Parameter_ImageSuperResolution_FeatureInitResult,
+        },
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_SlowMotion => CapabilityParameterNames {
+            available: keys::NVSDK_NGX_Parameter_SlowMotion_Available,
+            fallback_available: None,
+            needs_updated_driver: keys::NVSDK_NGX_Parameter_SlowMotion_NeedsUpdatedDriver,
+            min_driver_version_major: keys::NVSDK_NGX_Parameter_SlowMotion_MinDriverVersionMajor,
+            min_driver_version_minor: keys::NVSDK_NGX_Parameter_SlowMotion_MinDriverVersionMinor,
+            init_result: keys::NVSDK_NGX_Parameter_SlowMotion_FeatureInitResult,
+        },
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_VideoSuperResolution => CapabilityParameterNames {
+            available: keys::NVSDK_NGX_Parameter_VideoSuperResolution_Available,
+            fallback_available: None,
+            needs_updated_driver: keys::NVSDK_NGX_Parameter_VideoSuperResolution_NeedsUpdatedDriver,
+            min_driver_version_major:
+                keys::NVSDK_NGX_Parameter_VideoSuperResolution_MinDriverVersionMajor,
+            min_driver_version_minor:
+                keys::NVSDK_NGX_Parameter_VideoSuperResolution_MinDriverVersionMinor,
+            init_result: keys::NVSDK_NGX_Parameter_VideoSuperResolution_FeatureInitResult,
+        },
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_ImageSignalProcessing => CapabilityParameterNames {
+            available: keys::NVSDK_NGX_Parameter_ImageSignalProcessing_Available,
+            fallback_available: None,
+            needs_updated_driver:
+                keys::NVSDK_NGX_Parameter_ImageSignalProcessing_NeedsUpdatedDriver,
+            min_driver_version_major:
+                keys::NVSDK_NGX_Parameter_ImageSignalProcessing_MinDriverVersionMajor,
+            min_driver_version_minor:
+                keys::NVSDK_NGX_Parameter_ImageSignalProcessing_MinDriverVersionMinor,
+            init_result: keys::NVSDK_NGX_Parameter_ImageSignalProcessing_FeatureInitResult,
+        },
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_DeepResolve => CapabilityParameterNames {
+            available: keys::NVSDK_NGX_Parameter_DeepResolve_Available,
+            fallback_available: None,
+            needs_updated_driver: keys::NVSDK_NGX_Parameter_DeepResolve_NeedsUpdatedDriver,
+            min_driver_version_major: keys::NVSDK_NGX_Parameter_DeepResolve_MinDriverVersionMajor,
+            min_driver_version_minor: keys::NVSDK_NGX_Parameter_DeepResolve_MinDriverVersionMinor,
+            init_result: keys::NVSDK_NGX_Parameter_DeepResolve_FeatureInitResult,
+        },
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_DeepDVC => CapabilityParameterNames {
+            available: keys::NVSDK_NGX_Parameter_DeepDVC_Available,
+            fallback_available: None,
+            needs_updated_driver: keys::NVSDK_NGX_Parameter_DeepDVC_NeedsUpdatedDriver,
+            min_driver_version_major: keys::NVSDK_NGX_Parameter_DeepDVC_MinDriverVersionMajor,
+            min_driver_version_minor: keys::NVSDK_NGX_Parameter_DeepDVC_MinDriverVersionMinor,
+            init_result: keys::NVSDK_NGX_Parameter_DeepDVC_FeatureInitResult,
+        },
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration => CapabilityParameterNames {
+            available: keys::NVSDK_NGX_Parameter_FrameGeneration_Available,
+            fallback_available: Some(keys::NVSDK_NGX_Parameter_FrameInterpolation_Available),
+            needs_updated_driver: keys::NVSDK_NGX_Parameter_FrameInterpolation_NeedsUpdatedDriver,
+            min_driver_version_major:
+                keys::NVSDK_NGX_Parameter_FrameInterpolation_MinDriverVersionMajor,
+            min_driver_version_minor:
+                keys::NVSDK_NGX_Parameter_FrameInterpolation_MinDriverVersionMinor,
+            init_result: keys::NVSDK_NGX_Parameter_FrameInterpolation_FeatureInitResult,
+        },
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction => CapabilityParameterNames {
+            available: keys::NVSDK_NGX_Parameter_SuperSamplingDenoising_Available,
+            fallback_available: None,
+            needs_updated_driver:
+                keys::NVSDK_NGX_Parameter_SuperSamplingDenoising_NeedsUpdatedDriver,
+            min_driver_version_major:
+                keys::NVSDK_NGX_Parameter_SuperSamplingDenoising_MinDriverVersionMajor,
+            min_driver_version_minor:
+                keys::NVSDK_NGX_Parameter_SuperSamplingDenoising_MinDriverVersionMinor,
+            init_result: keys::NVSDK_NGX_Parameter_SuperSamplingDenoising_FeatureInitResult,
+        },
+        _ => return None,
+    })
+}
+
+/// A snapshot of the capabilities of every NGX feature, read from the
+/// capability parameters, see
+/// [`System::get_capability_parameters`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capabilities {
+    /// The capabilities of the SuperSampling (DLSS) feature.
+    pub super_sampling: FeatureCapability,
+    /// The capabilities of the InPainting feature.
+    pub in_painting: FeatureCapability,
+    /// The capabilities of the ImageSuperResolution feature.
+    pub image_super_resolution: FeatureCapability,
+    /// The capabilities of the SlowMotion feature.
+    pub slow_motion: FeatureCapability,
+    /// The capabilities of the VideoSuperResolution feature.
+    pub video_super_resolution: FeatureCapability,
+    /// The capabilities of the ImageSignalProcessing feature.
+    pub image_signal_processing: FeatureCapability,
+    /// The capabilities of the DeepResolve feature.
+    pub deep_resolve: FeatureCapability,
+    /// The capabilities of the DeepDVC feature.
+    pub deep_dvc: FeatureCapability,
+    /// The capabilities of the FrameGeneration feature.
+    pub frame_generation: FeatureCapability,
+    /// The capabilities of the RayReconstruction (DLSS-RR) feature.
+    pub ray_reconstruction: FeatureCapability,
+}
+
+impl Capabilities {
+    /// Reads the capabilities from new capability parameters of the
+    /// `system`.
+    pub fn get(system: &System) -> Result<Self> {
+        Ok(Self::from(&*system.get_capability_parameters()?))
+    }
+
+    /// Returns the capabilities of the `feature`, if it is one of the
+    /// features with capability parameters.
+    pub fn get_feature(&self, feature: NVSDK_NGX_Feature) -> Option<&FeatureCapability> {
+        Some(match feature {
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling => &self.super_sampling,
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_InPainting => &self.in_painting,
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_ImageSuperResolution => {
+                &self.image_super_resolution
+            }
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_SlowMotion => &self.slow_motion,
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_VideoSuperResolution => {
+                &self.video_super_resolution
+            }
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_ImageSignalProcessing => {
+                &self.image_signal_processing
+            }
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_DeepResolve => &self.deep_resolve,
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_DeepDVC => &self.deep_dvc,
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration => &self.frame_generation,
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction => &self.ray_reconstruction,
+            _ => return None,
+        })
+    }
+}
+
+impl From<&ParameterMap> for Capabilities {
+    fn from(parameters: &ParameterMap) -> Self {
+        let read = |feature| {
+            capability_parameter_names(feature)
+                .map(|names| FeatureCapability::read(parameters, &names))
+                .unwrap_or_default()
+        };
+        Self {
+            super_sampling: read(NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling),
+            in_painting: read(NVSDK_NGX_Feature::NVSDK_NGX_Feature_InPainting),
+            image_super_resolution: read(NVSDK_NGX_Feature::NVSDK_NGX_Feature_ImageSuperResolution),
+            slow_motion: read(NVSDK_NGX_Feature::NVSDK_NGX_Feature_SlowMotion),
+            video_super_resolution: read(NVSDK_NGX_Feature::NVSDK_NGX_Feature_VideoSuperResolution),
+            image_signal_processing: read(
+                NVSDK_NGX_Feature::NVSDK_NGX_Feature_ImageSignalProcessing,
+            ),
+            deep_resolve: read(NVSDK_NGX_Feature::NVSDK_NGX_Feature_DeepResolve),
+            deep_dvc: read(NVSDK_NGX_Feature::NVSDK_NGX_Feature_DeepDVC),
+            frame_generation: read(NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration),
+            ray_reconstruction: read(NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "mock")]
+    mod mock {
+        use crate::bindings::{self, NVSDK_NGX_Feature};
+        use crate::mock::{self, Value};
+        use crate::{Capabilities, DriverVersion, FeatureCapability};
+
+        #[test]
+        fn capabilities_snapshot() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMajor,
+                Value::U32(535),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSamplingDenoising_Available,
+                Value::I32(0),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameInterpolation_NeedsUpdatedDriver,
+                Value::I32(1),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameInterpolation_FeatureInitResult,
+                Value::I32(
+                    bindings::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_FeatureNotSupported.0 as i32,
+                ),
+            );
+
+            let capabilities = Capabilities::get(&system).unwrap();
+            assert_eq!(
+                capabilities.super_sampling,
+                FeatureCapability {
+                    available: Some(true),
+                    needs_updated_driver: Some(false),
+                    min_driver_version: Some(DriverVersion {
+                        major: 535,
+                        minor: 0
+                    }),
+                    init_result: Some(bindings::NVSDK_NGX_Result::NVSDK_NGX_Result_Success),
+                }
+            );
+            assert!(capabilities.super_sampling.is_usable());
+            assert_eq!(capabilities.ray_reconstruction.available, Some(false));
+            assert!(!capabilities.ray_reconstruction.is_usable());
+            assert_eq!(
+                capabilities.frame_generation,
+                FeatureCapability {
+                    needs_updated_driver: Some(true),
+                    init_result: Some(
+                        bindings::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_FeatureNotSupported
+                    ),
+                    ..Default::default()
+                }
+            );
+            assert_eq!(capabilities.deep_dvc, FeatureCapability::default());
+            assert_eq!(
+                capabilities.get_feature(NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction),
+                Some(&capabilities.ray_reconstruction)
+            );
+            assert_eq!(
+                capabilities.get_feature(NVSDK_NGX_Feature::NVSDK_NGX_Feature_Reserved_SDK),
+                None
+            );
+
+            mock::remove_capability(bindings::NVSDK_NGX_Parameter_SuperSampling_Available);
+            let capabilities = Capabilities::get(&system).unwrap();
+            assert_eq!(capabilities.super_sampling.available, None);
+            assert!(!capabilities.super_sampling.is_usable());
+
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSampling_Available,
+                Value::I32(1),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSampling_FeatureInitResult,
+                Value::I32(
+                    bindings::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfGPUMemory.0 as i32,
+                ),
+            );
+            let capabilities = Capabilities::get(&system).unwrap();
+            assert_eq!(
+                capabilities.super_sampling.init_result,
+                Some(bindings::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfGPUMemory)
+            );
+            assert!(!capabilities.super_sampling.is_usable());
+
+            // The frame generation availability falls back to the frame
+            // interpolation name.
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameInterpolation_Available,
+                Value::I32(1),
+            );
+            let capabilities = Capabilities::get(&system).unwrap();
+            assert_eq!(capabilities.frame_generation.available, Some(true));
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameGeneration_Available,
+                Value::I32(0),
+            );
+            let capabilities = Capabilities::get(&system).unwrap();
+            assert_eq!(capabilities.frame_generation.available, Some(false));
+        }
+    }
+}
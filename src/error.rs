@@ -5,31 +5,345 @@ use crate::bindings;
 /// The result type used within the crate.
 pub type Result<T = ()> = std::result::Result<T, Error>;
 
-/// The error type.
-#[derive(Debug, Clone)]
+/// The error type. Each failure result code of NGX has its own variant,
+/// and the conditions the crate detects itself have typed variants
+/// carrying the details, so that the errors can be told apart without
+/// matching on the messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
-    /// An internal NVIDIA NGX error, not related to the crate.
-    Internal(bindings::NVSDK_NGX_Result),
+    /// A generic NGX failure (`NVSDK_NGX_Result_Fail`).
+    Fail,
+    /// The feature isn't supported on the current hardware.
+    FeatureNotSupported,
+    /// A platform error, check the Vulkan validation layers' log for
+    /// more information.
+    PlatformError,
+    /// The feature with the given parameters already exists.
+    FeatureAlreadyExists,
+    /// The feature with the provided handle doesn't exist.
+    FeatureNotFound,
+    /// An invalid parameter was provided.
+    InvalidParameter,
+    /// The provided buffer is too small, please use the size provided
+    /// by `NVSDK_NGX_GetScratchBufferSize`.
+    ScratchBufferTooSmall,
+    /// SDK wasn't initialised properly.
+    NotInitialized,
+    /// Unsupported format used for the input/output buffers.
+    UnsupportedInputFormat,
+    /// The feature input/output needs the read/write access (UAV).
+    RwFlagMissing,
+    /// The feature was created with a specific input, but none was
+    /// provided at evaluation.
+    MissingInput,
+    /// The feature isn't available on the system.
+    UnableToInitializeFeature,
+    /// NGX system libraries are old and need an update.
+    OutOfDate,
+    /// The feature requires more GPU memory than it is available on the
+    /// system.
+    OutOfGpuMemory,
+    /// The format used in the input/output buffers is either unsupported
+    /// or doesn't meet the requirements of the feature.
+    UnsupportedFormat,
+    /// The path provided in `InApplicationDataPath` can't be written to.
+    UnableToWriteToAppDataPath,
+    /// An unsupported parameter was passed.
+    UnsupportedParameter,
+    /// The feature or application was denied (contact NVIDIA for
+    /// further details).
+    Denied,
+    /// The feature or functionality isn't implemented.
+    NotImplemented,
+    /// A failure result code unknown to the NGX headers the crate was
+    /// built with, likely returned by a newer driver.
+    UnknownResult(u32),
+    /// The feature requires a newer driver than the one installed.
+    DriverOutdated {
+        /// The feature which requires the update.
+        feature: bindings::NVSDK_NGX_Feature,
+        /// The major part of the minimal driver version required.
+        min_driver_version_major: u32,
+        /// The minor part of the minimal driver version required.
+        min_driver_version_minor: u32,
+    },
+    /// The feature isn't available on this platform.
+    FeatureUnavailable(bindings::NVSDK_NGX_Feature),
+    /// The quality level requested isn't supported.
+    QualityLevelUnsupported(bindings::NVSDK_NGX_PerfQuality_Value),
+    /// The NGX core of the NVIDIA driver couldn't be loaded. Only
+    /// returned when the crate is built with the `detect-driver` feature.
+    LibraryNotPresent(String),
     /// Any other error which doesn't originate from the NVIDIA NGX.
     Other(String),
+    /// The `error` with the context of the call which failed.
+    WithContext {
+        /// The error itself.
+        error: Box<Error>,
+        /// The call which failed.
+        context: Box<ErrorContext>,
+    },
+}
+
+/// The context of a failed NGX call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    function: &'static str,
+    feature: Option<bindings::NVSDK_NGX_Feature>,
+    parameter: Option<String>,
+    render_resolution: Option<[u32; 2]>,
+    target_resolution: Option<[u32; 2]>,
+    quality: Option<bindings::NVSDK_NGX_PerfQuality_Value>,
+}
+
+impl ErrorContext {
+    /// Creates a context of a call to the NGX `function`.
+    pub(crate) fn new(function: &'static str) -> Self {
+        Self {
+            function,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the feature the call was made for.
+    pub(crate) fn feature(mut self, feature: bindings::NVSDK_NGX_Feature) -> Self {
+        self.feature = Some(feature);
+        self
+    }
+
+    /// Sets the name of the parameter the call was made for.
+    pub(crate) fn parameter(mut self, name: &[u8]) -> Self {
+        let name = name.split(|b| *b == 0).next().unwrap_or_default();
+        self.parameter = Some(String::from_utf8_lossy(name).into_owned());
+        self
+    }
+
+    /// Sets the render resolution (width and height) requested.
+    pub(crate) fn render_resolution(mut self, resolution: [u32; 2]) -> Self {
+        self.render_resolution = Some(resolution);
+        self
+    }
+
+    /// Sets the target resolution (width and height) requested.
+    pub(crate) fn target_resolution(mut self, resolution: [u32; 2]) -> Self {
+        self.target_resolution = Some(resolution);
+        self
+    }
+
+    /// Sets the quality level requested.
+    pub(crate) fn quality(mut self, quality: bindings::NVSDK_NGX_PerfQuality_Value) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Returns the name of the NGX function which failed.
+    pub fn get_function(&self) -> &'static str {
+        self.function
+    }
+
+    /// Returns the feature the call was made for.
+    pub fn get_feature(&self) -> Option<bindings::NVSDK_NGX_Feature> {
+        self.feature
+    }
+
+    /// Returns the name of the parameter the call was made for.
+    pub fn get_parameter(&self) -> Option<&str> {
+        self.parameter.as_deref()
+    }
+
+    /// Returns the render resolution (width and height) requested.
+    pub fn get_render_resolution(&self) -> Option<[u32; 2]> {
+        self.render_resolution
+    }
+
+    /// Returns the target resolution (width and height) requested.
+    pub fn get_target_resolution(&self) -> Option<[u32; 2]> {
+        self.target_resolution
+    }
+
+    /// Returns the quality level requested.
+    pub fn get_quality(&self) -> Option<bindings::NVSDK_NGX_PerfQuality_Value> {
+        self.quality
+    }
+}
+
+impl std::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "in {}", self.function)?;
+        if let Some(feature) = self.feature {
+            write!(f, ", feature={feature}")?;
+        }
+        if let Some(parameter) = &self.parameter {
+            write!(f, ", parameter={parameter}")?;
+        }
+        if let Some([width, height]) = self.render_resolution {
+            write!(f, ", render resolution={width}x{height}")?;
+        }
+        if let Some([width, height]) = self.target_resolution {
+            write!(f, ", target resolution={width}x{height}")?;
+        }
+        if let Some(quality) = self.quality {
+            write!(f, ", quality={quality:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Attaches the [`ErrorContext`] to the errors.
+pub(crate) trait ResultExt {
+    /// Attaches the context returned by `context` to the error, if any.
+    fn context(self, context: impl FnOnce() -> ErrorContext) -> Self;
+}
+
+impl<T> ResultExt for Result<T> {
+    fn context(self, context: impl FnOnce() -> ErrorContext) -> Self {
+        self.map_err(|error| error.with_context(context()))
+    }
+}
+
+/// Implements the conversions between the result codes and the
+/// variants of [`Error`] which correspond to them.
+macro_rules! result_code_variants {
+    ($($variant:ident => $code:ident,)+) => {
+        impl Error {
+            /// Returns the NGX result code this error corresponds to,
+            /// if it comes from NGX.
+            pub fn result(&self) -> Option<bindings::NVSDK_NGX_Result> {
+                match self.without_context() {
+                    $(Self::$variant => Some(bindings::NVSDK_NGX_Result::$code),)+
+                    Self::UnknownResult(code) => Some(bindings::NVSDK_NGX_Result(*code)),
+                    _ => None,
+                }
+            }
+        }
+
+        impl From<bindings::NVSDK_NGX_Result> for Error {
+            fn from(value: bindings::NVSDK_NGX_Result) -> Self {
+                match value {
+                    $(bindings::NVSDK_NGX_Result::$code => Self::$variant,)+
+                    code => Self::UnknownResult(code.0),
+                }
+            }
+        }
+
+        impl bindings::NVSDK_NGX_Result {
+            /// Returns the name of the result code, if it is known to
+            /// the NGX headers the crate was built with.
+            pub fn name(self) -> Option<&'static str> {
+                match self {
+                    Self::NVSDK_NGX_Result_Success => Some("NVSDK_NGX_Result_Success"),
+                    $(Self::$code => Some(stringify!($code)),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+result_code_variants! {
+    Fail => NVSDK_NGX_Result_Fail,
+    FeatureNotSupported => NVSDK_NGX_Result_FAIL_FeatureNotSupported,
+    PlatformError => NVSDK_NGX_Result_FAIL_PlatformError,
+    FeatureAlreadyExists => NVSDK_NGX_Result_FAIL_FeatureAlreadyExists,
+    FeatureNotFound => NVSDK_NGX_Result_FAIL_FeatureNotFound,
+    InvalidParameter => NVSDK_NGX_Result_FAIL_InvalidParameter,
+    ScratchBufferTooSmall => NVSDK_NGX_Result_FAIL_ScratchBufferTooSmall,
+    NotInitialized => NVSDK_NGX_Result_FAIL_NotInitialized,
+    UnsupportedInputFormat => NVSDK_NGX_Result_FAIL_UnsupportedInputFormat,
+    RwFlagMissing => NVSDK_NGX_Result_FAIL_RWFlagMissing,
+    MissingInput => NVSDK_NGX_Result_FAIL_MissingInput,
+    UnableToInitializeFeature => NVSDK_NGX_Result_FAIL_UnableToInitializeFeature,
+    OutOfDate => NVSDK_NGX_Result_FAIL_OutOfDate,
+    OutOfGpuMemory => NVSDK_NGX_Result_FAIL_OutOfGPUMemory,
+    UnsupportedFormat => NVSDK_NGX_Result_FAIL_UnsupportedFormat,
+    UnableToWriteToAppDataPath => NVSDK_NGX_Result_FAIL_UnableToWriteToAppDataPath,
+    UnsupportedParameter => NVSDK_NGX_Result_FAIL_UnsupportedParameter,
+    Denied => NVSDK_NGX_Result_FAIL_Denied,
+    NotImplemented => NVSDK_NGX_Result_FAIL_NotImplemented,
+}
+
+impl Error {
+    /// Attaches the `context` to the error.
+    pub(crate) fn with_context(self, context: ErrorContext) -> Self {
+        Self::WithContext {
+            error: Box::new(self),
+            context: Box::new(context),
+        }
+    }
+
+    /// Returns the context of the call which failed, if known.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Returns the error without the context, to match on.
+    pub fn without_context(&self) -> &Self {
+        match self {
+            Self::WithContext { error, .. } => error.without_context(),
+            error => error,
+        }
+    }
+
+    /// Returns `true` if the driver or the NGX libraries have to be
+    /// updated.
+    pub fn is_driver_outdated(&self) -> bool {
+        matches!(
+            self.without_context(),
+            Self::DriverOutdated { .. } | Self::OutOfDate
+        )
+    }
+
+    /// Returns `true` if the feature or the quality level requested
+    /// isn't supported on this system.
+    pub fn is_unsupported(&self) -> bool {
+        matches!(
+            self.without_context(),
+            Self::FeatureNotSupported
+                | Self::FeatureUnavailable(_)
+                | Self::QualityLevelUnsupported(_)
+                | Self::NotImplemented
+        )
+    }
+
+    /// Returns `true` if there isn't enough GPU memory.
+    pub fn is_out_of_memory(&self) -> bool {
+        matches!(self.without_context(), Self::OutOfGpuMemory)
+    }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Self::WithContext { error, context } = self {
+            return write!(f, "{error} ({context})");
+        }
+        if let Some(code) = self.result() {
+            return write!(f, "Internal error: code={code}");
+        }
+
         f.write_str(&match self {
-            Self::Internal(code) => {
-                format!("Internal error: code={code}")
+            Self::DriverOutdated {
+                feature,
+                min_driver_version_major,
+                min_driver_version_minor,
+            } => format!("The {feature} feature requires a driver update. The driver version required should be higher or equal to {min_driver_version_major}.{min_driver_version_minor}"),
+            Self::FeatureUnavailable(feature) => {
+                format!("The {feature} feature isn't supported on this platform.")
+            }
+            Self::QualityLevelUnsupported(quality) => {
+                format!("The requested quality level isn't supported: {quality:?}")
             }
+            Self::LibraryNotPresent(s) => format!("The NGX library isn't present: {s}"),
             Self::Other(s) => format!("Other error: {s}"),
+            _ => unreachable!("the result code errors are formatted above"),
         })
     }
 }
 
-impl From<bindings::NVSDK_NGX_Result> for Error {
-    fn from(value: bindings::NVSDK_NGX_Result) -> Self {
-        Self::Internal(value)
-    }
-}
+impl std::error::Error for Error {}
 
 impl From<String> for Error {
     fn from(value: String) -> Self {
@@ -43,18 +357,42 @@ impl<'a> From<&'a str> for Error {
     }
 }
 
+impl bindings::NVSDK_NGX_Result {
+    /// Returns `true` if the result code means success. Like the
+    /// `NVSDK_NGX_SUCCEED` macro of NGX, this only checks that the code
+    /// isn't in the failure range, so that the success codes unknown to
+    /// the headers the crate was built with are still successful.
+    pub fn is_success(self) -> bool {
+        const FAILURE_MASK: u32 = 0xFFF0_0000;
+
+        self.0 & FAILURE_MASK != Self::NVSDK_NGX_Result_Fail.0
+    }
+}
+
 impl From<bindings::NVSDK_NGX_Result> for Result {
     fn from(value: bindings::NVSDK_NGX_Result) -> Self {
-        match value {
-            bindings::NVSDK_NGX_Result::NVSDK_NGX_Result_Success => Ok(()),
-            code => Err(Error::Internal(code)),
+        if value.is_success() {
+            Ok(())
+        } else {
+            Err(value.into())
         }
     }
 }
 
 impl std::fmt::Display for bindings::NVSDK_NGX_Result {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let chars = unsafe { bindings::GetNGXResultAsString(*self as _) };
+        let fallback = |f: &mut std::fmt::Formatter<'_>| match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "NVSDK_NGX_Result({:#010X})", self.0),
+        };
+        let Ok(library) = crate::library::Library::get() else {
+            return fallback(f);
+        };
+        // NGX may not know the code either, and return nothing.
+        let chars = unsafe { (library.GetNGXResultAsString)(*self) };
+        if chars.is_null() {
+            return fallback(f);
+        }
         let length = unsafe { libc::wcslen(chars) };
         let string = unsafe { widestring::WideCString::from_ptr(chars.cast(), length) }
             .map_err(|_| std::fmt::Error)?;
@@ -77,4 +415,65 @@ mod tests {
             bindings::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_FeatureNotSupported.to_string();
         assert_eq!(string, "NVSDK_NGX_Result_FAIL_FeatureNotSupported");
     }
+
+    #[test]
+    fn result_codes_round_trip() {
+        let code = bindings::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfGPUMemory;
+        let error = super::Error::from(code);
+        assert_eq!(error, super::Error::OutOfGpuMemory);
+        assert_eq!(error.result(), Some(code));
+        assert!(error.is_out_of_memory());
+        assert!(!error.is_unsupported());
+        assert_eq!(super::Error::Other("test".to_owned()).result(), None);
+    }
+
+    #[test]
+    fn context() {
+        let context = super::ErrorContext::new("NVSDK_NGX_VULKAN_CreateFeature1")
+            .feature(bindings::NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling)
+            .render_resolution([1920, 1080])
+            .target_resolution([3840, 2160]);
+        let error = super::Error::OutOfGpuMemory.with_context(context.clone());
+        assert_eq!(error.context(), Some(&context));
+        assert_eq!(error.without_context(), &super::Error::OutOfGpuMemory);
+        assert!(error.is_out_of_memory());
+        assert_eq!(
+            error.result(),
+            Some(bindings::NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfGPUMemory)
+        );
+        assert!(error.to_string().ends_with(
+            "(in NVSDK_NGX_VULKAN_CreateFeature1, feature=NVSDK_NGX_Feature_SuperSampling, render resolution=1920x1080, target resolution=3840x2160)"
+        ));
+    }
+
+    #[test]
+    fn unknown_result_codes() {
+        // Past the last failure code known.
+        let code = bindings::NVSDK_NGX_Result(0xBAD0_0FFF);
+        assert!(!code.is_success());
+        assert_eq!(code.name(), None);
+        let error = crate::Result::from(code).unwrap_err();
+        assert_eq!(error, super::Error::UnknownResult(0xBAD0_0FFF));
+        assert_eq!(error.result(), Some(code));
+        assert!(!error.to_string().is_empty());
+
+        // A success code unknown to the headers is still a success.
+        assert!(crate::Result::from(bindings::NVSDK_NGX_Result(2)).is_ok());
+    }
+
+    #[test]
+    fn predicates() {
+        let outdated = super::Error::DriverOutdated {
+            feature: bindings::NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+            min_driver_version_major: 535,
+            min_driver_version_minor: 0,
+        };
+        assert!(outdated.is_driver_outdated());
+        assert!(super::Error::OutOfDate.is_driver_outdated());
+        assert!(super::Error::QualityLevelUnsupported(
+            bindings::NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_UltraQuality
+        )
+        .is_unsupported());
+        assert!(super::Error::FeatureNotSupported.is_unsupported());
+    }
 }
@@ -11,10 +11,39 @@
 #[allow(missing_docs)]
 /// The raw bindings to the NVIDIA NGX library.
 pub mod bindings {
+    #[cfg(feature = "generate-bindings")]
     include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+    #[cfg(not(feature = "generate-bindings"))]
+    include!("bindings.rs");
 }
 
+pub mod capabilities;
+pub use capabilities::*;
 pub mod error;
 pub use error::*;
+pub mod library;
+pub use library::is_library_present;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod parameter_key;
+pub use parameter_key::*;
 pub mod vk;
 pub use vk::*;
+
+#[cfg(test)]
+mod tests {
+    /// The pre-generated bindings must be what bindgen produces from the
+    /// pinned SDK headers.
+    #[test]
+    #[cfg(feature = "generate-bindings")]
+    fn pregenerated_bindings_are_up_to_date() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+        let pregenerated =
+            std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/src/bindings.rs"))
+                .unwrap_or_default();
+        assert!(
+            generated == pregenerated,
+            "src/bindings.rs is out of date, regenerate it with `NVNGX_UPDATE_BINDINGS=1 cargo build`."
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! The NVIDIA NGX entry points used by the crate.
+//!
+//! The crate links against the static `nvsdk_ngx` library of the SDK and
+//! the entry points are resolved by the linker. That library only loads
+//! the NGX core, which ships with the NVIDIA driver, when NGX is
+//! initialised. With the `detect-driver` feature enabled, the crate checks
+//! that the NGX core of the driver can be opened first, so that
+//! [`crate::System::new`] returns [`crate::Error::LibraryNotPresent`] on
+//! machines without it. With the `mock` feature enabled, the entry points
+//! are replaced with the fake ones from [`crate::mock`], taking precedence
+//! over the other modes.
+
+use crate::bindings::{
+    wchar_t, NVSDK_NGX_EngineType, NVSDK_NGX_Feature, NVSDK_NGX_FeatureCommonInfo,
+    NVSDK_NGX_FeatureDiscoveryInfo, NVSDK_NGX_FeatureRequirement, NVSDK_NGX_Handle,
+    NVSDK_NGX_Parameter, NVSDK_NGX_Result, NVSDK_NGX_Version, PFN_NVSDK_NGX_ProgressCallback_C,
+    PFN_vkGetDeviceProcAddr, PFN_vkGetInstanceProcAddr, VkCommandBuffer, VkDevice,
+    VkExtensionProperties, VkInstance, VkPhysicalDevice,
+};
+use std::ffi::{c_char, c_int, c_uint, c_ulonglong, c_void};
+
+/// The name of the environment variable which, if set, overrides the
+/// path the NGX core of the driver is looked up at in the `detect-driver`
+/// mode.
+pub const DRIVER_LIBRARY_PATH_ENVIRONMENT_VARIABLE: &str = "NVNGX_DRIVER_LIBRARY_PATH";
+
+/// The NGX core installed by the NVIDIA driver, which the SDK library
+/// loads on initialisation.
+#[cfg(all(feature = "detect-driver", not(feature = "mock")))]
+const DRIVER_LIBRARY_NAME: &str = "libnvidia-ngx.so.1";
+
+macro_rules! ngx_functions {
+    ($($(#[$meta:meta])* $name:ident($($argument:ident: $type:ty),* $(,)?) $(-> $return:ty)?;)+) => {
+        /// The table of the NGX entry points used by the crate.
+        #[allow(non_snake_case)]
+        #[derive(Copy, Clone)]
+        pub(crate) struct Library {
+            $(
+                $(#[$meta])*
+                pub(crate) $name: unsafe extern "C" fn($($argument: $type),*) $(-> $return)?,
+            )+
+        }
+
+        impl Library {
+            /// Returns the table of the entry points resolved by the
+            /// linker.
+            #[cfg(not(feature = "mock"))]
+            const fn linked() -> Self {
+                Self {
+                    $($name: crate::bindings::$name,)+
+                }
+            }
+        }
+    };
+}
+
+ngx_functions! {
+    GetNGXResultAsString(result: NVSDK_NGX_Result) -> *const wchar_t;
+    NVSDK_NGX_VULKAN_RequiredExtensions(
+        instance_extension_count: *mut c_uint,
+        instance_extensions: *mut *mut *const c_char,
+        device_extension_count: *mut c_uint,
+        device_extensions: *mut *mut *const c_char,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_Init_with_ProjectID(
+        project_id: *const c_char,
+        engine_type: NVSDK_NGX_EngineType,
+        engine_version: *const c_char,
+        application_data_path: *const wchar_t,
+        instance: VkInstance,
+        physical_device: VkPhysicalDevice,
+        device: VkDevice,
+        get_instance_proc_addr: PFN_vkGetInstanceProcAddr,
+        get_device_proc_addr: PFN_vkGetDeviceProcAddr,
+        feature_info: *const NVSDK_NGX_FeatureCommonInfo,
+        sdk_version: NVSDK_NGX_Version,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_Shutdown1(device: VkDevice) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_GetFeatureRequirements(
+        instance: VkInstance,
+        physical_device: VkPhysicalDevice,
+        feature_discovery_info: *const NVSDK_NGX_FeatureDiscoveryInfo,
+        supported: *mut NVSDK_NGX_FeatureRequirement,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_GetFeatureInstanceExtensionRequirements(
+        feature_discovery_info: *const NVSDK_NGX_FeatureDiscoveryInfo,
+        extension_count: *mut u32,
+        extension_properties: *mut *mut VkExtensionProperties,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_GetFeatureDeviceExtensionRequirements(
+        instance: VkInstance,
+        physical_device: VkPhysicalDevice,
+        feature_discovery_info: *const NVSDK_NGX_FeatureDiscoveryInfo,
+        extension_count: *mut u32,
+        extension_properties: *mut *mut VkExtensionProperties,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_AllocateParameters(
+        parameters: *mut *mut NVSDK_NGX_Parameter,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_GetCapabilityParameters(
+        parameters: *mut *mut NVSDK_NGX_Parameter,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_DestroyParameters(parameters: *mut NVSDK_NGX_Parameter) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_GetParameters(parameters: *mut *mut NVSDK_NGX_Parameter) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_GetScratchBufferSize(
+        feature: NVSDK_NGX_Feature,
+        parameters: *const NVSDK_NGX_Parameter,
+        size_in_bytes: *mut usize,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_CreateFeature1(
+        device: VkDevice,
+        command_buffer: VkCommandBuffer,
+        feature: NVSDK_NGX_Feature,
+        parameters: *mut NVSDK_NGX_Parameter,
+        handle: *mut *mut NVSDK_NGX_Handle,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_ReleaseFeature(handle: *mut NVSDK_NGX_Handle) -> NVSDK_NGX_Result;
+    NVSDK_NGX_VULKAN_EvaluateFeature_C(
+        command_buffer: VkCommandBuffer,
+        handle: *const NVSDK_NGX_Handle,
+        parameters: *const NVSDK_NGX_Parameter,
+        callback: PFN_NVSDK_NGX_ProgressCallback_C,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_Parameter_SetULL(parameters: *mut NVSDK_NGX_Parameter, name: *const c_char, value: c_ulonglong);
+    NVSDK_NGX_Parameter_SetF(parameters: *mut NVSDK_NGX_Parameter, name: *const c_char, value: f32);
+    NVSDK_NGX_Parameter_SetD(parameters: *mut NVSDK_NGX_Parameter, name: *const c_char, value: f64);
+    NVSDK_NGX_Parameter_SetUI(parameters: *mut NVSDK_NGX_Parameter, name: *const c_char, value: c_uint);
+    NVSDK_NGX_Parameter_SetI(parameters: *mut NVSDK_NGX_Parameter, name: *const c_char, value: c_int);
+    NVSDK_NGX_Parameter_SetVoidPointer(
+        parameters: *mut NVSDK_NGX_Parameter,
+        name: *const c_char,
+        value: *mut c_void,
+    );
+    NVSDK_NGX_Parameter_GetULL(
+        parameters: *mut NVSDK_NGX_Parameter,
+        name: *const c_char,
+        value: *mut c_ulonglong,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_Parameter_GetF(
+        parameters: *mut NVSDK_NGX_Parameter,
+        name: *const c_char,
+        value: *mut f32,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_Parameter_GetD(
+        parameters: *mut NVSDK_NGX_Parameter,
+        name: *const c_char,
+        value: *mut f64,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_Parameter_GetUI(
+        parameters: *mut NVSDK_NGX_Parameter,
+        name: *const c_char,
+        value: *mut c_uint,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_Parameter_GetI(
+        parameters: *mut NVSDK_NGX_Parameter,
+        name: *const c_char,
+        value: *mut c_int,
+    ) -> NVSDK_NGX_Result;
+    NVSDK_NGX_Parameter_GetVoidPointer(
+        parameters: *mut NVSDK_NGX_Parameter,
+        name: *const c_char,
+        value: *mut *mut c_void,
+    ) -> NVSDK_NGX_Result;
+}
+
+#[cfg(not(feature = "mock"))]
+static LINKED: Library = Library::linked();
+
+#[cfg(not(any(feature = "detect-driver", feature = "mock")))]
+impl Library {
+    /// Returns the entry points. Never fails, as the library is linked.
+    pub(crate) fn get() -> crate::Result<&'static Self> {
+        Ok(&LINKED)
+    }
+}
+
+#[cfg(all(feature = "detect-driver", not(feature = "mock")))]
+impl Library {
+    /// Returns the entry points once the NGX core of the driver is
+    /// found. It is looked up at the path set in
+    /// [`DRIVER_LIBRARY_PATH_ENVIRONMENT_VARIABLE`], or by its name in the
+    /// default search paths of the dynamic loader. A failed attempt
+    /// isn't cached, so the driver may be installed later.
+    pub(crate) fn get() -> crate::Result<&'static Self> {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        static PRESENT: AtomicBool = AtomicBool::new(false);
+
+        if PRESENT.load(Ordering::Acquire) {
+            return Ok(&LINKED);
+        }
+
+        let path = std::env::var_os(DRIVER_LIBRARY_PATH_ENVIRONMENT_VARIABLE)
+            .unwrap_or_else(|| DRIVER_LIBRARY_NAME.into());
+        // The library is only opened to check that it can be, NGX opens
+        // it again on its own when initialised.
+        unsafe { libloading::Library::new(&path) }.map_err(|e| {
+            crate::Error::LibraryNotPresent(format!("{}: {e}", path.to_string_lossy()))
+        })?;
+
+        PRESENT.store(true, Ordering::Release);
+        Ok(&LINKED)
+    }
+}
+
+#[cfg(feature = "mock")]
+impl Library {
+    /// Returns the mocked entry points.
+    pub(crate) fn get() -> crate::Result<&'static Self> {
+        Ok(&crate::mock::LIBRARY)
+    }
+}
+
+impl Library {
+    /// Returns the entry points of an already loaded library.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the library couldn't be loaded. This can't happen for
+    /// the objects which could only have been created via the library,
+    /// like the feature parameters, so this is only to be used there.
+    pub(crate) fn loaded() -> &'static Self {
+        Self::get().expect("the NGX library must be loaded at this point")
+    }
+}
+
+/// Returns `true` if the NGX core of the driver is available, so that
+/// the [`crate::System`] could be created. Always `true` unless the
+/// crate is built with the `detect-driver` feature.
+pub fn is_library_present() -> bool {
+    Library::get().is_ok()
+}
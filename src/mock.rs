@@ -0,0 +1,1014 @@
+//! An in-process fake of the NGX entry points, used instead of the NGX
+//! library when the crate is built with the `mock` feature. It allows
+//! testing the code using NGX on machines without an NVIDIA GPU.
+//!
+//! The fake keeps real parameter maps, fills the capability parameter
+//! maps with the configured values, computes the optimal settings from
+//! a configurable table and records the features created, evaluated
+//! and released. Any entry point can be made to fail with a result code
+//! of choice with [`set_result`].
+//!
+//! The state of the mock is per thread, so that the tests running in
+//! parallel don't interfere with each other. All the objects must be
+//! used on the thread they were created on.
+//!
+//! ```ignore
+//! use nvngx::bindings::NVSDK_NGX_Result;
+//!
+//! nvngx::mock::reset();
+//! nvngx::mock::set_result(
+//!     nvngx::mock::Function::CreateFeature,
+//!     NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_FeatureNotSupported,
+//! );
+//! let system = nvngx::mock::create_system()?;
+//! ```
+
+use crate::bindings::{
+    self, wchar_t, NVSDK_NGX_Application_Identifier_Type, NVSDK_NGX_EngineType, NVSDK_NGX_Feature,
+    NVSDK_NGX_FeatureCommonInfo, NVSDK_NGX_FeatureDiscoveryInfo, NVSDK_NGX_FeatureRequirement,
+    NVSDK_NGX_Handle, NVSDK_NGX_Parameter, NVSDK_NGX_PerfQuality_Value, NVSDK_NGX_Result,
+    NVSDK_NGX_Version, PFN_NVSDK_NGX_ProgressCallback_C, PFN_vkGetDeviceProcAddr,
+    PFN_vkGetInstanceProcAddr, VkCommandBuffer, VkDevice, VkExtensionProperties, VkInstance,
+    VkPhysicalDevice,
+};
+use crate::library::Library;
+use crate::FeatureParameterName;
+use ash::vk::{self, Handle};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::{c_char, c_int, c_uint, c_ulonglong, c_void, CStr, CString};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A value stored in a mocked parameter map.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Value {
+    /// Set with `NVSDK_NGX_Parameter_SetULL`.
+    U64(u64),
+    /// Set with `NVSDK_NGX_Parameter_SetF`.
+    F32(f32),
+    /// Set with `NVSDK_NGX_Parameter_SetD`.
+    F64(f64),
+    /// Set with `NVSDK_NGX_Parameter_SetUI`.
+    U32(u32),
+    /// Set with `NVSDK_NGX_Parameter_SetI`.
+    I32(i32),
+    /// Set with `NVSDK_NGX_Parameter_SetVoidPointer`.
+    Pointer(*mut c_void),
+}
+
+/// The mocked entry points which can be made to fail with
+/// [`set_result`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Function {
+    /// `NVSDK_NGX_VULKAN_RequiredExtensions`.
+    RequiredExtensions,
+    /// `NVSDK_NGX_VULKAN_Init_with_ProjectID`.
+    Init,
+    /// `NVSDK_NGX_VULKAN_Shutdown1`.
+    Shutdown,
+    /// `NVSDK_NGX_VULKAN_GetFeatureRequirements`.
+    GetFeatureRequirements,
+    /// `NVSDK_NGX_VULKAN_GetFeatureInstanceExtensionRequirements`.
+    GetFeatureInstanceExtensionRequirements,
+    /// `NVSDK_NGX_VULKAN_GetFeatureDeviceExtensionRequirements`.
+    GetFeatureDeviceExtensionRequirements,
+    /// `NVSDK_NGX_VULKAN_AllocateParameters`.
+    AllocateParameters,
+    /// `NVSDK_NGX_VULKAN_GetCapabilityParameters`.
+    GetCapabilityParameters,
+    /// `NVSDK_NGX_VULKAN_DestroyParameters`.
+    DestroyParameters,
+    /// `NVSDK_NGX_VULKAN_GetParameters`.
+    GetParameters,
+    /// `NVSDK_NGX_VULKAN_GetScratchBufferSize`.
+    GetScratchBufferSize,
+    /// `NVSDK_NGX_VULKAN_CreateFeature1`.
+    CreateFeature,
+    /// `NVSDK_NGX_VULKAN_ReleaseFeature`.
+    ReleaseFeature,
+    /// `NVSDK_NGX_VULKAN_EvaluateFeature_C`.
+    EvaluateFeature,
+    /// The DLSS optimal settings callback, stored in the capability
+    /// parameters.
+    OptimalSettings,
+}
+
+/// A recorded call creating a feature.
+#[derive(Debug, Clone)]
+pub struct CreatedFeature {
+    /// The id of the handle returned for the feature.
+    pub id: u32,
+    /// The type of the feature created.
+    pub feature: NVSDK_NGX_Feature,
+    /// The parameters the feature was created with.
+    pub parameters: HashMap<String, Value>,
+}
+
+impl CreatedFeature {
+    /// Returns the value of the parameter named `name` the feature was
+    /// created with.
+    pub fn get(&self, name: &FeatureParameterName) -> Option<Value> {
+        self.parameters.get(&key(name)).copied()
+    }
+}
+
+/// A recorded call evaluating a feature.
+#[derive(Debug, Clone)]
+pub struct Evaluation {
+    /// The id of the handle of the feature evaluated.
+    pub id: u32,
+    /// The parameters the feature was evaluated with.
+    pub parameters: HashMap<String, Value>,
+    /// Whether the progress callback cancelled the evaluation.
+    pub cancelled: bool,
+}
+
+impl Evaluation {
+    /// Returns the value of the parameter named `name` the feature was
+    /// evaluated with.
+    pub fn get(&self, name: &FeatureParameterName) -> Option<Value> {
+        self.parameters.get(&key(name)).copied()
+    }
+}
+
+/// A recorded call discovering the requirements of a feature.
+#[derive(Debug, Clone)]
+pub struct Discovery {
+    /// The type of the feature discovered.
+    pub feature: NVSDK_NGX_Feature,
+    /// The identifier of the application discovering the feature.
+    pub identifier: crate::ApplicationIdentifier,
+    /// The application data path.
+    pub application_data_path: std::path::PathBuf,
+    /// The paths to load the feature from.
+    pub feature_paths: Vec<std::path::PathBuf>,
+}
+
+/// The state of the mock of the current thread.
+struct State {
+    results: HashMap<Function, NVSDK_NGX_Result>,
+    capabilities: HashMap<String, Value>,
+    /// The scale of the render resolution per quality level.
+    optimal_settings: HashMap<i32, f32>,
+    scratch_buffer_size: usize,
+    instance_extensions: Vec<CString>,
+    instance_extension_pointers: Vec<*const c_char>,
+    device_extensions: Vec<CString>,
+    device_extension_pointers: Vec<*const c_char>,
+    result_strings: HashMap<u32, Vec<wchar_t>>,
+    is_initialised: bool,
+    feature_paths: Vec<std::path::PathBuf>,
+    /// The requirements reported per feature, all the features are
+    /// supported otherwise.
+    feature_requirements: HashMap<u32, crate::FeatureRequirement>,
+    discoveries: Vec<Discovery>,
+    /// The instance and device extensions reported per feature.
+    feature_extensions: HashMap<u32, (Vec<VkExtensionProperties>, Vec<VkExtensionProperties>)>,
+    next_feature_id: u32,
+    parameter_maps: usize,
+    /// The parameter map returned by `NVSDK_NGX_VULKAN_GetParameters`,
+    /// owned by the mock until the shutdown.
+    driver_parameters: *mut NVSDK_NGX_Parameter,
+    /// The number of calls per entry point.
+    calls: HashMap<Function, usize>,
+    created_features: Vec<CreatedFeature>,
+    released_features: Vec<u32>,
+    evaluations: Vec<Evaluation>,
+    /// The progress reported to the callback of each evaluation.
+    evaluation_progress: Vec<f32>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let mut capabilities = HashMap::new();
+        let callback: unsafe extern "C" fn(*mut NVSDK_NGX_Parameter) -> NVSDK_NGX_Result =
+            optimal_settings_callback;
+        let capability_values = [
+            (
+                &bindings::NVSDK_NGX_Parameter_SuperSampling_Available[..],
+                Value::I32(1),
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_SuperSampling_NeedsUpdatedDriver,
+                Value::I32(0),
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMajor,
+                Value::U32(0),
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMinor,
+                Value::U32(0),
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_SuperSampling_FeatureInitResult,
+                Value::I32(1),
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_IsDevSnippetBranch,
+                Value::I32(0),
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_DLSSOptimalSettingsCallback,
+                Value::Pointer(callback as *mut c_void),
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_DLSSDOptimalSettingsCallback,
+                Value::Pointer(callback as *mut c_void),
+            ),
+        ];
+        for (name, value) in capability_values {
+            capabilities.insert(key(name), value);
+        }
+
+        // The scales DLSS uses for each of the quality levels. The
+        // ultra quality isn't supported.
+        let optimal_settings = [
+            (
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxPerf,
+                0.5,
+            ),
+            (
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_Balanced,
+                0.58,
+            ),
+            (
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxQuality,
+                0.667,
+            ),
+            (
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_UltraPerformance,
+                0.333,
+            ),
+            (
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_DLAA,
+                1.0,
+            ),
+        ]
+        .into_iter()
+        .map(|(quality, scale)| (quality as i32, scale))
+        .collect();
+
+        Self {
+            results: HashMap::new(),
+            capabilities,
+            optimal_settings,
+            scratch_buffer_size: 0,
+            instance_extensions: Vec::new(),
+            instance_extension_pointers: Vec::new(),
+            device_extensions: Vec::new(),
+            device_extension_pointers: Vec::new(),
+            result_strings: HashMap::new(),
+            is_initialised: false,
+            feature_paths: Vec::new(),
+            feature_requirements: HashMap::new(),
+            discoveries: Vec::new(),
+            feature_extensions: HashMap::new(),
+            next_feature_id: 1,
+            parameter_maps: 0,
+            driver_parameters: std::ptr::null_mut(),
+            calls: HashMap::new(),
+            created_features: Vec::new(),
+            released_features: Vec::new(),
+            evaluations: Vec::new(),
+            evaluation_progress: vec![1.0],
+        }
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        self.destroy_driver_parameters();
+    }
+}
+
+impl State {
+    /// Destroys the parameter map returned by
+    /// `NVSDK_NGX_VULKAN_GetParameters`, as NGX does on shutdown.
+    fn destroy_driver_parameters(&mut self) {
+        if !self.driver_parameters.is_null() {
+            drop(unsafe { Box::from_raw(self.driver_parameters.cast::<ParameterMap>()) });
+            self.driver_parameters = std::ptr::null_mut();
+        }
+    }
+
+    /// Returns the result injected for the `function`, if it is a
+    /// failure.
+    fn failure(&self, function: Function) -> Option<NVSDK_NGX_Result> {
+        self.results
+            .get(&function)
+            .copied()
+            .filter(|r| *r != NVSDK_NGX_Result::NVSDK_NGX_Result_Success)
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+fn with_state<T>(f: impl FnOnce(&mut State) -> T) -> T {
+    STATE.with(|state| f(&mut state.borrow_mut()))
+}
+
+/// Returns the key of the parameter map for the NUL-terminated `name`.
+fn key(name: &FeatureParameterName) -> String {
+    let name = name.split(|b| *b == 0).next().unwrap_or_default();
+    String::from_utf8_lossy(name).into_owned()
+}
+
+type ParameterMap = HashMap<String, Value>;
+
+/// Returns the parameter map behind the `parameters` pointer.
+///
+/// # Safety
+///
+/// The `parameters` must have been allocated by the mock and not yet
+/// destroyed.
+unsafe fn parameter_map<'a>(parameters: *mut NVSDK_NGX_Parameter) -> &'a mut ParameterMap {
+    &mut *parameters.cast::<ParameterMap>()
+}
+
+fn allocate_parameter_map(map: ParameterMap) -> *mut NVSDK_NGX_Parameter {
+    with_state(|state| state.parameter_maps += 1);
+    Box::into_raw(Box::new(map)).cast()
+}
+
+/// Resets the mock of the current thread to its default state: every
+/// entry point succeeds, the super sampling is available, the optimal
+/// settings use the scales of DLSS, and nothing is recorded.
+pub fn reset() {
+    with_state(|state| *state = State::default());
+}
+
+/// Makes the `function` return the `result` until the mock is
+/// [`reset`] or another result is set.
+pub fn set_result(function: Function, result: NVSDK_NGX_Result) {
+    with_state(|state| {
+        state.results.insert(function, result);
+    });
+}
+
+/// Sets the value of the parameter named `name` in the capability
+/// parameter maps returned afterwards.
+pub fn set_capability(name: &FeatureParameterName, value: Value) {
+    with_state(|state| {
+        state.capabilities.insert(key(name), value);
+    });
+}
+
+/// Removes the parameter named `name` from the capability parameter
+/// maps returned afterwards.
+pub fn remove_capability(name: &FeatureParameterName) {
+    with_state(|state| {
+        state.capabilities.remove(&key(name));
+    });
+}
+
+/// Sets the scale of the render resolution relative to the target
+/// resolution, returned by the optimal settings callback for the
+/// `quality`. [`None`] makes the quality level unsupported.
+pub fn set_optimal_settings(quality: NVSDK_NGX_PerfQuality_Value, scale: Option<f32>) {
+    with_state(|state| match scale {
+        Some(scale) => {
+            state.optimal_settings.insert(quality as i32, scale);
+        }
+        None => {
+            state.optimal_settings.remove(&(quality as i32));
+        }
+    });
+}
+
+/// Sets the scratch buffer size returned for every feature.
+pub fn set_scratch_buffer_size(size: usize) {
+    with_state(|state| state.scratch_buffer_size = size);
+}
+
+/// Sets the Vulkan extensions reported as required.
+pub fn set_required_extensions(instance: &[&str], device: &[&str]) {
+    let to_c_strings = |extensions: &[&str]| -> Vec<CString> {
+        extensions
+            .iter()
+            .map(|e| CString::new(*e).expect("an extension name without NUL"))
+            .collect()
+    };
+    with_state(|state| {
+        state.instance_extensions = to_c_strings(instance);
+        state.instance_extension_pointers = state
+            .instance_extensions
+            .iter()
+            .map(|e| e.as_ptr())
+            .collect();
+        state.device_extensions = to_c_strings(device);
+        state.device_extension_pointers =
+            state.device_extensions.iter().map(|e| e.as_ptr()).collect();
+    });
+}
+
+/// Sets the requirements reported for the `feature`.
+pub fn set_feature_requirement(feature: NVSDK_NGX_Feature, requirement: crate::FeatureRequirement) {
+    with_state(|state| {
+        state.feature_requirements.insert(feature.0, requirement);
+    });
+}
+
+/// Sets the Vulkan instance and device extensions, with their spec
+/// versions, reported as required by the `feature`.
+pub fn set_feature_extension_requirements(
+    feature: NVSDK_NGX_Feature,
+    instance: &[(&str, u32)],
+    device: &[(&str, u32)],
+) {
+    let to_properties = |extensions: &[(&str, u32)]| -> Vec<VkExtensionProperties> {
+        extensions
+            .iter()
+            .map(|(name, spec_version)| {
+                let mut properties: VkExtensionProperties = unsafe { std::mem::zeroed() };
+                assert!(name.len() < properties.extensionName.len());
+                for (destination, source) in properties.extensionName.iter_mut().zip(name.bytes()) {
+                    *destination = source as c_char;
+                }
+                properties.specVersion = *spec_version;
+                properties
+            })
+            .collect()
+    };
+    with_state(|state| {
+        state
+            .feature_extensions
+            .insert(feature.0, (to_properties(instance), to_properties(device)));
+    });
+}
+
+/// Returns the feature discoveries so far.
+pub fn discoveries() -> Vec<Discovery> {
+    with_state(|state| state.discoveries.clone())
+}
+
+/// Returns `true` if NGX is initialised and not shut down yet.
+pub fn is_initialised() -> bool {
+    with_state(|state| state.is_initialised)
+}
+
+/// Returns the paths NGX was initialised to load the features from.
+pub fn feature_paths() -> Vec<std::path::PathBuf> {
+    with_state(|state| state.feature_paths.clone())
+}
+
+/// Returns the number of parameter maps allocated and not destroyed.
+pub fn live_parameter_maps() -> usize {
+    with_state(|state| state.parameter_maps)
+}
+
+/// Returns the number of calls to the `function` so far.
+pub fn call_count(function: Function) -> usize {
+    with_state(|state| state.calls.get(&function).copied().unwrap_or_default())
+}
+
+/// Returns the features created so far.
+pub fn created_features() -> Vec<CreatedFeature> {
+    with_state(|state| state.created_features.clone())
+}
+
+/// Returns the ids of the features released so far.
+pub fn released_features() -> Vec<u32> {
+    with_state(|state| state.released_features.clone())
+}
+
+/// Returns the feature evaluations so far.
+pub fn evaluations() -> Vec<Evaluation> {
+    with_state(|state| state.evaluations.clone())
+}
+
+/// Sets the progress reported to the callback of the evaluations, in
+/// order, until the callback cancels. Only the completion is reported
+/// by default.
+pub fn set_evaluation_progress(progress: &[f32]) {
+    with_state(|state| state.evaluation_progress = progress.to_vec());
+}
+
+/// Returns a logical device handle which doesn't exist, but is unique
+/// in the process, so that a [`crate::System`] can be created for it.
+pub fn fake_device() -> vk::Device {
+    static NEXT_DEVICE: AtomicU64 = AtomicU64::new(1);
+    vk::Device::from_raw(NEXT_DEVICE.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Creates a [`crate::System`] with Vulkan objects which don't exist,
+/// as the mock never uses them.
+pub fn create_system() -> crate::Result<crate::System> {
+    unsafe extern "system" fn get_instance_proc_addr(
+        _instance: vk::Instance,
+        _name: *const c_char,
+    ) -> vk::PFN_vkVoidFunction {
+        None
+    }
+
+    let static_fn = vk::StaticFn {
+        get_instance_proc_addr,
+    };
+    let entry = unsafe { ash::Entry::from_static_fn(static_fn.clone()) };
+    let instance = unsafe { ash::Instance::load(&static_fn, vk::Instance::null()) };
+    crate::System::new(
+        None,
+        env!("CARGO_PKG_VERSION"),
+        &std::env::temp_dir(),
+        &entry,
+        &instance,
+        vk::PhysicalDevice::null(),
+        fake_device(),
+    )
+}
+
+/// Returns the injected failure of the `function`, if any, from the
+/// enclosing mocked entry point.
+macro_rules! return_injected_failure {
+    ($function:expr) => {
+        if let Some(result) = with_state(|state| {
+            *state.calls.entry($function).or_default() += 1;
+            state.failure($function)
+        }) {
+            return result;
+        }
+    };
+}
+
+/// Fails the enclosing mocked entry point if NGX isn't initialised.
+macro_rules! ensure_initialised {
+    () => {
+        if !is_initialised() {
+            return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_NotInitialized;
+        }
+    };
+}
+
+/// Returns the name of the `result`, or null if it isn't known.
+unsafe extern "C" fn get_result_as_string(result: NVSDK_NGX_Result) -> *const wchar_t {
+    let Some(name) = result.name() else {
+        return std::ptr::null();
+    };
+    with_state(|state| {
+        state
+            .result_strings
+            .entry(result.0)
+            .or_insert_with(|| {
+                name.chars()
+                    .map(|c| c as wchar_t)
+                    .chain(std::iter::once(0))
+                    .collect()
+            })
+            .as_ptr()
+    })
+}
+
+unsafe extern "C" fn required_extensions(
+    instance_extension_count: *mut c_uint,
+    instance_extensions: *mut *mut *const c_char,
+    device_extension_count: *mut c_uint,
+    device_extensions: *mut *mut *const c_char,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::RequiredExtensions);
+    with_state(|state| {
+        *instance_extension_count = state.instance_extension_pointers.len() as c_uint;
+        *instance_extensions = state.instance_extension_pointers.as_mut_ptr();
+        *device_extension_count = state.device_extension_pointers.len() as c_uint;
+        *device_extensions = state.device_extension_pointers.as_mut_ptr();
+    });
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn init(
+    _project_id: *const c_char,
+    _engine_type: NVSDK_NGX_EngineType,
+    _engine_version: *const c_char,
+    _application_data_path: *const wchar_t,
+    _instance: VkInstance,
+    _physical_device: VkPhysicalDevice,
+    _device: VkDevice,
+    _get_instance_proc_addr: PFN_vkGetInstanceProcAddr,
+    _get_device_proc_addr: PFN_vkGetDeviceProcAddr,
+    feature_info: *const NVSDK_NGX_FeatureCommonInfo,
+    _sdk_version: NVSDK_NGX_Version,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::Init);
+    let feature_paths = read_feature_paths(feature_info);
+    with_state(|state| {
+        state.is_initialised = true;
+        state.feature_paths = feature_paths;
+    });
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+/// Returns the paths to load the features from in the `feature_info`.
+unsafe fn read_feature_paths(
+    feature_info: *const NVSDK_NGX_FeatureCommonInfo,
+) -> Vec<std::path::PathBuf> {
+    let mut feature_paths = Vec::new();
+    if let Some(feature_info) = feature_info.as_ref() {
+        let paths = &feature_info.PathListInfo;
+        for i in 0..paths.Length as usize {
+            let path = widestring::WideCStr::from_ptr_str((*paths.Path.add(i)).cast());
+            feature_paths.push(path.to_os_string().into());
+        }
+    }
+    feature_paths
+}
+
+/// Records the discovery described by the `feature_discovery_info`,
+/// returning it, or [`None`] if the info is invalid.
+unsafe fn record_discovery(
+    feature_discovery_info: *const NVSDK_NGX_FeatureDiscoveryInfo,
+) -> Option<Discovery> {
+    let info = feature_discovery_info.as_ref()?;
+    let identifier = match info.Identifier.IdentifierType {
+        NVSDK_NGX_Application_Identifier_Type::NVSDK_NGX_Application_Identifier_Type_Application_Id => {
+            crate::ApplicationIdentifier::ApplicationId(info.Identifier.v.ApplicationId)
+        }
+        _ => {
+            let description = info.Identifier.v.ProjectDesc;
+            let project_id = CStr::from_ptr(description.ProjectId).to_string_lossy();
+            crate::ApplicationIdentifier::ProjectId {
+                project_id: uuid::Uuid::parse_str(&project_id).ok()?,
+                engine_version: CStr::from_ptr(description.EngineVersion)
+                    .to_string_lossy()
+                    .into_owned(),
+            }
+        }
+    };
+    let discovery = Discovery {
+        feature: info.FeatureID,
+        identifier,
+        application_data_path: widestring::WideCStr::from_ptr_str(info.ApplicationDataPath.cast())
+            .to_os_string()
+            .into(),
+        feature_paths: read_feature_paths(info.FeatureInfo),
+    };
+    with_state(|state| state.discoveries.push(discovery.clone()));
+    Some(discovery)
+}
+
+unsafe extern "C" fn get_feature_requirements(
+    _instance: VkInstance,
+    _physical_device: VkPhysicalDevice,
+    feature_discovery_info: *const NVSDK_NGX_FeatureDiscoveryInfo,
+    supported: *mut NVSDK_NGX_FeatureRequirement,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::GetFeatureRequirements);
+    if supported.is_null() {
+        return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter;
+    }
+    let Some(discovery) = record_discovery(feature_discovery_info) else {
+        return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter;
+    };
+    let requirement = with_state(|state| {
+        state
+            .feature_requirements
+            .get(&discovery.feature.0)
+            .cloned()
+    });
+
+    let mut raw: NVSDK_NGX_FeatureRequirement = std::mem::zeroed();
+    if let Some(requirement) = requirement {
+        raw.FeatureSupported = requirement.support;
+        raw.MinHWArchitecture = requirement.min_hw_architecture;
+        let length = requirement
+            .min_os_version
+            .len()
+            .min(raw.MinOSVersion.len() - 1);
+        for (destination, source) in raw
+            .MinOSVersion
+            .iter_mut()
+            .zip(&requirement.min_os_version.as_bytes()[..length])
+        {
+            *destination = *source as c_char;
+        }
+    }
+    *supported = raw;
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+/// Returns the extensions set for the feature of the discovery, which
+/// stay owned by the mock, like NGX does.
+unsafe fn feature_extension_requirements(
+    feature_discovery_info: *const NVSDK_NGX_FeatureDiscoveryInfo,
+    device: bool,
+    extension_count: *mut u32,
+    extension_properties: *mut *mut VkExtensionProperties,
+) -> NVSDK_NGX_Result {
+    if extension_count.is_null() || extension_properties.is_null() {
+        return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter;
+    }
+    let Some(discovery) = record_discovery(feature_discovery_info) else {
+        return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter;
+    };
+    with_state(|state| {
+        let extensions = state
+            .feature_extensions
+            .entry(discovery.feature.0)
+            .or_default();
+        let extensions = if device {
+            &mut extensions.1
+        } else {
+            &mut extensions.0
+        };
+        *extension_count = extensions.len() as u32;
+        *extension_properties = extensions.as_mut_ptr();
+    });
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn get_feature_instance_extension_requirements(
+    feature_discovery_info: *const NVSDK_NGX_FeatureDiscoveryInfo,
+    extension_count: *mut u32,
+    extension_properties: *mut *mut VkExtensionProperties,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::GetFeatureInstanceExtensionRequirements);
+    feature_extension_requirements(
+        feature_discovery_info,
+        false,
+        extension_count,
+        extension_properties,
+    )
+}
+
+unsafe extern "C" fn get_feature_device_extension_requirements(
+    _instance: VkInstance,
+    _physical_device: VkPhysicalDevice,
+    feature_discovery_info: *const NVSDK_NGX_FeatureDiscoveryInfo,
+    extension_count: *mut u32,
+    extension_properties: *mut *mut VkExtensionProperties,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::GetFeatureDeviceExtensionRequirements);
+    feature_extension_requirements(
+        feature_discovery_info,
+        true,
+        extension_count,
+        extension_properties,
+    )
+}
+
+unsafe extern "C" fn shutdown(_device: VkDevice) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::Shutdown);
+    with_state(|state| {
+        state.is_initialised = false;
+        state.destroy_driver_parameters();
+    });
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn allocate_parameters(
+    parameters: *mut *mut NVSDK_NGX_Parameter,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::AllocateParameters);
+    ensure_initialised!();
+    *parameters = allocate_parameter_map(ParameterMap::new());
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn get_capability_parameters(
+    parameters: *mut *mut NVSDK_NGX_Parameter,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::GetCapabilityParameters);
+    ensure_initialised!();
+    let capabilities = with_state(|state| state.capabilities.clone());
+    *parameters = allocate_parameter_map(capabilities);
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn get_parameters(parameters: *mut *mut NVSDK_NGX_Parameter) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::GetParameters);
+    ensure_initialised!();
+    *parameters = with_state(|state| {
+        if state.driver_parameters.is_null() {
+            let map = Box::new(state.capabilities.clone());
+            state.driver_parameters = Box::into_raw(map).cast();
+        }
+        state.driver_parameters
+    });
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn destroy_parameters(parameters: *mut NVSDK_NGX_Parameter) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::DestroyParameters);
+    // NGX would crash on the map returned by GetParameters.
+    if parameters.is_null() || parameters == with_state(|state| state.driver_parameters) {
+        return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter;
+    }
+    drop(Box::from_raw(parameters.cast::<ParameterMap>()));
+    with_state(|state| state.parameter_maps -= 1);
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn get_scratch_buffer_size(
+    _feature: NVSDK_NGX_Feature,
+    _parameters: *const NVSDK_NGX_Parameter,
+    size_in_bytes: *mut usize,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::GetScratchBufferSize);
+    *size_in_bytes = with_state(|state| state.scratch_buffer_size);
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn create_feature(
+    _device: VkDevice,
+    _command_buffer: VkCommandBuffer,
+    feature: NVSDK_NGX_Feature,
+    parameters: *mut NVSDK_NGX_Parameter,
+    handle: *mut *mut NVSDK_NGX_Handle,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::CreateFeature);
+    ensure_initialised!();
+    if parameters.is_null() || handle.is_null() {
+        return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter;
+    }
+    let parameters = parameter_map(parameters).clone();
+    let id = with_state(|state| {
+        let id = state.next_feature_id;
+        state.next_feature_id += 1;
+        state.created_features.push(CreatedFeature {
+            id,
+            feature,
+            parameters,
+        });
+        id
+    });
+    *handle = Box::into_raw(Box::new(NVSDK_NGX_Handle { Id: id }));
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn release_feature(handle: *mut NVSDK_NGX_Handle) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::ReleaseFeature);
+    ensure_initialised!();
+    if handle.is_null() {
+        return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter;
+    }
+    let handle = Box::from_raw(handle);
+    with_state(|state| state.released_features.push(handle.Id));
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn evaluate_feature(
+    _command_buffer: VkCommandBuffer,
+    handle: *const NVSDK_NGX_Handle,
+    parameters: *const NVSDK_NGX_Parameter,
+    callback: PFN_NVSDK_NGX_ProgressCallback_C,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::EvaluateFeature);
+    if handle.is_null() || parameters.is_null() {
+        return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter;
+    }
+    let id = (*handle).Id;
+    let parameters = parameter_map(parameters as *mut _).clone();
+    let progress = with_state(|state| state.evaluation_progress.clone());
+    // The state isn't borrowed here, so the callback may call into the
+    // mock.
+    let mut cancelled = false;
+    if let Some(callback) = callback {
+        for progress in progress {
+            callback(progress, &mut cancelled);
+            if cancelled {
+                break;
+            }
+        }
+    }
+    let evaluation = Evaluation {
+        id,
+        parameters,
+        cancelled,
+    };
+    with_state(|state| state.evaluations.push(evaluation));
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+unsafe extern "C" fn optimal_settings_callback(
+    parameters: *mut NVSDK_NGX_Parameter,
+) -> NVSDK_NGX_Result {
+    return_injected_failure!(Function::OptimalSettings);
+    let map = parameter_map(parameters);
+    let get = |name: &FeatureParameterName| match map.get(&key(name)) {
+        Some(Value::U32(value)) => Some(*value as f32),
+        Some(Value::I32(value)) => Some(*value as f32),
+        _ => None,
+    };
+    let (Some(width), Some(height), Some(quality)) = (
+        get(bindings::NVSDK_NGX_Parameter_Width),
+        get(bindings::NVSDK_NGX_Parameter_Height),
+        get(bindings::NVSDK_NGX_Parameter_PerfQualityValue),
+    ) else {
+        return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter;
+    };
+    // An unsupported quality level is reported with the zero size.
+    let scale = with_state(|state| state.optimal_settings.get(&(quality as i32)).copied())
+        .unwrap_or_default();
+    map.insert(
+        key(bindings::NVSDK_NGX_Parameter_OutWidth),
+        Value::U32((width * scale).round() as u32),
+    );
+    map.insert(
+        key(bindings::NVSDK_NGX_Parameter_OutHeight),
+        Value::U32((height * scale).round() as u32),
+    );
+    NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+}
+
+/// Generates the mocked `NVSDK_NGX_Parameter_Set*` entry point named
+/// `$name`, storing the value as the `$variant` of [`Value`].
+macro_rules! parameter_setter {
+    ($name:ident, $type:ty, $variant:ident) => {
+        unsafe extern "C" fn $name(
+            parameters: *mut NVSDK_NGX_Parameter,
+            name: *const c_char,
+            value: $type,
+        ) {
+            let name = CStr::from_ptr(name).to_string_lossy().into_owned();
+            parameter_map(parameters).insert(name, Value::$variant(value as _));
+        }
+    };
+}
+
+/// Generates the mocked `NVSDK_NGX_Parameter_Get*` entry point named
+/// `$name`. Like NGX does, the numeric values are converted to the type
+/// requested.
+macro_rules! parameter_getter {
+    ($name:ident, $type:ty) => {
+        unsafe extern "C" fn $name(
+            parameters: *mut NVSDK_NGX_Parameter,
+            name: *const c_char,
+            value: *mut $type,
+        ) -> NVSDK_NGX_Result {
+            let name = CStr::from_ptr(name).to_string_lossy();
+            *value = match parameter_map(parameters).get(name.as_ref()) {
+                Some(Value::U64(v)) => *v as $type,
+                Some(Value::F32(v)) => *v as $type,
+                Some(Value::F64(v)) => *v as $type,
+                Some(Value::U32(v)) => *v as $type,
+                Some(Value::I32(v)) => *v as $type,
+                Some(Value::Pointer(_)) => {
+                    return NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter
+                }
+                None => return NVSDK_NGX_Result::NVSDK_NGX_Result_Fail,
+            };
+            NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+        }
+    };
+}
+
+parameter_setter!(parameter_set_u64, c_ulonglong, U64);
+parameter_setter!(parameter_set_f32, f32, F32);
+parameter_setter!(parameter_set_f64, f64, F64);
+parameter_setter!(parameter_set_u32, c_uint, U32);
+parameter_setter!(parameter_set_i32, c_int, I32);
+parameter_setter!(parameter_set_pointer, *mut c_void, Pointer);
+parameter_getter!(parameter_get_u64, c_ulonglong);
+parameter_getter!(parameter_get_f32, f32);
+parameter_getter!(parameter_get_f64, f64);
+parameter_getter!(parameter_get_u32, c_uint);
+parameter_getter!(parameter_get_i32, c_int);
+
+unsafe extern "C" fn parameter_get_pointer(
+    parameters: *mut NVSDK_NGX_Parameter,
+    name: *const c_char,
+    value: *mut *mut c_void,
+) -> NVSDK_NGX_Result {
+    let name = CStr::from_ptr(name).to_string_lossy();
+    match parameter_map(parameters).get(name.as_ref()) {
+        Some(Value::Pointer(pointer)) => {
+            *value = *pointer;
+            NVSDK_NGX_Result::NVSDK_NGX_Result_Success
+        }
+        Some(_) => NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter,
+        None => NVSDK_NGX_Result::NVSDK_NGX_Result_Fail,
+    }
+}
+
+/// The mocked entry points.
+pub(crate) static LIBRARY: Library = Library {
+    GetNGXResultAsString: get_result_as_string,
+    NVSDK_NGX_VULKAN_RequiredExtensions: required_extensions,
+    NVSDK_NGX_VULKAN_Init_with_ProjectID: init,
+    NVSDK_NGX_VULKAN_Shutdown1: shutdown,
+    NVSDK_NGX_VULKAN_GetFeatureRequirements: get_feature_requirements,
+    NVSDK_NGX_VULKAN_GetFeatureInstanceExtensionRequirements:
+        get_feature_instance_extension_requirements,
+    NVSDK_NGX_VULKAN_GetFeatureDeviceExtensionRequirements:
+        get_feature_device_extension_requirements,
+    NVSDK_NGX_VULKAN_AllocateParameters: allocate_parameters,
+    NVSDK_NGX_VULKAN_GetCapabilityParameters: get_capability_parameters,
+    NVSDK_NGX_VULKAN_DestroyParameters: destroy_parameters,
+    NVSDK_NGX_VULKAN_GetParameters: get_parameters,
+    NVSDK_NGX_VULKAN_GetScratchBufferSize: get_scratch_buffer_size,
+    NVSDK_NGX_VULKAN_CreateFeature1: create_feature,
+    NVSDK_NGX_VULKAN_ReleaseFeature: release_feature,
+    NVSDK_NGX_VULKAN_EvaluateFeature_C: evaluate_feature,
+    NVSDK_NGX_Parameter_SetULL: parameter_set_u64,
+    NVSDK_NGX_Parameter_SetF: parameter_set_f32,
+    NVSDK_NGX_Parameter_SetD: parameter_set_f64,
+    NVSDK_NGX_Parameter_SetUI: parameter_set_u32,
+    NVSDK_NGX_Parameter_SetI: parameter_set_i32,
+    NVSDK_NGX_Parameter_SetVoidPointer: parameter_set_pointer,
+    NVSDK_NGX_Parameter_GetULL: parameter_get_u64,
+    NVSDK_NGX_Parameter_GetF: parameter_get_f32,
+    NVSDK_NGX_Parameter_GetD: parameter_get_f64,
+    NVSDK_NGX_Parameter_GetUI: parameter_get_u32,
+    NVSDK_NGX_Parameter_GetI: parameter_get_i32,
+    NVSDK_NGX_Parameter_GetVoidPointer: parameter_get_pointer,
+};
@@ -0,0 +1,271 @@
+//! Typed keys of the parameter maps.
+//!
+//! The parameter names in [`crate::bindings`] are plain NUL-terminated
+//! byte strings, which say nothing about the type of the value stored
+//! under them. A [`ParameterKey`] pairs the name with the type of its
+//! value, so that [`FeatureParameters::get`] and
+//! [`FeatureParameters::set`] can't read a value with the wrong
+//! accessor, and checks that the name is NUL-terminated.
+
+use std::borrow::Cow;
+use std::ffi::c_void;
+use std::marker::PhantomData;
+
+use crate::{FeatureParameterName, ParameterMap, Result};
+
+/// Returns `true` if the `name` is terminated with a NUL and has no
+/// other NUL bytes, so that it can be passed to NGX as a C string.
+pub(crate) const fn is_valid_parameter_name(name: &FeatureParameterName) -> bool {
+    if name.is_empty() || name[name.len() - 1] != 0 {
+        return false;
+    }
+    let mut i = 0;
+    while i < name.len() - 1 {
+        if name[i] == 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A type of a value which can be stored in a parameter map. The NGX
+/// parameter maps store signed and unsigned integers, floating point
+/// numbers and type-erased pointers. The [`bool`] values are stored as
+/// integers with value `1` being `true` and `0` being `false`.
+///
+/// This trait is sealed and can't be implemented outside of this
+/// crate.
+pub trait ParameterType: private::Sealed + Sized {
+    /// Returns the value of the parameter named `name`.
+    #[doc(hidden)]
+    fn get(parameters: &ParameterMap, name: &FeatureParameterName) -> Result<Self>;
+
+    /// Sets the value of the parameter named `name`.
+    #[doc(hidden)]
+    fn set(self, parameters: &ParameterMap, name: &FeatureParameterName);
+}
+
+/// A value of a parameter, of any of the [`ParameterType`]s.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ParameterValue {
+    /// A [`bool`] value.
+    Bool(bool),
+    /// An [`i32`] value.
+    I32(i32),
+    /// An [`u32`] value.
+    U32(u32),
+    /// An [`u64`] value.
+    U64(u64),
+    /// An [`f32`] value.
+    F32(f32),
+    /// An [`f64`] value.
+    F64(f64),
+    /// A type-erased pointer to a resource, a buffer or a callback.
+    Pointer(*mut c_void),
+}
+
+impl std::fmt::Display for ParameterValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(value) => value.fmt(f),
+            Self::I32(value) => value.fmt(f),
+            Self::U32(value) => value.fmt(f),
+            Self::U64(value) => value.fmt(f),
+            Self::F32(value) => value.fmt(f),
+            Self::F64(value) => value.fmt(f),
+            Self::Pointer(value) => write!(f, "{value:p}"),
+        }
+    }
+}
+
+macro_rules! impl_parameter_type {
+    ($($typ:ty => $get:ident, $set:ident, $variant:ident;)+) => {
+        $(
+            impl private::Sealed for $typ {}
+
+            impl From<$typ> for ParameterValue {
+                fn from(value: $typ) -> Self {
+                    Self::$variant(value)
+                }
+            }
+
+            impl ParameterType for $typ {
+                fn get(parameters: &ParameterMap, name: &FeatureParameterName) -> Result<Self> {
+                    parameters.$get(name)
+                }
+
+                fn set(self, parameters: &ParameterMap, name: &FeatureParameterName) {
+                    parameters.$set(name, self)
+                }
+            }
+        )+
+    };
+}
+
+impl_parameter_type! {
+    bool => get_bool, set_bool, Bool;
+    i32 => get_i32, set_i32, I32;
+    u32 => get_u32, set_u32, U32;
+    u64 => get_u64, set_u64, U64;
+    f32 => get_f32, set_f32, F32;
+    f64 => get_f64, set_f64, F64;
+    *mut c_void => get_ptr, set_ptr, Pointer;
+}
+
+/// The name of a parameter together with the type of its value. The
+/// keys of the SDK are in the [`keys`] module, and the keys which
+/// aren't there can be made with [`ParameterKey::custom`].
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ParameterKey<T> {
+    name: Cow<'static, FeatureParameterName>,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> ParameterKey<T> {
+    /// Creates a key from the NUL-terminated `name`, like the ones in
+    /// [`crate::bindings`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `name` isn't terminated with a NUL or has other NUL
+    /// bytes. In constants, this is a compile-time error.
+    pub const fn from_bytes_with_nul(name: &'static FeatureParameterName) -> Self {
+        assert!(
+            is_valid_parameter_name(name),
+            "The parameter name must be terminated with a single NUL."
+        );
+        Self {
+            name: Cow::Borrowed(name),
+            _type: PhantomData,
+        }
+    }
+
+    /// Creates a key for a parameter which isn't in the [`keys`]
+    /// module, like the ones of a newer SDK. Returns an error if the
+    /// `name` contains a NUL byte.
+    pub fn custom(name: &str) -> Result<Self> {
+        if name.bytes().any(|b| b == 0) {
+            return Err(crate::Error::Other(format!(
+                "The parameter name {name:?} contains a NUL byte."
+            )));
+        }
+        let mut bytes = Vec::with_capacity(name.len() + 1);
+        bytes.extend_from_slice(name.as_bytes());
+        bytes.push(0);
+        Ok(Self {
+            name: Cow::Owned(bytes),
+            _type: PhantomData,
+        })
+    }
+
+    /// Returns the NUL-terminated name of the parameter.
+    pub fn name(&self) -> &FeatureParameterName {
+        &self.name
+    }
+
+    /// Returns the name of the parameter without the trailing NUL.
+    pub fn as_str(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.name[..self.name.len() - 1])
+    }
+}
+
+impl<T> std::fmt::Debug for ParameterKey<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ParameterKey").field(&self.as_str()).finish()
+    }
+}
+
+impl<T> std::fmt::Display for ParameterKey<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.as_str())
+    }
+}
+
+/// Generates the typed keys for the parameter names of the same name in
+/// [`crate::bindings`], and the `dump` function reading all of them.
+macro_rules! parameter_keys {
+    ($($name:ident: $typ:ty,)+) => {
+        $(
+            #[doc = concat!("The [`crate::bindings::", stringify!($name), "`] parameter.")]
+            pub const $name: ParameterKey<$typ> =
+                ParameterKey::from_bytes_with_nul(crate::bindings::$name);
+        )+
+
+        /// Returns the values of all the parameters with the keys in
+        /// this module which are set in the `parameters`.
+        pub(crate) fn dump(parameters: &ParameterMap) -> BTreeMap<String, ParameterValue> {
+            let mut map = BTreeMap::new();
+            $(
+                if let Ok(value) = parameters.get(&$name) {
+                    map.insert($name.as_str().into_owned(), ParameterValue::from(value));
+                }
+            )+
+            map
+        }
+    };
+}
+
+/// The typed keys of the parameters declared in the SDK headers, named
+/// after the names in [`crate::bindings`]. The keys are generated from
+/// the bindings by the build script, which also knows the types of the
+/// values. The integers with the boolean semantics are [`bool`], and
+/// the resources, buffers and callbacks are type-erased pointers.
+#[allow(non_upper_case_globals)]
+pub mod keys {
+    use super::{ParameterKey, ParameterValue};
+    use crate::ParameterMap;
+    use std::collections::BTreeMap;
+    use std::ffi::c_void;
+
+    include!(concat!(env!("OUT_DIR"), "/parameter_keys.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid_parameter_name, keys, ParameterKey};
+    use std::ffi::c_void;
+
+    #[test]
+    fn parameter_names() {
+        assert!(is_valid_parameter_name(b"Width\0"));
+        assert!(!is_valid_parameter_name(b"Width"));
+        assert!(!is_valid_parameter_name(b"Wid\0th\0"));
+        assert!(!is_valid_parameter_name(b""));
+
+        assert_eq!(keys::NVSDK_NGX_Parameter_Width.as_str(), "Width");
+        assert_eq!(keys::NVSDK_NGX_Parameter_Width.name(), b"Width\0");
+        assert_eq!(
+            format!("{:?}", keys::NVSDK_NGX_Parameter_Width),
+            "ParameterKey(\"Width\")"
+        );
+
+        let custom = ParameterKey::<u32>::custom("Width").unwrap();
+        assert_eq!(custom, keys::NVSDK_NGX_Parameter_Width);
+        assert!(ParameterKey::<u32>::custom("Wid\0th").is_err());
+    }
+
+    #[test]
+    fn generated_key_types() {
+        let _: ParameterKey<*mut c_void> = keys::NVSDK_NGX_Parameter_DLSSDOptimalSettingsCallback;
+        let _: ParameterKey<*mut c_void> = keys::NVSDK_NGX_Parameter_DLSSDGetStatsCallback;
+        let _: ParameterKey<*mut c_void> =
+            keys::NVSDK_NGX_Parameter_DLSS_INV_VIEW_PROJECTION_MATRIX;
+        let _: ParameterKey<*mut c_void> = keys::NVSDK_NGX_Parameter_DLSS_CLIP_TO_PREV_CLIP_MATRIX;
+        let _: ParameterKey<*mut c_void> = keys::NVSDK_NGX_Parameter_DLSS_DisocclusionMask;
+        let _: ParameterKey<*mut c_void> = keys::NVSDK_NGX_Parameter_FI_Output1;
+        let _: ParameterKey<u32> = keys::NVSDK_NGX_Parameter_DLSS_Hint_Render_Preset_Quality;
+        let _: ParameterKey<u32> = keys::NVSDK_NGX_Parameter_DeepDVC_MinDriverVersionMajor;
+    }
+
+    #[test]
+    #[should_panic]
+    fn unterminated_parameter_name() {
+        let name: &'static [u8] = b"Width";
+        ParameterKey::<u32>::from_bytes_with_nul(name);
+    }
+}
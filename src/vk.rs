@@ -1,18 +1,24 @@
 //! Vulkan bindings to NGX.
 
-use std::mem::ManuallyDrop;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
 
 use crate::bindings::{
-    self, NVSDK_NGX_DLSS_Create_Params, NVSDK_NGX_DLSS_Feature_Flags, NVSDK_NGX_Dimensions,
+    self, NVSDK_NGX_DLSS_Create_Params, NVSDK_NGX_DLSS_Denoise_Mode, NVSDK_NGX_DLSS_Depth_Type,
+    NVSDK_NGX_DLSS_Feature_Flags, NVSDK_NGX_DLSS_Roughness_Mode, NVSDK_NGX_Dimensions,
     NVSDK_NGX_Feature, NVSDK_NGX_ImageViewInfo_VK, NVSDK_NGX_PerfQuality_Value,
     NVSDK_NGX_Resource_VK_Type, NVSDK_NGX_Resource_VK__bindgen_ty_1, NVSDK_NGX_VK_DLSS_Eval_Params,
     VkFormat, VkImageSubresourceRange,
 };
 use crate::bindings::{NVSDK_NGX_Coordinates, NVSDK_NGX_Resource_VK};
-use crate::Result;
+use crate::error::{ErrorContext, ResultExt};
+use crate::library::Library;
+use crate::{keys, ParameterKey, ParameterType, ParameterValue, Result};
 use ash::vk::{self, Handle};
 
+mod helpers;
+
 /// Returns a mutable pointer for [`ash::vk::Handle`].
 fn ash_handle_to_pointer_mut<H: Handle + Copy, T>(ash_handle: &H) -> *mut T {
     let address = ash_handle.as_raw();
@@ -21,6 +27,12 @@ fn ash_handle_to_pointer_mut<H: Handle + Copy, T>(ash_handle: &H) -> *mut T {
     pointer.cast()
 }
 
+/// Returns the [`ash::vk::Handle`] for a pointer, the inverse of
+/// [`ash_handle_to_pointer_mut`].
+fn pointer_to_ash_handle<H: Handle, T>(pointer: *mut T) -> H {
+    H::from_raw(pointer as usize as u64)
+}
+
 fn convert_slice_of_strings_to_cstrings(data: &[String]) -> Result<Vec<std::ffi::CString>> {
     let strings: Vec<_> = data
         .iter()
@@ -57,20 +69,26 @@ impl RequiredExtensions {
         convert_slice_of_strings_to_cstrings(&self.instance)
     }
 
-    /// Returns a list of required vulkan extensions for NGX to work.
+    /// Returns a list of required vulkan extensions for NGX to work,
+    /// the same for all the features and physical devices. See
+    /// [`FeatureDiscoveryBuilder::get_instance_extension_requirements`]
+    /// and [`FeatureDiscoveryBuilder::get_device_extension_requirements`]
+    /// for the extensions of a single feature.
     pub fn get() -> Result<Self> {
         let mut instance_extensions: *mut *const std::ffi::c_char = std::ptr::null_mut();
         let mut device_extensions: *mut *const std::ffi::c_char = std::ptr::null_mut();
         let mut instance_count = 0u32;
         let mut device_count = 0u32;
+        let library = Library::get()?;
         Result::from(unsafe {
-            bindings::NVSDK_NGX_VULKAN_RequiredExtensions(
+            (library.NVSDK_NGX_VULKAN_RequiredExtensions)(
                 &mut instance_count as *mut _,
                 &mut instance_extensions as *mut _,
                 &mut device_count as *mut _,
                 &mut device_extensions as *mut _,
             )
-        })?;
+        })
+        .context(|| ErrorContext::new("NVSDK_NGX_VULKAN_RequiredExtensions"))?;
 
         let mut instance = Vec::new();
         for i in 0..instance_count {
@@ -101,6 +119,150 @@ impl RequiredExtensions {
     }
 }
 
+impl RequiredExtensions {
+    /// Merges the instance extensions NGX requires into the `requested`
+    /// ones, see [`MergedExtensions::instance`].
+    pub fn merge_instance_extensions(
+        &self,
+        entry: &ash::Entry,
+        requested: &[&std::ffi::CStr],
+    ) -> Result<MergedExtensions> {
+        MergedExtensions::instance(entry, requested, &self.get_instance_extensions_c_strings()?)
+    }
+
+    /// Merges the device extensions NGX requires into the `requested`
+    /// ones, see [`MergedExtensions::device`].
+    pub fn merge_device_extensions(
+        &self,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        requested: &[&std::ffi::CStr],
+    ) -> Result<MergedExtensions> {
+        MergedExtensions::device(
+            instance,
+            physical_device,
+            requested,
+            &self.get_device_extensions_c_strings()?,
+        )
+    }
+}
+
+/// Returns the names of the extensions, like the ones returned by
+/// [`FeatureDiscoveryBuilder::get_device_extension_requirements`], to be
+/// merged with [`MergedExtensions`].
+pub fn extension_names(properties: &[vk::ExtensionProperties]) -> Vec<std::ffi::CString> {
+    properties
+        .iter()
+        .map(|p| unsafe { std::ffi::CStr::from_ptr(p.extension_name.as_ptr()) }.to_owned())
+        .collect()
+}
+
+/// The Vulkan extensions requested by the application merged with the
+/// ones NGX requires, without duplicates. The NGX extensions the
+/// system doesn't expose are left out and reported by
+/// [`Self::get_missing`], so that the instance or the device can still
+/// be created, with NGX disabled.
+///
+/// The list owns the names, so it must be kept alive until the instance
+/// or the device is created with [`Self::as_ptrs`].
+#[derive(Debug, Default)]
+pub struct MergedExtensions {
+    names: Vec<std::ffi::CString>,
+    pointers: Vec<*const std::ffi::c_char>,
+    missing: Vec<std::ffi::CString>,
+}
+
+impl MergedExtensions {
+    /// Merges the `required` extensions into the `requested` ones,
+    /// leaving out the required ones not among the `available` ones.
+    /// The requested extensions are kept as they are.
+    pub fn new(
+        requested: &[&std::ffi::CStr],
+        required: &[std::ffi::CString],
+        available: &[vk::ExtensionProperties],
+    ) -> Self {
+        let available = extension_names(available);
+        let mut names: Vec<std::ffi::CString> = Vec::new();
+        let mut missing = Vec::new();
+        for name in requested.iter().map(|name| (*name).to_owned()) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        for name in required {
+            if names.contains(name) || missing.contains(name) {
+                continue;
+            }
+            if available.contains(name) {
+                names.push(name.clone());
+            } else {
+                missing.push(name.clone());
+            }
+        }
+        let pointers = names.iter().map(|name| name.as_ptr()).collect();
+        Self {
+            names,
+            pointers,
+            missing,
+        }
+    }
+
+    /// Merges the `required` instance extensions into the `requested`
+    /// ones, checking them against the extensions the `entry` exposes.
+    pub fn instance(
+        entry: &ash::Entry,
+        requested: &[&std::ffi::CStr],
+        required: &[std::ffi::CString],
+    ) -> Result<Self> {
+        let available = entry
+            .enumerate_instance_extension_properties(None)
+            .map_err(|e| {
+                crate::Error::Other(format!("Couldn't enumerate the instance extensions: {e}"))
+            })?;
+        Ok(Self::new(requested, required, &available))
+    }
+
+    /// Merges the `required` device extensions into the `requested`
+    /// ones, checking them against the extensions the `physical_device`
+    /// exposes.
+    pub fn device(
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        requested: &[&std::ffi::CStr],
+        required: &[std::ffi::CString],
+    ) -> Result<Self> {
+        let available = unsafe { instance.enumerate_device_extension_properties(physical_device) }
+            .map_err(|e| {
+                crate::Error::Other(format!("Couldn't enumerate the device extensions: {e}"))
+            })?;
+        Ok(Self::new(requested, required, &available))
+    }
+
+    /// Returns the names of the extensions to enable.
+    pub fn get_names(&self) -> &[std::ffi::CString] {
+        &self.names
+    }
+
+    /// Returns the pointers to the names of the extensions to enable,
+    /// as expected by [`vk::InstanceCreateInfoBuilder::enabled_extension_names`]
+    /// and [`vk::DeviceCreateInfoBuilder::enabled_extension_names`].
+    pub fn as_ptrs(&self) -> &[*const std::ffi::c_char] {
+        &self.pointers
+    }
+
+    /// Returns the extensions NGX requires which the system doesn't
+    /// expose.
+    pub fn get_missing(&self) -> &[std::ffi::CString] {
+        &self.missing
+    }
+
+    /// Returns `true` if all the extensions NGX requires are exposed,
+    /// so that NGX may be used.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
 /// Implementors of this trait can convert to a pointer of custom type
 /// `T` from their [`ash::vk::Handle::as_raw`].
 trait HandleToPointer<T> {
@@ -121,47 +283,216 @@ where
     }
 }
 
-/// NVIDIA NGX system.
-#[repr(transparent)]
+/// The name of the environment variable which, if set, overrides the
+/// directory the DLSS snippets are loaded from at runtime, see
+/// [`DlssLibraryType::snippet_path`].
+pub const SNIPPET_PATH_ENVIRONMENT_VARIABLE: &str = "NVNGX_SNIPPET_PATH";
+
+/// The flavour of the DLSS snippet libraries (`libnvidia-ngx-dlss.so`),
+/// which NGX loads the features from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DlssLibraryType {
+    /// The development snippets, with the on-screen indicator and
+    /// verbose logging. These must not be shipped.
+    Development,
+    /// The release snippets.
+    Release,
+}
+
+impl DlssLibraryType {
+    /// Returns the type selected at build time with the
+    /// `NVNGX_DLSS_LIBRARY_TYPE` environment variable (`development` or
+    /// `release`). The default is [`DlssLibraryType::Release`].
+    pub fn selected() -> Self {
+        match option_env!("NVNGX_DLSS_LIBRARY_TYPE") {
+            Some("development") => Self::Development,
+            _ => Self::Release,
+        }
+    }
+
+    /// Returns the directory with the snippets of this type in the SDK
+    /// the crate was built with, if the SDK was located at build time.
+    pub fn sdk_snippet_path(self) -> Option<std::path::PathBuf> {
+        option_env!("NVNGX_SDK_LIBRARY_PATH")
+            .map(|path| std::path::Path::new(path).join(self.directory_name()))
+    }
+
+    /// Returns the directory to load the snippets of this type from,
+    /// resolved at runtime, as the SDK the crate was built with usually
+    /// isn't on the machine the application runs on. This is the
+    /// directory set in the [`SNIPPET_PATH_ENVIRONMENT_VARIABLE`], then
+    /// the `dev` or `rel` directory next to the executable, if it
+    /// exists, and the [`Self::sdk_snippet_path`] only when neither is.
+    pub fn snippet_path(self) -> Option<std::path::PathBuf> {
+        self.resolve_snippet_path(
+            std::env::var_os(SNIPPET_PATH_ENVIRONMENT_VARIABLE),
+            std::env::current_exe().ok(),
+        )
+    }
+
+    /// Resolves the [`Self::snippet_path`] with the value of the
+    /// environment variable and the path to the executable.
+    fn resolve_snippet_path(
+        self,
+        environment_path: Option<std::ffi::OsString>,
+        executable: Option<std::path::PathBuf>,
+    ) -> Option<std::path::PathBuf> {
+        if let Some(path) = environment_path.filter(|path| !path.is_empty()) {
+            return Some(path.into());
+        }
+        executable
+            .as_deref()
+            .and_then(std::path::Path::parent)
+            .map(|directory| directory.join(self.directory_name()))
+            .filter(|path| path.is_dir())
+            .or_else(|| self.sdk_snippet_path())
+    }
+
+    /// Returns the name of the directory with the snippets of this type
+    /// in the SDK.
+    const fn directory_name(self) -> &'static str {
+        match self {
+            Self::Development => "dev",
+            Self::Release => "rel",
+        }
+    }
+}
+
+/// NVIDIA NGX system, initialised for a single logical device.
+///
+/// Several systems may exist at once, as long as each was created for
+/// a different logical device: creating a second one for the same
+/// device fails, since dropping either would shut NGX down for both.
+///
+/// A system may be moved to another thread, but isn't [`Sync`], as NGX
+/// doesn't guarantee that its entry points may be called concurrently.
+/// Wrap it into a [`std::sync::Mutex`] to share it between threads.
+///
+/// The features created by a system keep NGX initialised, so NGX is
+/// only shut down once both the system and its features are dropped.
 #[derive(Debug)]
 pub struct System {
+    handle: Arc<SystemHandle>,
+    _not_sync: std::marker::PhantomData<std::cell::Cell<()>>,
+}
+
+/// The NGX initialisation for a logical device, shared by a [`System`]
+/// and the features it created.
+#[derive(Debug)]
+struct SystemHandle {
+    device: vk::Device,
+}
+
+impl SystemHandle {
+    fn release(&self) -> Result {
+        let result = Result::from(unsafe {
+            (Library::loaded().NVSDK_NGX_VULKAN_Shutdown1)(self.device.as_pointer_mut())
+        })
+        .context(|| ErrorContext::new("NVSDK_NGX_VULKAN_Shutdown1"));
+        Loader::unregister(self.device);
+        result
+    }
+}
+
+impl Drop for SystemHandle {
+    fn drop(&mut self) {
+        if let Err(e) = self.release() {
+            log::error!("Couldn't shutdown the NGX system {self:?}: {e}");
+        }
+    }
+}
+
+/// The Vulkan loader entry points NGX resolves the Vulkan functions of
+/// a [`System`] with.
+#[derive(Clone)]
+struct Loader {
+    /// Keeps the Vulkan library the entry points are in loaded, if the
+    /// entry loaded it.
+    _entry: ash::Entry,
+    instance: vk::Instance,
     device: vk::Device,
+    get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
 }
 
-/// Current [`ash::Entry`] with which the NGX was associated.
-static mut ASH_ENTRY: Option<ManuallyDrop<ash::Entry>> = None;
+/// The loaders of the [`System`]s alive, at most one per logical device.
+/// NGX passes nothing but the Vulkan handle to the loader callbacks, so
+/// the loaders can only be found by the handle.
+static LOADERS: Mutex<Vec<Loader>> = Mutex::new(Vec::new());
+
+impl Loader {
+    fn new(entry: &ash::Entry, instance: &ash::Instance, device: vk::Device) -> Self {
+        Self {
+            _entry: entry.clone(),
+            instance: instance.handle(),
+            device,
+            get_instance_proc_addr: entry.static_fn().get_instance_proc_addr,
+            get_device_proc_addr: instance.fp_v1_0().get_device_proc_addr,
+        }
+    }
+
+    fn all() -> MutexGuard<'static, Vec<Self>> {
+        LOADERS.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Registers the loader, failing if there is one for its device.
+    fn register(self) -> Result {
+        let mut loaders = Self::all();
+        if loaders.iter().any(|l| l.device == self.device) {
+            return Err(crate::Error::Other(format!(
+                "An NGX system already exists for the device {:?}",
+                self.device
+            )));
+        }
+        loaders.push(self);
+        Ok(())
+    }
+
+    fn unregister(device: vk::Device) {
+        Self::all().retain(|l| l.device != device);
+    }
+
+    /// Returns the loader for the `instance`. Any loader is suitable for
+    /// the null instance, which is used for the global functions.
+    fn find_instance(instance: vk::Instance) -> Option<Self> {
+        Self::all()
+            .iter()
+            .find(|l| instance == vk::Instance::null() || l.instance == instance)
+            .cloned()
+    }
 
-/// Current [`ash::Instance`] with which the NGX was associated.
-static mut ASH_INSTANCE: Option<ManuallyDrop<ash::Instance>> = None;
+    fn find_device(device: vk::Device) -> Option<Self> {
+        Self::all().iter().find(|l| l.device == device).cloned()
+    }
+}
 
 unsafe extern "C" fn get_instance_proc_addr<T>(
     instance: *mut T,
-    proc_name: *const i8,
+    proc_name: *const std::ffi::c_char,
 ) -> Option<unsafe extern "C" fn()> {
-    ASH_ENTRY.as_ref().and_then(|e| {
-        let instance = instance as *mut u8;
-        let address = instance.offset_from(std::ptr::null::<u8>());
-        let raw_handle = address as u64;
-        e.get_instance_proc_addr(vk::Instance::from_raw(raw_handle), proc_name)
-            .map(|p| std::mem::transmute(p))
-    })
+    let instance = pointer_to_ash_handle::<vk::Instance, _>(instance);
+    let loader = Loader::find_instance(instance)?;
+    (loader.get_instance_proc_addr)(instance, proc_name).map(|p| std::mem::transmute(p))
 }
 
 unsafe extern "C" fn get_device_proc_addr<T>(
     logical_device: *mut T,
-    proc_name: *const i8,
+    proc_name: *const std::ffi::c_char,
 ) -> Option<unsafe extern "C" fn()> {
-    ASH_INSTANCE.as_ref().and_then(|i| {
-        let logical_device = logical_device as *mut u8;
-        let address = logical_device.offset_from(std::ptr::null::<u8>());
-        let raw_handle = address as u64;
-        (i.fp_v1_0().get_device_proc_addr)(vk::Device::from_raw(raw_handle), proc_name)
-            .map(|p| std::mem::transmute(p))
-    })
+    let device = pointer_to_ash_handle::<vk::Device, _>(logical_device);
+    let loader = Loader::find_device(device)?;
+    (loader.get_device_proc_addr)(device, proc_name).map(|p| std::mem::transmute(p))
 }
 
 impl System {
-    /// Creates a new NVIDIA NGX system.
+    /// Creates a new NVIDIA NGX system. The features are loaded from
+    /// the snippets of the [`DlssLibraryType::selected`] at build time,
+    /// in the directory of [`DlssLibraryType::snippet_path`], if any, and
+    /// from the application directory.
+    ///
+    /// With the `detect-driver` feature enabled, this returns
+    /// [`crate::Error::LibraryNotPresent`] if the NGX core of the driver
+    /// couldn't be loaded.
     pub fn new(
         project_id: Option<uuid::Uuid>,
         engine_version: &str,
@@ -171,10 +502,35 @@ impl System {
         physical_device: vk::PhysicalDevice,
         logical_device: vk::Device,
     ) -> Result<Self> {
-        unsafe {
-            ASH_ENTRY = Some(ManuallyDrop::new(entry.clone()));
-            ASH_INSTANCE = Some(ManuallyDrop::new(instance.clone()));
-        }
+        Self::new_with_snippet_path(
+            project_id,
+            engine_version,
+            application_data_path,
+            entry,
+            instance,
+            physical_device,
+            logical_device,
+            DlssLibraryType::selected().snippet_path().as_deref(),
+        )
+    }
+
+    /// Creates a new NVIDIA NGX system, which loads the features from
+    /// the snippets in the `snippet_path` directory, if provided, and
+    /// from the application directory. Use
+    /// [`FeatureParameters::get_dlss_library_type`] to check which
+    /// snippets have been loaded.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_snippet_path(
+        project_id: Option<uuid::Uuid>,
+        engine_version: &str,
+        application_data_path: &std::path::Path,
+        entry: &ash::Entry,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        logical_device: vk::Device,
+        snippet_path: Option<&std::path::Path>,
+    ) -> Result<Self> {
+        let library = Library::get()?;
         let engine_type = bindings::NVSDK_NGX_EngineType::NVSDK_NGX_ENGINE_TYPE_CUSTOM;
         let project_id =
             std::ffi::CString::new(project_id.unwrap_or_else(uuid::Uuid::new_v4).to_string())
@@ -182,8 +538,15 @@ impl System {
         let engine_version = std::ffi::CString::new(engine_version).unwrap();
         let application_data_path =
             widestring::WideString::from_str(application_data_path.to_str().unwrap());
-        Result::from(unsafe {
-            bindings::NVSDK_NGX_VULKAN_Init_with_ProjectID(
+        let common_info = RawFeatureCommonInfo::new(&FeatureCommonInfo::new(
+            snippet_path
+                .into_iter()
+                .map(|path| path.to_owned())
+                .collect(),
+        ))?;
+        Loader::new(entry, instance, logical_device).register()?;
+        let result = Result::from(unsafe {
+            (library.NVSDK_NGX_VULKAN_Init_with_ProjectID)(
                 project_id.as_ptr(),
                 engine_type,
                 engine_version.as_ptr(),
@@ -193,17 +556,94 @@ impl System {
                 logical_device.as_pointer_mut(),
                 Some(get_instance_proc_addr),
                 Some(get_device_proc_addr),
-                std::ptr::null(),
+                &common_info.info,
                 bindings::NVSDK_NGX_Version::NVSDK_NGX_Version_API,
             )
         })
-        .map(|_| Self {
-            device: logical_device,
+        .context(|| ErrorContext::new("NVSDK_NGX_VULKAN_Init_with_ProjectID"));
+        if result.is_err() {
+            Loader::unregister(logical_device);
+        }
+        result.map(|_| Self {
+            handle: Arc::new(SystemHandle {
+                device: logical_device,
+            }),
+            _not_sync: std::marker::PhantomData,
         })
     }
 
-    fn shutdown(&self) -> Result {
-        unsafe { bindings::NVSDK_NGX_VULKAN_Shutdown1(self.device.as_pointer_mut()) }.into()
+    /// Shuts NGX down for the device, returning the error, unlike
+    /// dropping the system, which only logs it.
+    ///
+    /// Fails without shutting down if any feature or parameter map
+    /// created by this system is still alive. NGX is then shut down once
+    /// the last of them is dropped.
+    pub fn shutdown(self) -> Result {
+        let handle = Arc::try_unwrap(self.handle).map_err(|handle| {
+            crate::Error::Other(format!(
+                "Couldn't shutdown the NGX system, as {} of its features or parameter maps are alive.",
+                Arc::strong_count(&handle) - 1
+            ))
+        })?;
+        let result = handle.release();
+        std::mem::forget(handle);
+        result
+    }
+
+    /// Returns the parameter map of NGX, pre-populated with the NGX
+    /// capabilities, for the older drivers which support neither
+    /// [`FeatureParameters::new`] nor
+    /// [`FeatureParameters::get_capability_parameters`].
+    ///
+    /// The map is owned by NGX and is only borrowed for as long as the
+    /// system is alive. It is shared by all the callers, so the
+    /// parameters set on it stay there until NGX is shut down.
+    ///
+    /// # NVIDIA documentation
+    ///
+    /// Parameter maps output by NVSDK_NGX_GetParameters are also pre-populated
+    /// with NGX capabilities and available features.
+    /// Unlike with NVSDK_NGX_AllocateParameters, parameter maps output by NVSDK_NGX_GetParameters
+    /// have their lifetimes managed by NGX, and must not
+    /// be destroyed by the app using NVSDK_NGX_DestroyParameters.
+    /// NVSDK_NGX_GetParameters is deprecated and apps should move to using
+    /// NVSDK_NGX_AllocateParameters and NVSDK_NGX_GetCapabilityParameters when possible.
+    pub fn get_parameters(&self) -> Result<&ParameterMap> {
+        let library = Library::get()?;
+        let mut pointer: *mut bindings::NVSDK_NGX_Parameter = std::ptr::null_mut();
+        Result::from(unsafe { (library.NVSDK_NGX_VULKAN_GetParameters)(&mut pointer as *mut _) })
+            .context(|| ErrorContext::new("NVSDK_NGX_VULKAN_GetParameters"))?;
+        // NGX keeps the map alive until it is shut down with the system.
+        Ok(unsafe { ParameterMap::from_raw(pointer) })
+    }
+
+    /// Allocates a new parameter map with [`FeatureParameters::new`],
+    /// or returns the map of [`Self::get_parameters`] on the older
+    /// drivers which don't support it.
+    pub fn allocate_parameters(&self) -> Result<SystemParameters<'_>> {
+        match FeatureParameters::new(self) {
+            Ok(parameters) => Ok(SystemParameters::Allocated(parameters)),
+            Err(e) if matches!(e.without_context(), crate::Error::OutOfDate) => {
+                log::debug!("Falling back to NVSDK_NGX_VULKAN_GetParameters: {e}");
+                self.get_parameters().map(SystemParameters::Ngx)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the capability parameters of
+    /// [`FeatureParameters::get_capability_parameters`], or the map of
+    /// [`Self::get_parameters`] on the older drivers which don't support
+    /// them.
+    pub fn get_capability_parameters(&self) -> Result<SystemParameters<'_>> {
+        match FeatureParameters::get_capability_parameters(self) {
+            Ok(parameters) => Ok(SystemParameters::Allocated(parameters)),
+            Err(e) if matches!(e.without_context(), crate::Error::OutOfDate) => {
+                log::debug!("Falling back to NVSDK_NGX_VULKAN_GetParameters: {e}");
+                self.get_parameters().map(SystemParameters::Ngx)
+            }
+            Err(e) => Err(e),
+        }
     }
 
     /// Creates a new [`Feature`] with the logical device used to create
@@ -216,44 +656,209 @@ impl System {
     ) -> Result<Feature> {
         let parameters = match parameters {
             Some(p) => p,
-            None => FeatureParameters::get_capability_parameters()?,
+            None => FeatureParameters::get_capability_parameters(self)?,
         };
-        Feature::new(self.device, command_buffer, feature_type, parameters)
+        Feature::new(self, command_buffer, feature_type, parameters)
     }
 
     /// Creates a supersampling (or "DLSS") feature.
     pub fn create_super_sampling_feature(
         &self,
         command_buffer: vk::CommandBuffer,
-        feature_parameters: FeatureParameters,
+        feature_parameters: impl Into<Rc<FeatureParameters>>,
         create_parameters: SuperSamplingCreateParameters,
     ) -> Result<SuperSamplingFeature> {
-        Feature::new_super_sampling(
-            self.device,
+        Feature::new_super_sampling(self, command_buffer, feature_parameters, create_parameters)
+    }
+
+    /// Creates a ray reconstruction (or "DLSS-RR") feature.
+    pub fn create_ray_reconstruction_feature(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        feature_parameters: impl Into<Rc<FeatureParameters>>,
+        create_parameters: RayReconstructionCreateParameters,
+    ) -> Result<RayReconstructionFeature> {
+        Feature::new_ray_reconstruction(self, command_buffer, feature_parameters, create_parameters)
+    }
+
+    /// Creates an image super resolution feature.
+    pub fn create_image_super_resolution_feature(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        feature_parameters: impl Into<Rc<FeatureParameters>>,
+        create_parameters: ImageSuperResolutionCreateParameters,
+    ) -> Result<ImageSuperResolutionFeature> {
+        Feature::new_image_super_resolution(
+            self,
             command_buffer,
             feature_parameters,
             create_parameters,
         )
     }
 
+    /// Records the upscaling of the `input` image into the `output` one
+    /// by the `scale` into the `command_buffer`, without submitting it.
+    ///
+    /// The returned feature is used by the recorded work, so it must be
+    /// kept alive until the `command_buffer` has finished executing on
+    /// the GPU: dropping it releases the feature, which is still in use
+    /// until then. It can record the upscaling of more images of the
+    /// same size with [`ImageSuperResolutionFeature::upscale`].
+    #[must_use = "the feature must outlive the execution of the command buffer"]
+    pub fn record_upscale_image(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        input: VkImageResourceDescription,
+        output: VkImageResourceDescription,
+        scale: ImageSuperResolutionScale,
+    ) -> Result<ImageSuperResolutionFeature> {
+        let create_parameters =
+            ImageSuperResolutionCreateParameters::new(input.width, input.height, scale);
+        let mut feature = self.create_image_super_resolution_feature(
+            command_buffer,
+            FeatureParameters::new(self)?,
+            create_parameters,
+        )?;
+        feature.upscale(command_buffer, input, output)?;
+        Ok(feature)
+    }
+
+    /// Upscales the `input` image into the `output` one by the `scale`,
+    /// and waits for the upscaling to finish on the GPU.
+    ///
+    /// The work is recorded with [`Self::record_upscale_image`] into a
+    /// command buffer allocated from the `command_pool`, which must be
+    /// of the queue family of the `queue`, and submitted to the `queue`.
+    /// The `device` must be the logical device of this [`System`]. The
+    /// images must already be in the layouts the upscaling reads and
+    /// writes them in.
+    ///
+    /// If waiting for the upscaling fails, the `queue` is waited for to
+    /// be idle before the command buffer is freed and the feature is
+    /// released. If that fails too, the GPU may still be executing the
+    /// upscaling, so the command buffer, its fence and the feature are
+    /// leaked rather than freed while in use.
+    pub fn upscale_image(
+        &self,
+        device: &ash::Device,
+        queue: vk::Queue,
+        command_pool: vk::CommandPool,
+        input: VkImageResourceDescription,
+        output: VkImageResourceDescription,
+        scale: ImageSuperResolutionScale,
+    ) -> Result {
+        if device.handle() != self.handle.device {
+            return Err(crate::Error::Other(
+                "Can't upscale an image with a device other than the one of the NGX system."
+                    .to_owned(),
+            ));
+        }
+        let allocate_info = vk::CommandBufferAllocateInfo::builder()
+            .command_pool(command_pool)
+            .level(vk::CommandBufferLevel::PRIMARY)
+            .command_buffer_count(1);
+        let command_buffer =
+            unsafe { device.allocate_command_buffers(&allocate_info) }.map_err(|e| {
+                crate::Error::Other(format!("Couldn't allocate the command buffer: {e}"))
+            })?[0];
+        match self.submit_upscale_image(device, queue, command_buffer, input, output, scale) {
+            Err(UpscaleSubmissionError::Pending(e)) => Err(e),
+            result => {
+                unsafe { device.free_command_buffers(command_pool, &[command_buffer]) };
+                result.map_err(UpscaleSubmissionError::into_inner)
+            }
+        }
+    }
+
+    /// Records the upscaling into the `command_buffer`, submits it to
+    /// the `queue` and waits for it, see [`Self::upscale_image`].
+    fn submit_upscale_image(
+        &self,
+        device: &ash::Device,
+        queue: vk::Queue,
+        command_buffer: vk::CommandBuffer,
+        input: VkImageResourceDescription,
+        output: VkImageResourceDescription,
+        scale: ImageSuperResolutionScale,
+    ) -> std::result::Result<(), UpscaleSubmissionError> {
+        let begin_info = vk::CommandBufferBeginInfo::builder()
+            .flags(vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT);
+        unsafe { device.begin_command_buffer(command_buffer, &begin_info) }
+            .map_err(|e| crate::Error::Other(format!("Couldn't begin the command buffer: {e}")))?;
+        // The feature is used until the GPU is done with the work.
+        let feature = self.record_upscale_image(command_buffer, input, output, scale)?;
+        unsafe { device.end_command_buffer(command_buffer) }
+            .map_err(|e| crate::Error::Other(format!("Couldn't end the command buffer: {e}")))?;
+
+        let fence = unsafe { device.create_fence(&vk::FenceCreateInfo::default(), None) }
+            .map_err(|e| crate::Error::Other(format!("Couldn't create the fence: {e}")))?;
+        let command_buffers = [command_buffer];
+        let submit_info = vk::SubmitInfo::builder().command_buffers(&command_buffers);
+        let mut result = unsafe { device.queue_submit(queue, &[submit_info.build()], fence) }
+            .map_err(|e| crate::Error::Other(format!("Couldn't submit the upscaling: {e}")));
+        if result.is_ok() {
+            if let Err(e) = unsafe { device.wait_for_fences(&[fence], true, u64::MAX) } {
+                let error = crate::Error::Other(format!("Couldn't wait for the upscaling: {e}"));
+                if let Err(e) = unsafe { device.queue_wait_idle(queue) } {
+                    log::error!(
+                        "Couldn't wait for the queue of the upscaling either: {e}, leaking its command buffer, fence and feature."
+                    );
+                    std::mem::forget(feature);
+                    return Err(UpscaleSubmissionError::Pending(error));
+                }
+                result = Err(error);
+            }
+        }
+        unsafe { device.destroy_fence(fence, None) };
+        result.map_err(UpscaleSubmissionError::Idle)
+    }
+
+    /// Creates an in-painting feature.
+    pub fn create_in_painting_feature(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        feature_parameters: impl Into<Rc<FeatureParameters>>,
+        create_parameters: InPaintingCreateParameters,
+    ) -> Result<InPaintingFeature> {
+        Feature::new_in_painting(self, command_buffer, feature_parameters, create_parameters)
+    }
+
     /// Creates a frame generation feature.
     pub fn create_frame_generation_feature(
         &self,
         command_buffer: vk::CommandBuffer,
-        feature_parameters: FeatureParameters,
-    ) -> Result<Feature> {
-        Feature::new_frame_generation(self.device, command_buffer, feature_parameters)
+        feature_parameters: impl Into<Rc<FeatureParameters>>,
+        create_parameters: FrameGenerationCreateParameters,
+    ) -> Result<FrameGenerationFeature> {
+        Feature::new_frame_generation(self, command_buffer, feature_parameters, create_parameters)
     }
 }
 
-impl Drop for System {
-    fn drop(&mut self) {
-        if let Err(e) = self.shutdown() {
-            log::error!("Couldn't shutdown the NGX system {self:?}: {e}");
+/// An error of [`System::submit_upscale_image`].
+#[derive(Debug)]
+enum UpscaleSubmissionError {
+    /// The GPU doesn't use the command buffer, which may be freed.
+    Idle(crate::Error),
+    /// The GPU may still be executing the command buffer, which must
+    /// not be freed.
+    Pending(crate::Error),
+}
+
+impl UpscaleSubmissionError {
+    /// Returns the error the upscaling failed with.
+    fn into_inner(self) -> crate::Error {
+        match self {
+            Self::Idle(e) | Self::Pending(e) => e,
         }
     }
 }
 
+impl From<crate::Error> for UpscaleSubmissionError {
+    fn from(e: crate::Error) -> Self {
+        Self::Idle(e)
+    }
+}
+
 /// An NGX handle. Handle might be created and used by [`Feature::create`].
 #[repr(transparent)]
 #[derive(Debug)]
@@ -270,8 +875,9 @@ impl FeatureHandle {
         Self::default()
     }
 
-    fn release(&mut self) -> Result {
-        unsafe { bindings::NVSDK_NGX_VULKAN_ReleaseFeature(self.0) }.into()
+    fn release(&self) -> Result {
+        Result::from(unsafe { (Library::loaded().NVSDK_NGX_VULKAN_ReleaseFeature)(self.0) })
+            .context(|| ErrorContext::new("NVSDK_NGX_VULKAN_ReleaseFeature"))
     }
 }
 
@@ -288,215 +894,104 @@ impl Drop for FeatureHandle {
 }
 
 /// A type alias for feature parameter, like
-/// [`bindings::NVSDK_NGX_Parameter_NumFrames`].
+/// [`bindings::NVSDK_NGX_Parameter_NumFrames`]. The names must be
+/// terminated with a NUL: the getters of [`ParameterMap`] return an
+/// error otherwise, and the setters panic.
 // pub type FeatureParameterName = std::ffi::CStr;
 pub type FeatureParameterName = [u8];
 
-macro_rules! insert_parameter_debug {
-    ($map:ident, $parameters:ident, ($key:path, bool),) => {
-        if let Ok(value) = $parameters.get_bool($key) {
-            $map.insert(
-                stringify!($key).to_owned(),
-                format!("{:?}", value)
-                );
-        }
-    };
-    ($map:ident, $parameters:ident, ($key:path, i32),) => {
-        if let Ok(value) = $parameters.get_i32($key) {
-            $map.insert(
-                stringify!($key).to_owned(),
-                format!("{:?}", value),
-            );
-        }
-    };
-    ($map:ident, $parameters:ident, ($key:path, u32),) => {
-        if let Ok(value) = $parameters.get_u32($key) {
-            $map.insert(
-                stringify!($key).to_owned(),
-                format!("{:?}", value),
-            );
-        }
-    };
-    ($map:ident, $parameters:ident, ($key:path, f32),) => {
-        if let Ok(value) = $parameters.get_f32($key) {
-            $map.insert(
-                stringify!($key).to_owned(),
-                format!("{:?}", value),
-            );
-        }
-    };
-    ($map:ident, $parameters:ident, ($key:path, u64),) => {
-        if let Ok(value) = $parameters.get_u64($key) {
-            $map.insert(
-                stringify!($key).to_owned(),
-                format!("{:?}", value),
-            );
-        }
-    };
-    ($map:ident, $parameters:ident, ($key:path, f64),) => {
-        if let Ok(value) = $parameters.get_f64($key) {
-            $map.insert(
-                stringify!($key).to_owned(),
-                format!("{:?}", value),
-            );
-        }
-    };
-    ($map:ident, $parameters:ident, ($key:path, $typ:ident), $(($next_key:path, $next_type:ident)),+,) => {
-        insert_parameter_debug!($map, $parameters, ($key, $typ),);
-        insert_parameter_debug!($map, $parameters, $(($next_key, $next_type)),+,);
-    };
+/// Returns the pointer to the `name`, to be passed to the parameter
+/// map accessors of NGX, or an error if the `name` isn't terminated with
+/// a NUL, as NGX would read past its end otherwise.
+fn parameter_name_ptr(name: &FeatureParameterName) -> Result<*const std::ffi::c_char> {
+    if !crate::parameter_key::is_valid_parameter_name(name) {
+        return Err(crate::Error::Other(format!(
+            "The parameter name {:?} must be terminated with a single NUL.",
+            String::from_utf8_lossy(name)
+        )));
+    }
+    Ok(name.as_ptr().cast())
 }
 
-/// Feature parameters is a collection of parameters of a feature (ha!).
-#[repr(transparent)]
-pub struct FeatureParameters(*mut bindings::NVSDK_NGX_Parameter);
+/// Returns the pointer to the `name` as [`parameter_name_ptr`], for the
+/// setters, which can't return the error.
+///
+/// # Panics
+///
+/// Panics if the `name` isn't terminated with a NUL.
+fn parameter_name_ptr_or_panic(name: &FeatureParameterName) -> *const std::ffi::c_char {
+    parameter_name_ptr(name).unwrap_or_else(|e| panic!("{e}"))
+}
 
-impl std::fmt::Debug for FeatureParameters {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
-        #[repr(transparent)]
-        struct FeatureParametersDebugPrinter<'a>(&'a FeatureParameters);
-
-        impl<'a> std::fmt::Debug for FeatureParametersDebugPrinter<'a> {
-            fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
-                use std::collections::HashMap;
-
-                let mut fmt = fmt.debug_struct("FeatureParameters");
-                fmt.field("pointer_address", &self.0 .0);
-
-                let populate_map = || -> HashMap<String, String> {
-                    let mut map = HashMap::new();
-                    let parameters = self.0;
-
-                    // TODO: add more
-                    insert_parameter_debug!(
-                        map,
-                        parameters,
-                        (
-                            crate::bindings::NVSDK_NGX_Parameter_SuperSampling_Available,
-                            bool
-                        ),
-                        (
-                            crate::bindings::NVSDK_NGX_Parameter_InPainting_Available,
-                            bool
-                        ),
-                        (
-                            crate::bindings::NVSDK_NGX_Parameter_ImageSuperResolution_Available,
-                            bool
-                        ),
-                        (
-                            crate::bindings::NVSDK_NGX_Parameter_SlowMotion_Available,
-                            bool
-                        ),
-                        (
-                            crate::bindings::NVSDK_NGX_Parameter_VideoSuperResolution_Available,
-                            bool
-                        ),
-                        (
-                            crate::bindings::NVSDK_NGX_Parameter_ImageSignalProcessing_Available,
-                            bool
-                        ),
-                        (crate::bindings::NVSDK_NGX_Parameter_DeepResolve_Available, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_DeepDVC_Available, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_SuperSampling_NeedsUpdatedDriver, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_InPainting_NeedsUpdatedDriver, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_ImageSuperResolution_NeedsUpdatedDriver, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_SlowMotion_NeedsUpdatedDriver, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_VideoSuperResolution_NeedsUpdatedDriver, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_ImageSignalProcessing_NeedsUpdatedDriver, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_DeepResolve_NeedsUpdatedDriver, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_DeepDVC_NeedsUpdatedDriver, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_FrameInterpolation_NeedsUpdatedDriver, bool),
-                        (crate::bindings::NVSDK_NGX_Parameter_NumFrames, u32),
-                        (crate::bindings::NVSDK_NGX_Parameter_Scale, u32),
-                        (crate::bindings::NVSDK_NGX_Parameter_OptLevel, u32),
-                        (
-                            crate::bindings::NVSDK_NGX_Parameter_IsDevSnippetBranch,
-                            bool
-                        ),
-                        (
-                            crate::bindings::NVSDK_NGX_Parameter_SuperSampling_ScaleFactor,
-                            f32
-                        ),
-                    );
-                    map
-                };
-                let map = populate_map();
-                fmt.field("parameters", &map).finish()
-            }
-        }
+/// A parameter map of NGX, only ever used behind a reference. The
+/// owned maps are [`FeatureParameters`], which dereference to it, and
+/// the maps NGX hands out are borrowed, like [`System::get_parameters`]
+/// or with [`ParameterMap::from_raw`].
+///
+/// The values are set through the shared references, as NGX allows, so
+/// that a map can be shared, for example, between several features.
+pub struct ParameterMap {
+    _opaque: std::cell::UnsafeCell<[u8; 0]>,
+    // The maps aren't thread-safe.
+    _not_send_sync: std::marker::PhantomData<*mut bindings::NVSDK_NGX_Parameter>,
+}
 
-        let debug = FeatureParametersDebugPrinter(self);
-        fmt.debug_tuple("FeatureParameters").field(&debug).finish()
+impl std::fmt::Debug for ParameterMap {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt.debug_struct("ParameterMap")
+            .field("pointer_address", &self.as_ptr())
+            .field("parameters", &self.dump())
+            .finish()
     }
 }
 
-impl FeatureParameters {
-    /// Create a new feature parameter set.
+impl ParameterMap {
+    /// Borrows the parameter map behind the `pointer`, like the ones NGX
+    /// passes to the callbacks.
     ///
-    /// # NVIDIA documentation
+    /// # Safety
     ///
-    /// This interface allows allocating a simple parameter setup using named fields, whose
-    /// lifetime the app must manage.
-    /// For example one can set width by calling Set(NVSDK_NGX_Parameter_Denoiser_Width,100) or
-    /// provide CUDA buffer pointer by calling Set(NVSDK_NGX_Parameter_Denoiser_Color,cudaBuffer)
-    /// For more details please see sample code.
-    /// Parameter maps output by NVSDK_NGX_AllocateParameters must NOT be freed using
-    /// the free/delete operator; to free a parameter map
-    /// output by NVSDK_NGX_AllocateParameters, NVSDK_NGX_DestroyParameters should be used.
-    /// Unlike with NVSDK_NGX_GetParameters, parameter maps allocated with NVSDK_NGX_AllocateParameters
-    /// must be destroyed by the app using NVSDK_NGX_DestroyParameters.
-    /// Also unlike with NVSDK_NGX_GetParameters, parameter maps output by NVSDK_NGX_AllocateParameters
-    /// do not come pre-populated with NGX capabilities and available features.
-    /// To create a new parameter map pre-populated with such information, NVSDK_NGX_GetCapabilityParameters
-    /// should be used.
-    /// This function may return NVSDK_NGX_Result_FAIL_OutOfDate if an older driver, which
-    /// does not support this API call is being used. In such a case, NVSDK_NGX_GetParameters
-    /// may be used as a fallback.
-    /// This function may only be called after a successful call into NVSDK_NGX_Init.
-    pub fn new(&self) -> Result<Self> {
-        let mut ptr: *mut bindings::NVSDK_NGX_Parameter = std::ptr::null_mut();
-        Result::from(unsafe { bindings::NVSDK_NGX_VULKAN_AllocateParameters(&mut ptr as *mut _) })
-            .map(|_| Self(ptr))
+    /// The `pointer` must point to a parameter map which stays alive for
+    /// the lifetime `'a`.
+    pub unsafe fn from_raw<'a>(pointer: *mut bindings::NVSDK_NGX_Parameter) -> &'a Self {
+        &*pointer.cast::<Self>()
     }
 
-    /// Get a feature parameter set populated with NGX and feature
-    /// capabilities.
-    ///
-    /// # NVIDIA documentation
+    /// Returns the pointer to the map, to be passed to NGX.
+    pub fn as_ptr(&self) -> *mut bindings::NVSDK_NGX_Parameter {
+        self as *const Self as *mut bindings::NVSDK_NGX_Parameter
+    }
+
+    /// Returns the values of all the parameters declared in the SDK
+    /// headers (the [`keys`]) which are set, by their names. Useful for
+    /// logging the parameters or comparing them between frames.
+    pub fn dump(&self) -> std::collections::BTreeMap<String, ParameterValue> {
+        keys::dump(self)
+    }
+
+    /// Returns the value of the parameter with the `key`.
     ///
-    /// This interface allows the app to create a new parameter map
-    /// pre-populated with NGX capabilities and available features.
-    /// The output parameter map can also be used for any purpose
-    /// parameter maps output by NVSDK_NGX_AllocateParameters can be used for
-    /// but it is not recommended to use NVSDK_NGX_GetCapabilityParameters
-    /// unless querying NGX capabilities and available features
-    /// due to the overhead associated with pre-populating the parameter map.
-    /// Parameter maps output by NVSDK_NGX_GetCapabilityParameters must NOT be freed using
-    /// the free/delete operator; to free a parameter map
-    /// output by NVSDK_NGX_GetCapabilityParameters, NVSDK_NGX_DestroyParameters should be used.
-    /// Unlike with NVSDK_NGX_GetParameters, parameter maps allocated with NVSDK_NGX_GetCapabilityParameters
-    /// must be destroyed by the app using NVSDK_NGX_DestroyParameters.
-    /// This function may return NVSDK_NGX_Result_FAIL_OutOfDate if an older driver, which
-    /// does not support this API call is being used. This function may only be called
-    /// after a successful call into NVSDK_NGX_Init.
-    /// If NVSDK_NGX_GetCapabilityParameters fails with NVSDK_NGX_Result_FAIL_OutOfDate,
-    /// NVSDK_NGX_GetParameters may be used as a fallback, to get a parameter map pre-populated
-    /// with NGX capabilities and available features.
-    pub fn get_capability_parameters() -> Result<Self> {
-        let mut ptr: *mut bindings::NVSDK_NGX_Parameter = std::ptr::null_mut();
-        Result::from(unsafe {
-            bindings::NVSDK_NGX_VULKAN_GetCapabilityParameters(&mut ptr as *mut _)
-        })
-        .map(|_| Self(ptr))
+    /// ```no_run
+    /// # fn f(parameters: &nvngx::FeatureParameters) -> nvngx::Result {
+    /// let width = parameters.get(&nvngx::keys::NVSDK_NGX_Parameter_OutWidth)?;
+    /// # Ok(()) }
+    /// ```
+    pub fn get<T: ParameterType>(&self, key: &ParameterKey<T>) -> Result<T> {
+        T::get(self, key.name())
+    }
+
+    /// Sets the `value` of the parameter with the `key`.
+    pub fn set<T: ParameterType>(&self, key: &ParameterKey<T>, value: T) {
+        value.set(self, key.name())
     }
 
     /// Sets the value for the parameter named `name` to be a
     /// type-erased (`void *`) pointer.
     pub fn set_ptr<T>(&self, name: &FeatureParameterName, ptr: *mut T) {
         unsafe {
-            bindings::NVSDK_NGX_Parameter_SetVoidPointer(
-                self.0,
-                name.as_ptr().cast(),
+            (Library::loaded().NVSDK_NGX_Parameter_SetVoidPointer)(
+                self.as_ptr(),
+                parameter_name_ptr_or_panic(name),
                 ptr as *mut _,
             );
         }
@@ -506,13 +1001,15 @@ impl FeatureParameters {
     /// `name`.
     pub fn get_ptr(&self, name: &FeatureParameterName) -> Result<*mut std::ffi::c_void> {
         let mut ptr = std::ptr::null_mut();
+        let name_ptr = parameter_name_ptr(name)?;
         Result::from(unsafe {
-            bindings::NVSDK_NGX_Parameter_GetVoidPointer(
-                self.0,
-                name.as_ptr().cast(),
+            (Library::loaded().NVSDK_NGX_Parameter_GetVoidPointer)(
+                self.as_ptr(),
+                name_ptr,
                 &mut ptr as *mut _,
             )
         })
+        .context(|| ErrorContext::new("NVSDK_NGX_Parameter_GetVoidPointer").parameter(name))
         .map(|_| ptr)
     }
 
@@ -522,9 +1019,9 @@ impl FeatureParameters {
     /// `true` and `0` being `false`.
     pub fn set_bool(&self, name: &FeatureParameterName, value: bool) {
         unsafe {
-            bindings::NVSDK_NGX_Parameter_SetI(
-                self.0,
-                name.as_ptr().cast(),
+            (Library::loaded().NVSDK_NGX_Parameter_SetI)(
+                self.as_ptr(),
+                parameter_name_ptr_or_panic(name),
                 if value { 1 } else { 0 },
             )
         }
@@ -536,117 +1033,454 @@ impl FeatureParameters {
     /// `true` and `0` being `false`.
     pub fn get_bool(&self, name: &FeatureParameterName) -> Result<bool> {
         let mut value = 0i32;
+        let name_ptr = parameter_name_ptr(name)?;
         Result::from(unsafe {
-            bindings::NVSDK_NGX_Parameter_GetI(self.0, name.as_ptr().cast(), &mut value as *mut _)
+            (Library::loaded().NVSDK_NGX_Parameter_GetI)(
+                self.as_ptr(),
+                name_ptr,
+                &mut value as *mut _,
+            )
         })
+        .context(|| ErrorContext::new("NVSDK_NGX_Parameter_GetI").parameter(name))
         .map(|_| value == 1)
     }
 
     /// Sets an [f32] value for the parameter named `name`.
     pub fn set_f32(&self, name: &FeatureParameterName, value: f32) {
-        unsafe { bindings::NVSDK_NGX_Parameter_SetF(self.0, name.as_ptr().cast(), value) }
+        unsafe {
+            (Library::loaded().NVSDK_NGX_Parameter_SetF)(
+                self.as_ptr(),
+                parameter_name_ptr_or_panic(name),
+                value,
+            )
+        }
     }
 
     /// Returns a [f32] value of a parameter named `name`.
     pub fn get_f32(&self, name: &FeatureParameterName) -> Result<f32> {
         let mut value = 0f32;
+        let name_ptr = parameter_name_ptr(name)?;
         Result::from(unsafe {
-            bindings::NVSDK_NGX_Parameter_GetF(self.0, name.as_ptr().cast(), &mut value as *mut _)
+            (Library::loaded().NVSDK_NGX_Parameter_GetF)(
+                self.as_ptr(),
+                name_ptr,
+                &mut value as *mut _,
+            )
         })
+        .context(|| ErrorContext::new("NVSDK_NGX_Parameter_GetF").parameter(name))
         .map(|_| value)
     }
 
     /// Sets an [u32] value for the parameter named `name`.
     pub fn set_u32(&self, name: &FeatureParameterName, value: u32) {
-        unsafe { bindings::NVSDK_NGX_Parameter_SetUI(self.0, name.as_ptr().cast(), value) }
+        unsafe {
+            (Library::loaded().NVSDK_NGX_Parameter_SetUI)(
+                self.as_ptr(),
+                parameter_name_ptr_or_panic(name),
+                value,
+            )
+        }
     }
 
     /// Returns a [u32] value of a parameter named `name`.
     pub fn get_u32(&self, name: &FeatureParameterName) -> Result<u32> {
         let mut value = 0u32;
+        let name_ptr = parameter_name_ptr(name)?;
         Result::from(unsafe {
-            bindings::NVSDK_NGX_Parameter_GetUI(self.0, name.as_ptr().cast(), &mut value as *mut _)
+            (Library::loaded().NVSDK_NGX_Parameter_GetUI)(
+                self.as_ptr(),
+                name_ptr,
+                &mut value as *mut _,
+            )
         })
+        .context(|| ErrorContext::new("NVSDK_NGX_Parameter_GetUI").parameter(name))
         .map(|_| value)
     }
 
     /// Sets an [f64] value for the parameter named `name`.
     pub fn set_f64(&self, name: &FeatureParameterName, value: f64) {
-        unsafe { bindings::NVSDK_NGX_Parameter_SetD(self.0, name.as_ptr().cast(), value) }
+        unsafe {
+            (Library::loaded().NVSDK_NGX_Parameter_SetD)(
+                self.as_ptr(),
+                parameter_name_ptr_or_panic(name),
+                value,
+            )
+        }
     }
 
     /// Returns a [f64] value of a parameter named `name`.
     pub fn get_f64(&self, name: &FeatureParameterName) -> Result<f64> {
         let mut value = 0f64;
+        let name_ptr = parameter_name_ptr(name)?;
         Result::from(unsafe {
-            bindings::NVSDK_NGX_Parameter_GetD(self.0, name.as_ptr().cast(), &mut value as *mut _)
+            (Library::loaded().NVSDK_NGX_Parameter_GetD)(
+                self.as_ptr(),
+                name_ptr,
+                &mut value as *mut _,
+            )
         })
+        .context(|| ErrorContext::new("NVSDK_NGX_Parameter_GetD").parameter(name))
         .map(|_| value)
     }
 
     /// Sets an [i32] value for the parameter named `name`.
     pub fn set_i32(&self, name: &FeatureParameterName, value: i32) {
-        unsafe { bindings::NVSDK_NGX_Parameter_SetI(self.0, name.as_ptr().cast(), value) }
+        unsafe {
+            (Library::loaded().NVSDK_NGX_Parameter_SetI)(
+                self.as_ptr(),
+                parameter_name_ptr_or_panic(name),
+                value,
+            )
+        }
     }
 
     /// Returns a [i32] value of a parameter named `name`.
     pub fn get_i32(&self, name: &FeatureParameterName) -> Result<i32> {
         let mut value = 0i32;
+        let name_ptr = parameter_name_ptr(name)?;
         Result::from(unsafe {
-            bindings::NVSDK_NGX_Parameter_GetI(self.0, name.as_ptr().cast(), &mut value as *mut _)
+            (Library::loaded().NVSDK_NGX_Parameter_GetI)(
+                self.as_ptr(),
+                name_ptr,
+                &mut value as *mut _,
+            )
         })
+        .context(|| ErrorContext::new("NVSDK_NGX_Parameter_GetI").parameter(name))
         .map(|_| value)
     }
 
     /// Sets an [u64] value for the parameter named `name`.
     pub fn set_u64(&self, name: &FeatureParameterName, value: u64) {
-        unsafe { bindings::NVSDK_NGX_Parameter_SetULL(self.0, name.as_ptr().cast(), value) }
+        unsafe {
+            (Library::loaded().NVSDK_NGX_Parameter_SetULL)(
+                self.as_ptr(),
+                parameter_name_ptr_or_panic(name),
+                value,
+            )
+        }
     }
 
     /// Returns a [u64] value of a parameter named `name`.
     pub fn get_u64(&self, name: &FeatureParameterName) -> Result<u64> {
         let mut value = 0u64;
+        let name_ptr = parameter_name_ptr(name)?;
         Result::from(unsafe {
-            bindings::NVSDK_NGX_Parameter_GetULL(self.0, name.as_ptr().cast(), &mut value as *mut _)
+            (Library::loaded().NVSDK_NGX_Parameter_GetULL)(
+                self.as_ptr(),
+                name_ptr,
+                &mut value as *mut _,
+            )
         })
+        .context(|| ErrorContext::new("NVSDK_NGX_Parameter_GetULL").parameter(name))
         .map(|_| value)
     }
 
     /// Returns `Ok` if the parameters claim to support the
     /// super sampling feature ([`bindings::NVSDK_NGX_Parameter_SuperSampling_Available`]).
     pub fn supports_super_sampling(&self) -> Result<()> {
-        if self.get_bool(bindings::NVSDK_NGX_Parameter_SuperSampling_NeedsUpdatedDriver)? {
-            let major =
-                self.get_u32(bindings::NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMajor)?;
-            let minor =
-                self.get_u32(bindings::NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMinor)?;
-            return Err(crate::Error::Other(format!("The SuperSampling feature requires a driver update. The driver version required should be higher or equal to {major}.{minor}")));
-        }
-        match self.get_bool(bindings::NVSDK_NGX_Parameter_SuperSampling_Available) {
+        self.supports_feature(
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+            &keys::NVSDK_NGX_Parameter_SuperSampling_Available,
+            &keys::NVSDK_NGX_Parameter_SuperSampling_NeedsUpdatedDriver,
+            &keys::NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMajor,
+            &keys::NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMinor,
+        )
+    }
+
+    /// Returns `Ok` if the parameters claim to support the
+    /// frame generation feature ([`bindings::NVSDK_NGX_Parameter_FrameGeneration_Available`]).
+    /// The drivers which don't report that parameter report the frame
+    /// generation under the frame interpolation name
+    /// ([`bindings::NVSDK_NGX_Parameter_FrameInterpolation_Available`]),
+    /// which is also the only name of its driver requirements.
+    pub fn supports_frame_generation(&self) -> Result<()> {
+        let feature = NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration;
+        match self.get(&keys::NVSDK_NGX_Parameter_FrameGeneration_Available) {
+            Ok(true) => Ok(()),
+            Ok(false)
+                if !matches!(
+                    self.get(&keys::NVSDK_NGX_Parameter_FrameInterpolation_NeedsUpdatedDriver),
+                    Ok(true)
+                ) =>
+            {
+                Err(crate::Error::FeatureUnavailable(feature))
+            }
+            _ => self.supports_feature(
+                feature,
+                &keys::NVSDK_NGX_Parameter_FrameInterpolation_Available,
+                &keys::NVSDK_NGX_Parameter_FrameInterpolation_NeedsUpdatedDriver,
+                &keys::NVSDK_NGX_Parameter_FrameInterpolation_MinDriverVersionMajor,
+                &keys::NVSDK_NGX_Parameter_FrameInterpolation_MinDriverVersionMinor,
+            ),
+        }
+    }
+
+    /// Returns `Ok` if the parameters claim to support the ray
+    /// reconstruction feature ([`bindings::NVSDK_NGX_Parameter_SuperSamplingDenoising_Available`]).
+    pub fn supports_ray_reconstruction(&self) -> Result<()> {
+        self.supports_feature(
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction,
+            &keys::NVSDK_NGX_Parameter_SuperSamplingDenoising_Available,
+            &keys::NVSDK_NGX_Parameter_SuperSamplingDenoising_NeedsUpdatedDriver,
+            &keys::NVSDK_NGX_Parameter_SuperSamplingDenoising_MinDriverVersionMajor,
+            &keys::NVSDK_NGX_Parameter_SuperSamplingDenoising_MinDriverVersionMinor,
+        )
+    }
+
+    /// Returns `Ok` if the parameters claim to support the image
+    /// super resolution feature ([`bindings::NVSDK_NGX_Parameter_ImageSuperResolution_Available`]).
+    pub fn supports_image_super_resolution(&self) -> Result<()> {
+        self.supports_feature(
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_ImageSuperResolution,
+            &keys::NVSDK_NGX_Parameter_ImageSuperResolution_Available,
+            &keys::NVSDK_NGX_Parameter_ImageSuperResolution_NeedsUpdatedDriver,
+            &keys::NVSDK_NGX_Parameter_ImageSuperResolution_MinDriverVersionMajor,
+            &keys::NVSDK_NGX_Parameter_ImageSuperResolution_MinDriverVersionMinor,
+        )
+    }
+
+    /// Returns `Ok` if the parameters claim to support the in-painting
+    /// feature ([`bindings::NVSDK_NGX_Parameter_InPainting_Available`]).
+    pub fn supports_in_painting(&self) -> Result<()> {
+        self.supports_feature(
+            NVSDK_NGX_Feature::NVSDK_NGX_Feature_InPainting,
+            &keys::NVSDK_NGX_Parameter_InPainting_Available,
+            &keys::NVSDK_NGX_Parameter_InPainting_NeedsUpdatedDriver,
+            &keys::NVSDK_NGX_Parameter_InPainting_MinDriverVersionMajor,
+            &keys::NVSDK_NGX_Parameter_InPainting_MinDriverVersionMinor,
+        )
+    }
+
+    /// Returns `Ok` if the `available` parameter is set and the driver
+    /// doesn't need to be updated for the `feature`.
+    fn supports_feature(
+        &self,
+        feature: NVSDK_NGX_Feature,
+        available: &ParameterKey<bool>,
+        needs_updated_driver: &ParameterKey<bool>,
+        min_driver_version_major: &ParameterKey<u32>,
+        min_driver_version_minor: &ParameterKey<u32>,
+    ) -> Result<()> {
+        if self.get(needs_updated_driver)? {
+            return Err(crate::Error::DriverOutdated {
+                feature,
+                min_driver_version_major: self.get(min_driver_version_major)?,
+                min_driver_version_minor: self.get(min_driver_version_minor)?,
+            });
+        }
+        match self.get(available) {
             Ok(true) => Ok(()),
-            Ok(false) => Err(crate::Error::Other(
-                "The SuperSampling feature isn't supported on this platform.".to_string(),
-            )),
+            Ok(false) => Err(crate::Error::FeatureUnavailable(feature)),
             Err(e) => Err(e),
         }
     }
 
-    /// Returns `Ok` if the parameters claim to support the
-    /// super sampling feature ([`bindings::NVSDK_NGX_Parameter_SuperSampling_Available`]).
-    pub fn supports_super_sampling_static() -> Result<()> {
-        Self::get_capability_parameters()?.supports_super_sampling()
+    /// Returns the type of the DLSS snippets loaded
+    /// ([`bindings::NVSDK_NGX_Parameter_IsDevSnippetBranch`]).
+    pub fn get_dlss_library_type(&self) -> Result<DlssLibraryType> {
+        self.get(&keys::NVSDK_NGX_Parameter_IsDevSnippetBranch)
+            .map(|is_development| {
+                if is_development {
+                    DlssLibraryType::Development
+                } else {
+                    DlssLibraryType::Release
+                }
+            })
     }
 
     /// Returns `true` if the SuperSampling feature is initialised
     /// correctly.
     pub fn is_super_sampling_initialised(&self) -> bool {
-        self.get_bool(bindings::NVSDK_NGX_Parameter_SuperSampling_FeatureInitResult)
-            .unwrap_or(false)
+        matches!(
+            self.get(&keys::NVSDK_NGX_Parameter_SuperSampling_FeatureInitResult),
+            Ok(code) if bindings::NVSDK_NGX_Result(code as u32).is_success()
+        )
+    }
+
+    /// Returns `true` if the ray reconstruction feature is initialised
+    /// correctly.
+    pub fn is_ray_reconstruction_initialised(&self) -> bool {
+        matches!(
+            self.get(&keys::NVSDK_NGX_Parameter_SuperSamplingDenoising_FeatureInitResult),
+            Ok(code) if bindings::NVSDK_NGX_Result(code as u32).is_success()
+        )
+    }
+}
+
+/// A parameter map of a [`System`], which is either allocated by the
+/// application or, on the older drivers, the map of NGX borrowed from
+/// the system with [`System::get_parameters`].
+#[derive(Debug)]
+pub enum SystemParameters<'a> {
+    /// A map owned by the application.
+    Allocated(FeatureParameters),
+    /// The map of NGX.
+    Ngx(&'a ParameterMap),
+}
+
+impl std::ops::Deref for SystemParameters<'_> {
+    type Target = ParameterMap;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Allocated(parameters) => parameters,
+            Self::Ngx(parameters) => parameters,
+        }
+    }
+}
+
+/// Feature parameters is a collection of parameters of a feature (ha!).
+/// This is a parameter map owned by the application, which is destroyed
+/// when dropped. The parameters are accessed through the
+/// [`ParameterMap`] it dereferences to.
+///
+/// The map keeps NGX initialised for the device of the [`System`] it was
+/// obtained from, so that it is never used after NGX is shut down.
+pub struct FeatureParameters {
+    pointer: *mut bindings::NVSDK_NGX_Parameter,
+    _system: Arc<SystemHandle>,
+}
+
+impl std::fmt::Debug for FeatureParameters {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt.debug_struct("FeatureParameters")
+            .field("pointer_address", &self.pointer)
+            .field("parameters", &self.dump())
+            .finish()
+    }
+}
+
+impl std::ops::Deref for FeatureParameters {
+    type Target = ParameterMap;
+
+    fn deref(&self) -> &ParameterMap {
+        // The map is alive until the value is dropped.
+        unsafe { ParameterMap::from_raw(self.pointer) }
+    }
+}
+
+impl FeatureParameters {
+    /// Create a new feature parameter set.
+    ///
+    /// # NVIDIA documentation
+    ///
+    /// This interface allows allocating a simple parameter setup using named fields, whose
+    /// lifetime the app must manage.
+    /// For example one can set width by calling Set(NVSDK_NGX_Parameter_Denoiser_Width,100) or
+    /// provide CUDA buffer pointer by calling Set(NVSDK_NGX_Parameter_Denoiser_Color,cudaBuffer)
+    /// For more details please see sample code.
+    /// Parameter maps output by NVSDK_NGX_AllocateParameters must NOT be freed using
+    /// the free/delete operator; to free a parameter map
+    /// output by NVSDK_NGX_AllocateParameters, NVSDK_NGX_DestroyParameters should be used.
+    /// Unlike with NVSDK_NGX_GetParameters, parameter maps allocated with NVSDK_NGX_AllocateParameters
+    /// must be destroyed by the app using NVSDK_NGX_DestroyParameters.
+    /// Also unlike with NVSDK_NGX_GetParameters, parameter maps output by NVSDK_NGX_AllocateParameters
+    /// do not come pre-populated with NGX capabilities and available features.
+    /// To create a new parameter map pre-populated with such information, NVSDK_NGX_GetCapabilityParameters
+    /// should be used.
+    /// This function may return NVSDK_NGX_Result_FAIL_OutOfDate if an older driver, which
+    /// does not support this API call is being used. In such a case, NVSDK_NGX_GetParameters
+    /// may be used as a fallback.
+    /// This function may only be called after a successful call into NVSDK_NGX_Init.
+    ///
+    /// Use [`System::allocate_parameters`] to fall back to the map of
+    /// NGX on such an older driver.
+    pub fn new(system: &System) -> Result<Self> {
+        let library = Library::get()?;
+        let mut pointer: *mut bindings::NVSDK_NGX_Parameter = std::ptr::null_mut();
+        Result::from(unsafe {
+            (library.NVSDK_NGX_VULKAN_AllocateParameters)(&mut pointer as *mut _)
+        })
+        .context(|| ErrorContext::new("NVSDK_NGX_VULKAN_AllocateParameters"))?;
+        Ok(Self {
+            pointer,
+            _system: Arc::clone(&system.handle),
+        })
+    }
+
+    /// Get a feature parameter set populated with NGX and feature
+    /// capabilities.
+    ///
+    /// # NVIDIA documentation
+    ///
+    /// This interface allows the app to create a new parameter map
+    /// pre-populated with NGX capabilities and available features.
+    /// The output parameter map can also be used for any purpose
+    /// parameter maps output by NVSDK_NGX_AllocateParameters can be used for
+    /// but it is not recommended to use NVSDK_NGX_GetCapabilityParameters
+    /// unless querying NGX capabilities and available features
+    /// due to the overhead associated with pre-populating the parameter map.
+    /// Parameter maps output by NVSDK_NGX_GetCapabilityParameters must NOT be freed using
+    /// the free/delete operator; to free a parameter map
+    /// output by NVSDK_NGX_GetCapabilityParameters, NVSDK_NGX_DestroyParameters should be used.
+    /// Unlike with NVSDK_NGX_GetParameters, parameter maps allocated with NVSDK_NGX_GetCapabilityParameters
+    /// must be destroyed by the app using NVSDK_NGX_DestroyParameters.
+    /// This function may return NVSDK_NGX_Result_FAIL_OutOfDate if an older driver, which
+    /// does not support this API call is being used. This function may only be called
+    /// after a successful call into NVSDK_NGX_Init.
+    /// If NVSDK_NGX_GetCapabilityParameters fails with NVSDK_NGX_Result_FAIL_OutOfDate,
+    /// NVSDK_NGX_GetParameters may be used as a fallback, to get a parameter map pre-populated
+    /// with NGX capabilities and available features.
+    ///
+    /// Use [`System::get_capability_parameters`] to fall back to the map
+    /// of NGX on such an older driver.
+    pub fn get_capability_parameters(system: &System) -> Result<Self> {
+        let library = Library::get()?;
+        let mut pointer: *mut bindings::NVSDK_NGX_Parameter = std::ptr::null_mut();
+        Result::from(unsafe {
+            (library.NVSDK_NGX_VULKAN_GetCapabilityParameters)(&mut pointer as *mut _)
+        })
+        .context(|| ErrorContext::new("NVSDK_NGX_VULKAN_GetCapabilityParameters"))?;
+        Ok(Self {
+            pointer,
+            _system: Arc::clone(&system.handle),
+        })
+    }
+
+    /// Returns `Ok` if the parameters claim to support the
+    /// super sampling feature ([`bindings::NVSDK_NGX_Parameter_SuperSampling_Available`]).
+    pub fn supports_super_sampling_static(system: &System) -> Result<()> {
+        system
+            .get_capability_parameters()?
+            .supports_super_sampling()
+    }
+
+    /// Returns `Ok` if the parameters claim to support the
+    /// frame generation feature, see [`ParameterMap::supports_frame_generation`].
+    pub fn supports_frame_generation_static(system: &System) -> Result<()> {
+        system
+            .get_capability_parameters()?
+            .supports_frame_generation()
+    }
+
+    /// Returns `Ok` if the parameters claim to support the ray
+    /// reconstruction feature ([`bindings::NVSDK_NGX_Parameter_SuperSamplingDenoising_Available`]).
+    pub fn supports_ray_reconstruction_static(system: &System) -> Result<()> {
+        system
+            .get_capability_parameters()?
+            .supports_ray_reconstruction()
+    }
+
+    /// Returns `Ok` if the parameters claim to support the image
+    /// super resolution feature ([`bindings::NVSDK_NGX_Parameter_ImageSuperResolution_Available`]).
+    pub fn supports_image_super_resolution_static(system: &System) -> Result<()> {
+        system
+            .get_capability_parameters()?
+            .supports_image_super_resolution()
+    }
+
+    /// Returns `Ok` if the parameters claim to support the in-painting
+    /// feature ([`bindings::NVSDK_NGX_Parameter_InPainting_Available`]).
+    pub fn supports_in_painting_static(system: &System) -> Result<()> {
+        system.get_capability_parameters()?.supports_in_painting()
     }
 
     /// Deallocates the feature parameter set.
     fn release(&self) -> Result {
-        unsafe { bindings::NVSDK_NGX_VULKAN_DestroyParameters(self.0) }.into()
+        Result::from(unsafe {
+            (Library::loaded().NVSDK_NGX_VULKAN_DestroyParameters)(self.pointer)
+        })
+        .context(|| ErrorContext::new("NVSDK_NGX_VULKAN_DestroyParameters"))
     }
 }
 
@@ -661,46 +1495,96 @@ impl Drop for FeatureParameters {
     }
 }
 
-/// Describes a single NGX feature.
+impl NVSDK_NGX_Feature {
+    /// Returns the name of the feature, if it is known to the NGX
+    /// headers the crate was built with.
+    pub fn name(self) -> Option<&'static str> {
+        Some(match self {
+            Self::NVSDK_NGX_Feature_Reserved0 => "NVSDK_NGX_Feature_Reserved0",
+            Self::NVSDK_NGX_Feature_SuperSampling => "NVSDK_NGX_Feature_SuperSampling",
+            Self::NVSDK_NGX_Feature_InPainting => "NVSDK_NGX_Feature_InPainting",
+            Self::NVSDK_NGX_Feature_ImageSuperResolution => {
+                "NVSDK_NGX_Feature_ImageSuperResolution"
+            }
+            Self::NVSDK_NGX_Feature_SlowMotion => "NVSDK_NGX_Feature_SlowMotion",
+            Self::NVSDK_NGX_Feature_VideoSuperResolution => {
+                "NVSDK_NGX_Feature_VideoSuperResolution"
+            }
+            Self::NVSDK_NGX_Feature_Reserved1 => "NVSDK_NGX_Feature_Reserved1",
+            Self::NVSDK_NGX_Feature_Reserved2 => "NVSDK_NGX_Feature_Reserved2",
+            Self::NVSDK_NGX_Feature_Reserved3 => "NVSDK_NGX_Feature_Reserved3",
+            Self::NVSDK_NGX_Feature_ImageSignalProcessing => {
+                "NVSDK_NGX_Feature_ImageSignalProcessing"
+            }
+            Self::NVSDK_NGX_Feature_DeepResolve => "NVSDK_NGX_Feature_DeepResolve",
+            Self::NVSDK_NGX_Feature_FrameGeneration => "NVSDK_NGX_Feature_FrameGeneration",
+            Self::NVSDK_NGX_Feature_DeepDVC => "NVSDK_NGX_Feature_DeepDVC",
+            Self::NVSDK_NGX_Feature_RayReconstruction => "NVSDK_NGX_Feature_RayReconstruction",
+            Self::NVSDK_NGX_Feature_Reserved_SDK => "NVSDK_NGX_Feature_Reserved_SDK",
+            Self::NVSDK_NGX_Feature_Reserved_Core => "NVSDK_NGX_Feature_Reserved_Core",
+            Self::NVSDK_NGX_Feature_Reserved_Unknown => "NVSDK_NGX_Feature_Reserved_Unknown",
+            _ => return None,
+        })
+    }
+}
+
+impl std::fmt::Display for NVSDK_NGX_Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "NVSDK_NGX_Feature({})", self.0),
+        }
+    }
+}
+
+/// Describes a single NGX feature, which keeps the [`System`] it was
+/// created with initialised.
 #[derive(Debug)]
 pub struct Feature {
-    handle: Rc<FeatureHandle>,
+    handle: FeatureHandle,
     feature_type: bindings::NVSDK_NGX_Feature,
     parameters: Rc<FeatureParameters>,
+    /// Declared last, so that the feature is released before NGX might
+    /// be shut down.
+    _system: Arc<SystemHandle>,
 }
 
 impl Feature {
     /// Creates a new feature.
     pub fn new(
-        device: vk::Device,
+        system: &System,
         command_buffer: vk::CommandBuffer,
         feature_type: bindings::NVSDK_NGX_Feature,
-        parameters: FeatureParameters,
+        parameters: impl Into<Rc<FeatureParameters>>,
     ) -> Result<Self> {
+        let parameters = parameters.into();
         let mut handle = FeatureHandle::new();
         Result::from(unsafe {
-            bindings::NVSDK_NGX_VULKAN_CreateFeature1(
-                device.as_pointer_mut(),
+            (Library::loaded().NVSDK_NGX_VULKAN_CreateFeature1)(
+                system.handle.device.as_pointer_mut(),
                 command_buffer.as_pointer_mut(),
                 feature_type,
-                parameters.0,
+                parameters.as_ptr(),
                 &mut handle.0 as *mut _,
             )
         })
+        .context(|| ErrorContext::new("NVSDK_NGX_VULKAN_CreateFeature1").feature(feature_type))
         .map(|_| Self {
-            handle: handle.into(),
+            handle,
             feature_type,
-            parameters: parameters.into(),
+            parameters,
+            _system: system.handle.clone(),
         })
     }
 
     /// Creates a new SuperSampling feature.
     pub fn new_super_sampling(
-        device: vk::Device,
+        system: &System,
         command_buffer: vk::CommandBuffer,
-        parameters: FeatureParameters,
-        mut super_sampling_create_parameters: SuperSamplingCreateParameters,
+        parameters: impl Into<Rc<FeatureParameters>>,
+        super_sampling_create_parameters: SuperSamplingCreateParameters,
     ) -> Result<SuperSamplingFeature> {
+        let parameters = parameters.into();
         let feature_type = NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling;
         let rendering_resolution = vk::Extent2D::builder()
             .width(super_sampling_create_parameters.0.Feature.InWidth)
@@ -710,49 +1594,152 @@ impl Feature {
             .width(super_sampling_create_parameters.0.Feature.InTargetWidth)
             .height(super_sampling_create_parameters.0.Feature.InTargetHeight)
             .build();
-        unsafe {
-            let mut handle = FeatureHandle::new();
-            Result::from(bindings::HELPERS_NGX_VULKAN_CREATE_DLSS_EXT1(
-                device.as_pointer_mut(),
-                command_buffer.as_pointer_mut(),
-                1,
-                1,
-                &mut handle.0 as *mut _,
-                parameters.0,
-                &mut super_sampling_create_parameters.0 as *mut _,
-            ))
-            .and_then(|_| {
-                SuperSamplingFeature::new(
-                    Self {
-                        handle: handle.into(),
-                        feature_type,
-                        parameters: parameters.into(),
-                    },
-                    rendering_resolution,
-                    target_resolution,
-                )
-            })
-        }
+        let mut handle = FeatureHandle::new();
+        helpers::vulkan_create_dlss_ext1(
+            system.handle.device,
+            command_buffer,
+            1,
+            1,
+            &mut handle,
+            &parameters,
+            &super_sampling_create_parameters.0,
+        )?;
+        SuperSamplingFeature::new(
+            Self {
+                handle,
+                feature_type,
+                parameters,
+                _system: system.handle.clone(),
+            },
+            rendering_resolution,
+            target_resolution,
+        )
+    }
+
+    /// Creates a new ray reconstruction feature.
+    pub fn new_ray_reconstruction(
+        system: &System,
+        command_buffer: vk::CommandBuffer,
+        parameters: impl Into<Rc<FeatureParameters>>,
+        ray_reconstruction_create_parameters: RayReconstructionCreateParameters,
+    ) -> Result<RayReconstructionFeature> {
+        let parameters = parameters.into();
+        let feature_type = NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction;
+        let create_parameters = &ray_reconstruction_create_parameters.0;
+        let rendering_resolution = vk::Extent2D::builder()
+            .width(create_parameters.InWidth)
+            .height(create_parameters.InHeight)
+            .build();
+        let target_resolution = vk::Extent2D::builder()
+            .width(create_parameters.InTargetWidth)
+            .height(create_parameters.InTargetHeight)
+            .build();
+        let mut handle = FeatureHandle::new();
+        helpers::vulkan_create_dlssd_ext1(
+            system.handle.device,
+            command_buffer,
+            1,
+            1,
+            &mut handle,
+            &parameters,
+            create_parameters,
+        )?;
+        RayReconstructionFeature::new(
+            Self {
+                handle,
+                feature_type,
+                parameters,
+                _system: system.handle.clone(),
+            },
+            rendering_resolution,
+            target_resolution,
+        )
+    }
+
+    /// Creates a new image super resolution feature.
+    pub fn new_image_super_resolution(
+        system: &System,
+        command_buffer: vk::CommandBuffer,
+        parameters: impl Into<Rc<FeatureParameters>>,
+        image_super_resolution_create_parameters: ImageSuperResolutionCreateParameters,
+    ) -> Result<ImageSuperResolutionFeature> {
+        let parameters = parameters.into();
+        image_super_resolution_create_parameters.apply(&parameters);
+        let feature_type = NVSDK_NGX_Feature::NVSDK_NGX_Feature_ImageSuperResolution;
+        let feature = Self::new(system, command_buffer, feature_type, parameters)?;
+        ImageSuperResolutionFeature::new(
+            feature,
+            vk::Extent2D::builder()
+                .width(image_super_resolution_create_parameters.width)
+                .height(image_super_resolution_create_parameters.height)
+                .build(),
+            image_super_resolution_create_parameters.scale,
+        )
+    }
+
+    /// Creates a new in-painting feature.
+    pub fn new_in_painting(
+        system: &System,
+        command_buffer: vk::CommandBuffer,
+        parameters: impl Into<Rc<FeatureParameters>>,
+        in_painting_create_parameters: InPaintingCreateParameters,
+    ) -> Result<InPaintingFeature> {
+        let parameters = parameters.into();
+        in_painting_create_parameters.apply(&parameters);
+        let feature_type = NVSDK_NGX_Feature::NVSDK_NGX_Feature_InPainting;
+        let feature = Self::new(system, command_buffer, feature_type, parameters)?;
+        InPaintingFeature::new(
+            feature,
+            vk::Extent2D::builder()
+                .width(in_painting_create_parameters.width)
+                .height(in_painting_create_parameters.height)
+                .build(),
+        )
     }
 
-    /// Creates the Frame Generation feature.
+    /// Creates a new frame generation feature.
     pub fn new_frame_generation(
-        device: vk::Device,
+        system: &System,
         command_buffer: vk::CommandBuffer,
-        parameters: FeatureParameters,
-    ) -> Result<Self> {
+        parameters: impl Into<Rc<FeatureParameters>>,
+        frame_generation_create_parameters: FrameGenerationCreateParameters,
+    ) -> Result<FrameGenerationFeature> {
+        let parameters = parameters.into();
+        frame_generation_create_parameters.apply(&parameters);
         let feature_type = NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration;
-        Self::new(device, command_buffer, feature_type, parameters)
+        let feature = Self::new(system, command_buffer, feature_type, parameters)?;
+        FrameGenerationFeature::new(
+            feature,
+            vk::Extent2D::builder()
+                .width(frame_generation_create_parameters.width)
+                .height(frame_generation_create_parameters.height)
+                .build(),
+        )
+    }
+
+    /// Releases the feature, returning the error, unlike dropping the
+    /// feature, which only logs it.
+    pub fn destroy(mut self) -> Result {
+        let handle = std::mem::take(&mut self.handle);
+        if handle.0.is_null() {
+            return Ok(());
+        }
+        let result = handle.release();
+        std::mem::forget(handle);
+        result
     }
 
-    /// Returns the parameters associated with this feature.
+    /// Returns the parameters associated with this feature. The
+    /// parameters are set through the shared reference, even if the map
+    /// is shared with other features.
     pub fn get_parameters(&self) -> &FeatureParameters {
         &self.parameters
     }
 
-    /// Returns the parameters associated with this feature.
-    pub fn get_parameters_mut(&mut self) -> &mut FeatureParameters {
-        Rc::get_mut(&mut self.parameters).unwrap()
+    /// Returns the parameters associated with this feature, to create
+    /// another feature with the same parameter map.
+    pub fn get_shared_parameters(&self) -> Rc<FeatureParameters> {
+        self.parameters.clone()
     }
 
     /// Returns the type of this feature.
@@ -765,6 +1752,27 @@ impl Feature {
         self.feature_type == NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling
     }
 
+    /// Returns [`true`] if this feature is the ray reconstruction one.
+    pub fn is_ray_reconstruction(&self) -> bool {
+        self.feature_type == NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction
+    }
+
+    /// Returns [`true`] if this feature is the image super resolution
+    /// one.
+    pub fn is_image_super_resolution(&self) -> bool {
+        self.feature_type == NVSDK_NGX_Feature::NVSDK_NGX_Feature_ImageSuperResolution
+    }
+
+    /// Returns [`true`] if this feature is the in-painting one.
+    pub fn is_in_painting(&self) -> bool {
+        self.feature_type == NVSDK_NGX_Feature::NVSDK_NGX_Feature_InPainting
+    }
+
+    /// Returns [`true`] if this feature is the frame generation one.
+    pub fn is_frame_generation(&self) -> bool {
+        self.feature_type == NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration
+    }
+
     /// Returns the number of bytes needed for the scratch buffer for
     /// this feature.
     ///
@@ -780,12 +1788,15 @@ impl Feature {
     pub fn get_scratch_buffer_size(&self) -> Result<usize> {
         let mut size = 0usize;
         Result::from(unsafe {
-            bindings::NVSDK_NGX_VULKAN_GetScratchBufferSize(
+            (Library::loaded().NVSDK_NGX_VULKAN_GetScratchBufferSize)(
                 self.feature_type,
-                self.parameters.0 as _,
+                self.parameters.as_ptr() as _,
                 &mut size as *mut _,
             )
         })
+        .context(|| {
+            ErrorContext::new("NVSDK_NGX_VULKAN_GetScratchBufferSize").feature(self.feature_type)
+        })
         .map(|_| size)
     }
 
@@ -799,55 +1810,187 @@ impl Feature {
     /// as possible (for example provide all render targets like color,
     /// albedo, normals, depth etc)
     pub fn evaluate(&self, command_buffer: vk::CommandBuffer) -> Result {
-        unsafe {
-            bindings::NVSDK_NGX_VULKAN_EvaluateFeature_C(
+        Result::from(unsafe {
+            (Library::loaded().NVSDK_NGX_VULKAN_EvaluateFeature_C)(
                 command_buffer.as_pointer_mut(),
                 self.handle.0,
-                self.parameters.0,
+                self.parameters.as_ptr(),
                 Some(feature_progress_callback),
             )
+        })
+        .context(|| {
+            ErrorContext::new("NVSDK_NGX_VULKAN_EvaluateFeature_C").feature(self.feature_type)
+        })
+    }
+
+    /// Evaluates the feature as [`Self::evaluate`], reporting the
+    /// progress, from `0.0` to `1.0`, to the `progress` callback which
+    /// may cancel the evaluation.
+    ///
+    /// NGX reports the progress on the thread evaluating the feature
+    /// before the evaluation returns, so several features may be
+    /// evaluated on different threads at once, each with its own
+    /// callback. A panic in the callback cancels the evaluation and is
+    /// resumed once NGX returns.
+    ///
+    /// As the NGX callback doesn't take any user data, the `progress`
+    /// callback is kept in a thread-local. The progress NGX would report
+    /// on any other thread never reaches it and can't cancel the
+    /// evaluation: such reports are only logged as warnings.
+    pub fn evaluate_with_progress<F>(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        mut progress: F,
+    ) -> Result
+    where
+        F: FnMut(f32) -> EvaluationControl,
+    {
+        let result = {
+            let _scope = ProgressCallbackScope::new(&mut progress);
+            self.evaluate(command_buffer)
+        };
+        if let Some(payload) = PROGRESS_CALLBACK_PANIC.with(|panic| panic.take()) {
+            std::panic::resume_unwind(payload);
         }
-        .into()
+        result
     }
 }
 
-unsafe extern "C" fn feature_progress_callback(progress: f32, _should_cancel: *mut bool) {
-    log::debug!("Feature evalution progress={progress}.");
+/// Whether an evaluation should go on, as returned by the progress
+/// callback of [`Feature::evaluate_with_progress`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EvaluationControl {
+    /// Goes on with the evaluation.
+    Continue,
+    /// Cancels the evaluation.
+    Cancel,
 }
 
-/// Describes a set of NGX feature requirements.
-#[repr(transparent)]
-#[derive(Debug)]
-pub struct FeatureRequirement(bindings::NVSDK_NGX_FeatureRequirement);
+/// A progress callback with its lifetime erased, only valid within the
+/// [`ProgressCallbackScope`] which set it.
+type ProgressCallback = *mut (dyn FnMut(f32) -> EvaluationControl + 'static);
+
+/// The number of the evaluations with a progress callback running on
+/// all the threads, to tell the progress reported on another thread
+/// than the one evaluating from the progress of plain evaluations.
+static EVALUATIONS_WITH_PROGRESS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The progress callback of the evaluation running on the thread, as
+    /// the NGX callback doesn't take any user data.
+    static PROGRESS_CALLBACK: std::cell::Cell<Option<ProgressCallback>> =
+        std::cell::Cell::new(None);
+    /// The number of the evaluations with a progress callback running on
+    /// the thread, including the ones whose callback is running.
+    static THREAD_EVALUATIONS_WITH_PROGRESS: std::cell::Cell<usize> =
+        const { std::cell::Cell::new(0) };
+    /// The payload of a panic in the progress callback, to be resumed
+    /// once the evaluation returns.
+    static PROGRESS_CALLBACK_PANIC: std::cell::Cell<Option<Box<dyn std::any::Any + Send>>> =
+        std::cell::Cell::new(None);
+}
 
-/// A helpful type alias to quickly mention "DLSS".
-pub type DlssFeature = SuperSamplingFeature;
+/// Sets the progress callback of the thread until dropped, restoring
+/// the callback of the enclosing evaluation, if any.
+struct ProgressCallbackScope<'a> {
+    previous: Option<ProgressCallback>,
+    _callback: std::marker::PhantomData<&'a mut ()>,
+}
 
-/// Optimal settings for the DLSS based on the desired quality level and
-/// resolution.
-#[derive(Debug)]
-pub struct SuperSamplingOptimalSettings {
-    /// The render width which the renderer must render to before
-    /// upscaling.
-    pub render_width: u32,
-    /// The render height which the renderer must render to before
-    /// upscaling.
-    pub render_height: u32,
-    /// The target width desired, to which the SuperSampling feature
-    /// will upscale to.
-    pub target_width: u32,
-    /// The target height desired, to which the SuperSampling feature
-    /// will upscale to.
+impl<'a> ProgressCallbackScope<'a> {
+    fn new(callback: &'a mut (dyn FnMut(f32) -> EvaluationControl + 'a)) -> Self {
+        // SAFETY: only the lifetime is erased, and the callback is
+        // removed from the thread before the borrow ends.
+        let callback = unsafe {
+            std::mem::transmute::<*mut (dyn FnMut(f32) -> EvaluationControl + 'a), ProgressCallback>(
+                callback,
+            )
+        };
+        EVALUATIONS_WITH_PROGRESS.fetch_add(1, Ordering::Relaxed);
+        THREAD_EVALUATIONS_WITH_PROGRESS.with(|count| count.set(count.get() + 1));
+        Self {
+            previous: PROGRESS_CALLBACK.with(|current| current.replace(Some(callback))),
+            _callback: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Drop for ProgressCallbackScope<'_> {
+    fn drop(&mut self) {
+        PROGRESS_CALLBACK.with(|current| current.set(self.previous));
+        THREAD_EVALUATIONS_WITH_PROGRESS.with(|count| count.set(count.get() - 1));
+        EVALUATIONS_WITH_PROGRESS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Returns `true` if the progress reported on the current thread can
+/// only be the one of an evaluation with a progress callback running on
+/// another thread.
+fn is_progress_on_another_thread() -> bool {
+    THREAD_EVALUATIONS_WITH_PROGRESS.with(|count| count.get()) == 0
+        && EVALUATIONS_WITH_PROGRESS.load(Ordering::Relaxed) > 0
+}
+
+unsafe extern "C" fn feature_progress_callback(progress: f32, should_cancel: *mut bool) {
+    let Some(callback) = PROGRESS_CALLBACK.with(|current| current.take()) else {
+        if is_progress_on_another_thread() {
+            log::warn!(
+                "Feature evaluation progress={progress} reported on {:?}, which isn't evaluating \
+                 a feature with a progress callback, so the callback can't be called.",
+                std::thread::current().id()
+            );
+        } else {
+            log::debug!("Feature evalution progress={progress}.");
+        }
+        return;
+    };
+    // The callback is taken from the thread while it runs, so that it
+    // can't be called again if it evaluates a feature itself, and a
+    // callback which panicked isn't called again.
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (*callback)(progress)));
+    let control = match result {
+        Ok(control) => {
+            PROGRESS_CALLBACK.with(|current| current.set(Some(callback)));
+            control
+        }
+        Err(payload) => {
+            PROGRESS_CALLBACK_PANIC.with(|panic| panic.set(Some(payload)));
+            EvaluationControl::Cancel
+        }
+    };
+    if control == EvaluationControl::Cancel && !should_cancel.is_null() {
+        *should_cancel = true;
+    }
+}
+
+/// A helpful type alias to quickly mention "DLSS".
+pub type DlssFeature = SuperSamplingFeature;
+
+/// Optimal settings for the DLSS based on the desired quality level and
+/// resolution. The ray reconstruction (DLSS-RR) has the optimal settings
+/// of the same shape, see
+/// [`SuperSamplingOptimalSettings::get_ray_reconstruction_optimal_settings`].
+#[derive(Debug)]
+pub struct SuperSamplingOptimalSettings {
+    /// The render width which the renderer must render to before
+    /// upscaling.
+    pub render_width: u32,
+    /// The render height which the renderer must render to before
+    /// upscaling.
+    pub render_height: u32,
+    /// The target width desired, to which the feature will upscale to.
+    pub target_width: u32,
+    /// The target height desired, to which the feature will upscale to.
     pub target_height: u32,
     /// The requested quality level.
     pub desired_quality_level: bindings::NVSDK_NGX_PerfQuality_Value,
-    /// TODO:
+    /// The minimum render width with the dynamic resolution.
     pub dynamic_min_render_width: u32,
-    /// TODO:
+    /// The maximum render width with the dynamic resolution.
     pub dynamic_max_render_width: u32,
-    /// TODO:
+    /// The minimum render height with the dynamic resolution.
     pub dynamic_min_render_height: u32,
-    /// TODO:
+    /// The maximum render height with the dynamic resolution.
     pub dynamic_max_render_height: u32,
 }
 
@@ -855,44 +1998,70 @@ impl SuperSamplingOptimalSettings {
     /// Returns a set of optimal settings for the desired parameter
     /// set, render dimensions and quality level.
     pub fn get_optimal_settings(
-        parameters: &FeatureParameters,
+        parameters: &ParameterMap,
+        target_width: u32,
+        target_height: u32,
+        desired_quality_level: bindings::NVSDK_NGX_PerfQuality_Value,
+    ) -> Result<Self> {
+        let optimal_settings = helpers::dlss_get_optimal_settings(
+            parameters,
+            target_width,
+            target_height,
+            desired_quality_level,
+        )?;
+        Self::from_helper(
+            optimal_settings,
+            target_width,
+            target_height,
+            desired_quality_level,
+        )
+    }
+
+    /// Returns a set of optimal settings of the ray reconstruction for
+    /// the desired parameter set, render dimensions and quality level.
+    pub fn get_ray_reconstruction_optimal_settings(
+        parameters: &ParameterMap,
+        target_width: u32,
+        target_height: u32,
+        desired_quality_level: bindings::NVSDK_NGX_PerfQuality_Value,
+    ) -> Result<Self> {
+        let optimal_settings = helpers::dlssd_get_optimal_settings(
+            parameters,
+            target_width,
+            target_height,
+            desired_quality_level,
+        )?;
+        Self::from_helper(
+            optimal_settings,
+            target_width,
+            target_height,
+            desired_quality_level,
+        )
+    }
+
+    /// Returns the optimal settings of DLSS or DLSSD from the ones the
+    /// helper read.
+    fn from_helper(
+        optimal_settings: helpers::DlssOptimalSettings,
         target_width: u32,
         target_height: u32,
         desired_quality_level: bindings::NVSDK_NGX_PerfQuality_Value,
     ) -> Result<Self> {
-        let mut settings = Self {
-            render_width: 0,
-            render_height: 0,
+        // The sharpness is deprecated, so it is ignored.
+        let settings = Self {
+            render_width: optimal_settings.render_optimal_width,
+            render_height: optimal_settings.render_optimal_height,
             target_width,
             target_height,
             desired_quality_level,
-            dynamic_min_render_width: 0,
-            dynamic_max_render_width: 0,
-            dynamic_min_render_height: 0,
-            dynamic_max_render_height: 0,
+            dynamic_min_render_width: optimal_settings.render_min_width,
+            dynamic_max_render_width: optimal_settings.render_max_width,
+            dynamic_min_render_height: optimal_settings.render_min_height,
+            dynamic_max_render_height: optimal_settings.render_max_height,
         };
-        // The sharpness is deprecated, should stay zero.
-        let mut sharpness = 0.0f32;
-        Result::from(unsafe {
-            bindings::HELPERS_NGX_DLSS_GET_OPTIMAL_SETTINGS(
-                parameters.0,
-                settings.target_width,
-                settings.target_height,
-                settings.desired_quality_level,
-                &mut settings.render_width as *mut _,
-                &mut settings.render_height as *mut _,
-                &mut settings.dynamic_max_render_width as *mut _,
-                &mut settings.dynamic_max_render_height as *mut _,
-                &mut settings.dynamic_min_render_width as *mut _,
-                &mut settings.dynamic_min_render_height as *mut _,
-                &mut sharpness as *mut _,
-            )
-        })?;
 
         if settings.render_height == 0 || settings.render_width == 0 {
-            return Err(crate::Error::Other(format!(
-                "The requested quality level isn't supported: {desired_quality_level:?}"
-            )));
+            return Err(crate::Error::QualityLevelUnsupported(desired_quality_level));
         }
 
         Ok(settings)
@@ -967,7 +2136,7 @@ impl From<SuperSamplingOptimalSettings> for SuperSamplingCreateParameters {
 //         params.Feature = value.feature_evaluation_parameters;
 //         params.pInDepth = value.depth;
 //         unsafe {
-//             bindings::HELPERS_NVSDK_NGX_Create_ImageView_Resource_VK(imageView, image, subresourceRange, format, width, height, readWrite)
+//             NVSDK_NGX_Resource_VK::from(VkImageResourceDescription { .. })
 //         }
 //         Self(params)
 //     }
@@ -1262,104 +2431,3649 @@ impl SuperSamplingFeature {
         &mut self.parameters
     }
 
+    /// See [`Feature::destroy`].
+    pub fn destroy(self) -> Result {
+        self.feature.destroy()
+    }
+
     /// Evaluates the feature.
     pub fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result {
-        Result::from(unsafe {
-            bindings::HELPERS_NGX_VULKAN_EVALUATE_DLSS_EXT(
-                command_buffer.as_pointer_mut(),
-                self.feature.handle.0,
-                self.feature.parameters.0,
-                self.parameters.get_dlss_evaluation_parameters(),
-            )
-        })
+        let evaluation_parameters = self.parameters.get_dlss_evaluation_parameters();
+        helpers::vulkan_evaluate_dlss_ext(
+            command_buffer,
+            &self.feature.handle,
+            &self.feature.parameters,
+            unsafe { &*evaluation_parameters },
+        )
     }
 }
 
-// #[derive(Debug)]
-// pub struct FeatureCommonInfo {
-//     path_list_info:,
+/// Create parameters for the ray reconstruction feature.
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct RayReconstructionCreateParameters(bindings::NVSDK_NGX_DLSSD_Create_Params);
 
-// }
+impl RayReconstructionCreateParameters {
+    /// Creates a new set of create parameters for the ray
+    /// reconstruction feature, with the unpacked roughness and the
+    /// linear depth.
+    pub fn new(
+        render_width: u32,
+        render_height: u32,
+        target_width: u32,
+        target_height: u32,
+        quality_value: Option<NVSDK_NGX_PerfQuality_Value>,
+        flags: Option<NVSDK_NGX_DLSS_Feature_Flags>,
+    ) -> Self {
+        let mut params: bindings::NVSDK_NGX_DLSSD_Create_Params = unsafe { std::mem::zeroed() };
+        params.InDenoiseMode = NVSDK_NGX_DLSS_Denoise_Mode::NVSDK_NGX_DLSS_Denoise_Mode_DLUnified;
+        params.InRoughnessMode =
+            NVSDK_NGX_DLSS_Roughness_Mode::NVSDK_NGX_DLSS_Roughness_Mode_Unpacked;
+        params.InUseHWDepth = NVSDK_NGX_DLSS_Depth_Type::NVSDK_NGX_DLSS_Depth_Type_Linear;
+        params.InWidth = render_width;
+        params.InHeight = render_height;
+        params.InTargetWidth = target_width;
+        params.InTargetHeight = target_height;
+        if let Some(quality_value) = quality_value {
+            params.InPerfQualityValue = quality_value;
+        }
+        params.InFeatureCreateFlags = flags.map(|f| f.0).unwrap_or(0);
+        Self(params)
+    }
 
-// /// Contains information common to all features, used by NGX in
-// /// determining requested feature availability.
-// #[derive(Debug, Clone)]
-// pub struct FeatureDiscoveryBuilder {
-//     /// API Struct version number.
-//     sdk_version: Option<bindings::NVSDK_NGX_Version>,
-//     /// Valid NVSDK_NGX_Feature enum corresponding to DLSS v3 Feature
-//     /// which is being queried for availability.
-//     feature_type: Option<bindings::NVSDK_NGX_Feature>,
-//     /// Unique Id provided by NVIDIA corresponding to a particular
-//     /// Application or alternatively custom Id set by Engine.
-//     application_identifier: Option<bindings::NVSDK_NGX_Application_Identifier>,
-//     /// Folder to store logs and other temporary files (write access
-//     /// required), normally this would be a location in Documents or
-//     /// ProgramData.
-//     application_data_path: Option<widestring::WideCString>,
-//     /// Contains information common to all features, presently only a
-//     /// list of all paths feature dlls can be located in, other than the
-//     /// default path - application directory.
-//     common_info: Option<FeatureCommonInfo>,
-// }
+    /// Sets how the roughness is passed: as a resource of its own, see
+    /// [`RayReconstructionEvaluationParameters::set_roughness`], or in
+    /// the alpha channel of the normals.
+    pub fn set_roughness_mode(&mut self, roughness_mode: NVSDK_NGX_DLSS_Roughness_Mode) {
+        self.0.InRoughnessMode = roughness_mode;
+    }
 
-// impl FeatureDiscoveryBuilder {
-//     /// Creates a new feature discovery builder. The created feature
-//     /// discovery builder contains blanket values.
-//     pub fn new() -> Self {
-//         Self(bindings::NVSDK_NGX_FeatureDiscoveryInfo {
-//             SDKVersion: bindings::NVSDK_NGX_Version::NVSDK_NGX_Version_API,
-//             FeatureID: bindings::NVSDK_NGX_Feature::NVSDK_NGX_Feature_Reserved_Unknown,
+    /// Sets the type of the depth passed, the linear depth or the one of
+    /// the depth buffer.
+    pub fn set_depth_type(&mut self, depth_type: NVSDK_NGX_DLSS_Depth_Type) {
+        self.0.InUseHWDepth = depth_type;
+    }
+}
 
-//         })
-//     }
+impl From<SuperSamplingOptimalSettings> for RayReconstructionCreateParameters {
+    fn from(value: SuperSamplingOptimalSettings) -> Self {
+        Self::new(
+            value.render_width,
+            value.render_height,
+            value.target_width,
+            value.target_height,
+            Some(value.desired_quality_level),
+            Some(
+                NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_AutoExposure
+                    | NVSDK_NGX_DLSS_Feature_Flags::NVSDK_NGX_DLSS_Feature_Flags_MVLowRes,
+            ),
+        )
+    }
+}
 
-//     /// Consumes the builder and obtains the requirements for the
-//     /// requested feature based on the information provided.
-//     pub fn get_requirements(self) -> Result<FeatureRequirement> {
-//         unimplemented!()
-//     }
-// }
+/// The ray reconstruction evaluation parameters: the ones of the
+/// SuperSampling and the guide buffers of the denoiser.
+#[derive(Debug, Default)]
+pub struct RayReconstructionEvaluationParameters {
+    super_sampling: SuperSamplingEvaluationParameters,
+    diffuse_albedo: Option<NVSDK_NGX_Resource_VK>,
+    specular_albedo: Option<NVSDK_NGX_Resource_VK>,
+    normals: Option<NVSDK_NGX_Resource_VK>,
+    roughness: Option<NVSDK_NGX_Resource_VK>,
+    specular_hit_distance: Option<NVSDK_NGX_Resource_VK>,
+    specular_motion_vectors: Option<NVSDK_NGX_Resource_VK>,
+    world_to_view_matrix: [f32; 16],
+    view_to_clip_matrix: [f32; 16],
+}
 
-#[cfg(test)]
-mod tests {
-    use std::collections::HashMap;
+impl RayReconstructionEvaluationParameters {
+    /// Creates a new set of evaluation parameters for the ray
+    /// reconstruction.
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    #[test]
-    fn features() {
-        // TODO: initialise vulkan and be able to do this.
-        // dbg!(super::FeatureParameters::get_capability_parameters().unwrap());
+    /// See [`SuperSamplingEvaluationParameters::set_color_input`].
+    pub fn set_color_input(&mut self, description: VkImageResourceDescription) {
+        self.super_sampling.set_color_input(description);
     }
 
-    #[test]
-    fn get_required_extensions() {
-        assert!(super::RequiredExtensions::get().is_ok());
+    /// See [`SuperSamplingEvaluationParameters::set_color_output`].
+    pub fn set_color_output(&mut self, description: VkImageResourceDescription) {
+        self.super_sampling.set_color_output(description);
     }
 
-    /// Ignored as it just needs to compile.
-    #[test]
-    #[ignore]
-    fn insert_parameter_debug_macro() -> super::Result {
-        let mut map = HashMap::new();
-        let parameters = super::FeatureParameters::get_capability_parameters().unwrap();
-        insert_parameter_debug!(
-            map,
-            parameters,
-            (crate::bindings::NVSDK_NGX_EParameter_Reserved00, i32),
-            (
-                crate::bindings::NVSDK_NGX_EParameter_SuperSampling_Available,
-                bool
-            ),
-            (
-                crate::bindings::NVSDK_NGX_EParameter_InPainting_Available,
-                bool
-            ),
-            (
-                crate::bindings::NVSDK_NGX_EParameter_ImageSuperResolution_Available,
-                bool
-            ),
-        );
+    /// See [`SuperSamplingEvaluationParameters::set_motions_vectors`].
+    pub fn set_motions_vectors(
+        &mut self,
+        description: VkImageResourceDescription,
+        scale: Option<[f32; 2]>,
+    ) {
+        self.super_sampling.set_motions_vectors(description, scale);
+    }
 
-        Ok(())
+    /// See [`SuperSamplingEvaluationParameters::set_depth_buffer`].
+    pub fn set_depth_buffer(&mut self, description: VkImageResourceDescription) {
+        self.super_sampling.set_depth_buffer(description);
+    }
+
+    /// See [`SuperSamplingEvaluationParameters::set_jitter_offsets`].
+    pub fn set_jitter_offsets(&mut self, x: f32, y: f32) {
+        self.super_sampling.set_jitter_offsets(x, y);
+    }
+
+    /// See [`SuperSamplingEvaluationParameters::set_reset`].
+    pub fn set_reset(&mut self, should_reset: bool) {
+        self.super_sampling.set_reset(should_reset);
+    }
+
+    /// See [`SuperSamplingEvaluationParameters::set_rendering_dimensions`].
+    pub fn set_rendering_dimensions(
+        &mut self,
+        rendering_offset: [u32; 2],
+        rendering_size: [u32; 2],
+    ) {
+        self.super_sampling
+            .set_rendering_dimensions(rendering_offset, rendering_size);
+    }
+
+    /// Sets the diffuse albedo.
+    pub fn set_diffuse_albedo(&mut self, description: VkImageResourceDescription) {
+        self.diffuse_albedo = Some(description.into());
+    }
+
+    /// Sets the specular albedo.
+    pub fn set_specular_albedo(&mut self, description: VkImageResourceDescription) {
+        self.specular_albedo = Some(description.into());
+    }
+
+    /// Sets the normals, with the roughness in the alpha channel if the
+    /// feature was created with the
+    /// [`NVSDK_NGX_DLSS_Roughness_Mode::NVSDK_NGX_DLSS_Roughness_Mode_Packed`].
+    pub fn set_normals(&mut self, description: VkImageResourceDescription) {
+        self.normals = Some(description.into());
+    }
+
+    /// Sets the roughness, unless it is packed with the normals.
+    pub fn set_roughness(&mut self, description: VkImageResourceDescription) {
+        self.roughness = Some(description.into());
+    }
+
+    /// Sets the hit distance of the specular rays.
+    pub fn set_specular_hit_distance(&mut self, description: VkImageResourceDescription) {
+        self.specular_hit_distance = Some(description.into());
+    }
+
+    /// Sets the motion vectors of the specular reflections.
+    pub fn set_specular_motion_vectors(&mut self, description: VkImageResourceDescription) {
+        self.specular_motion_vectors = Some(description.into());
+    }
+
+    /// Sets the row-major matrices from the world space to the view
+    /// space, and from the view space to the clip space.
+    pub fn set_matrices(&mut self, world_to_view: [f32; 16], view_to_clip: [f32; 16]) {
+        self.world_to_view_matrix = world_to_view;
+        self.view_to_clip_matrix = view_to_clip;
+    }
+
+    /// Returns the DLSSD evaluation parameters, with the resources and
+    /// the matrices as pointers into `self`, so they are only valid
+    /// until `self` is moved or changed.
+    fn get_dlssd_evaluation_parameters(&mut self) -> bindings::NVSDK_NGX_VK_DLSSD_Eval_Params {
+        let super_sampling = unsafe { *self.super_sampling.get_dlss_evaluation_parameters() };
+        let mut parameters: bindings::NVSDK_NGX_VK_DLSSD_Eval_Params =
+            unsafe { std::mem::zeroed() };
+        parameters.pInColor = super_sampling.Feature.pInColor;
+        parameters.pInOutput = super_sampling.Feature.pInOutput;
+        parameters.pInDepth = super_sampling.pInDepth;
+        parameters.pInMotionVectors = super_sampling.pInMotionVectors;
+        parameters.InJitterOffsetX = super_sampling.InJitterOffsetX;
+        parameters.InJitterOffsetY = super_sampling.InJitterOffsetY;
+        parameters.InRenderSubrectDimensions = super_sampling.InRenderSubrectDimensions;
+        parameters.InReset = super_sampling.InReset;
+        parameters.InMVScaleX = super_sampling.InMVScaleX;
+        parameters.InMVScaleY = super_sampling.InMVScaleY;
+        parameters.InColorSubrectBase = super_sampling.InColorSubrectBase;
+        parameters.InDepthSubrectBase = super_sampling.InDepthSubrectBase;
+        parameters.InMVSubrectBase = super_sampling.InMVSubrectBase;
+        parameters.InTranslucencySubrectBase = super_sampling.InTranslucencySubrectBase;
+        parameters.pInDiffuseAlbedo = resource_ptr(&mut self.diffuse_albedo);
+        parameters.pInSpecularAlbedo = resource_ptr(&mut self.specular_albedo);
+        parameters.pInNormals = resource_ptr(&mut self.normals);
+        parameters.pInRoughness = resource_ptr(&mut self.roughness);
+        parameters.pInRayTracingHitDistance = resource_ptr(&mut self.specular_hit_distance);
+        parameters.pInMotionVectorsReflections = resource_ptr(&mut self.specular_motion_vectors);
+        parameters.pInWorldToViewMatrix = self.world_to_view_matrix.as_mut_ptr();
+        parameters.pInViewToClipMatrix = self.view_to_clip_matrix.as_mut_ptr();
+        parameters
+    }
+}
+
+/// Returns a pointer to the `resource`, or a null pointer if it isn't
+/// set.
+fn resource_ptr(resource: &mut Option<NVSDK_NGX_Resource_VK>) -> *mut NVSDK_NGX_Resource_VK {
+    resource
+        .as_mut()
+        .map_or(std::ptr::null_mut(), |resource| resource)
+}
+
+/// A ray reconstruction (or "DLSS-RR") feature, the SuperSampling with
+/// the denoising of the ray traced lighting.
+#[derive(Debug)]
+pub struct RayReconstructionFeature {
+    feature: Feature,
+    parameters: RayReconstructionEvaluationParameters,
+    rendering_resolution: vk::Extent2D,
+    target_resolution: vk::Extent2D,
+}
+
+impl RayReconstructionFeature {
+    /// Creates a new ray reconstruction feature.
+    pub fn new(
+        feature: Feature,
+        rendering_resolution: vk::Extent2D,
+        target_resolution: vk::Extent2D,
+    ) -> Result<Self> {
+        if !feature.is_ray_reconstruction() {
+            return Err(crate::error::Error::Other(
+                "Attempt to create a ray reconstruction feature with another feature.".to_owned(),
+            ));
+        }
+
+        Ok(Self {
+            feature,
+            parameters: RayReconstructionEvaluationParameters::new(),
+            rendering_resolution,
+            target_resolution,
+        })
+    }
+
+    /// Returns the inner feature object.
+    pub fn get_inner(&self) -> &Feature {
+        &self.feature
+    }
+
+    /// Returns the inner feature object (mutable).
+    pub fn get_inner_mut(&mut self) -> &mut Feature {
+        &mut self.feature
+    }
+
+    /// Returns the rendering resolution (input resolution) of the
+    /// image that needs to be upscaled to the [`Self::target_resolution`].
+    pub const fn get_rendering_resolution(&self) -> vk::Extent2D {
+        self.rendering_resolution
+    }
+
+    /// Returns the target resolution (output resolution) of the
+    /// image that the original image should be upscaled to.
+    pub const fn get_target_resolution(&self) -> vk::Extent2D {
+        self.target_resolution
+    }
+
+    /// See [`ParameterMap::is_ray_reconstruction_initialised`].
+    pub fn is_initialised(&self) -> bool {
+        self.feature
+            .get_parameters()
+            .is_ray_reconstruction_initialised()
+    }
+
+    /// Returns the evaluation parameters.
+    pub fn get_evaluation_parameters_mut(&mut self) -> &mut RayReconstructionEvaluationParameters {
+        &mut self.parameters
+    }
+
+    /// See [`Feature::destroy`].
+    pub fn destroy(self) -> Result {
+        self.feature.destroy()
+    }
+
+    /// Evaluates the feature.
+    pub fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result {
+        let evaluation_parameters = self.parameters.get_dlssd_evaluation_parameters();
+        helpers::vulkan_evaluate_dlssd_ext(
+            command_buffer,
+            &self.feature.handle,
+            &self.feature.parameters,
+            &evaluation_parameters,
+        )
+    }
+}
+
+/// The key of the color of the frame without the UI, for the frame
+/// generation. The NGX SDK headers don't declare it, so the name is the
+/// one of the Streamline buffer type and is unverified.
+pub const FRAME_GENERATION_HUDLESS_COLOR: ParameterKey<*mut std::ffi::c_void> =
+    ParameterKey::from_bytes_with_nul(b"HUDLessColor\0");
+/// The key of the color of the UI, with its alpha, for the frame
+/// generation. The NGX SDK headers don't declare it, so the name is the
+/// one of the Streamline buffer type and is unverified.
+pub const FRAME_GENERATION_UI_COLOR: ParameterKey<*mut std::ffi::c_void> =
+    ParameterKey::from_bytes_with_nul(b"UIColorAndAlpha\0");
+
+/// Create parameters for the frame generation feature.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FrameGenerationCreateParameters {
+    /// The width of the backbuffer.
+    pub width: u32,
+    /// The height of the backbuffer.
+    pub height: u32,
+}
+
+impl FrameGenerationCreateParameters {
+    /// Creates a new set of create parameters for the frame generation
+    /// feature.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// Sets the create parameters in the parameter map the feature is
+    /// created with.
+    fn apply(&self, parameters: &ParameterMap) {
+        parameters.set(&keys::NVSDK_NGX_Parameter_Width, self.width);
+        parameters.set(&keys::NVSDK_NGX_Parameter_Height, self.height);
+    }
+}
+
+/// The row-major camera matrices of a frame, for the frame generation.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct FrameGenerationCameraMatrices {
+    /// The matrix from the world space to the view space
+    /// ([`bindings::NVSDK_NGX_Parameter_DLSS_WorldToViewMatrix`]).
+    pub world_to_view: [f32; 16],
+    /// The matrix from the view space to the clip space
+    /// ([`bindings::NVSDK_NGX_Parameter_DLSS_ViewToClipMatrix`]).
+    pub view_to_clip: [f32; 16],
+    /// The matrix from the clip space to the world space
+    /// ([`bindings::NVSDK_NGX_Parameter_DLSS_INV_VIEW_PROJECTION_MATRIX`]).
+    pub inverse_view_projection: [f32; 16],
+    /// The matrix from the clip space to the clip space of the previous
+    /// frame ([`bindings::NVSDK_NGX_Parameter_DLSS_CLIP_TO_PREV_CLIP_MATRIX`]).
+    pub clip_to_prev_clip: [f32; 16],
+}
+
+/// The frame generation evaluation parameters. The frames are generated
+/// between the previous frame and the current one, which the headers
+/// name `1` and `2`.
+#[derive(Debug)]
+pub struct FrameGenerationEvaluationParameters {
+    previous_backbuffer: Option<NVSDK_NGX_Resource_VK>,
+    backbuffer: Option<NVSDK_NGX_Resource_VK>,
+    previous_depth: Option<NVSDK_NGX_Resource_VK>,
+    depth: Option<NVSDK_NGX_Resource_VK>,
+    previous_motion_vectors: Option<NVSDK_NGX_Resource_VK>,
+    motion_vectors: Option<NVSDK_NGX_Resource_VK>,
+    hudless_color: Option<NVSDK_NGX_Resource_VK>,
+    ui_color: Option<NVSDK_NGX_Resource_VK>,
+    outputs: [Option<NVSDK_NGX_Resource_VK>; 3],
+    motion_vectors_scale: [f32; 2],
+    jitter_offsets: [f32; 2],
+    camera_matrices: FrameGenerationCameraMatrices,
+    reset: bool,
+    generated_frames: u32,
+}
+
+impl Default for FrameGenerationEvaluationParameters {
+    fn default() -> Self {
+        Self {
+            previous_backbuffer: None,
+            backbuffer: None,
+            previous_depth: None,
+            depth: None,
+            previous_motion_vectors: None,
+            motion_vectors: None,
+            hudless_color: None,
+            ui_color: None,
+            outputs: [None; 3],
+            motion_vectors_scale: [1.0, 1.0],
+            jitter_offsets: [0.0, 0.0],
+            camera_matrices: FrameGenerationCameraMatrices::default(),
+            reset: false,
+            generated_frames: 1,
+        }
+    }
+}
+
+impl FrameGenerationEvaluationParameters {
+    /// Creates a new set of evaluation parameters for the frame
+    /// generation, which generates one frame.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the backbuffer of the current frame
+    /// ([`bindings::NVSDK_NGX_Parameter_FI_Color2`]).
+    pub fn set_backbuffer(&mut self, description: VkImageResourceDescription) {
+        self.backbuffer = Some(description.into());
+    }
+
+    /// Sets the backbuffer of the previous frame
+    /// ([`bindings::NVSDK_NGX_Parameter_FI_Color1`]).
+    pub fn set_previous_backbuffer(&mut self, description: VkImageResourceDescription) {
+        self.previous_backbuffer = Some(description.into());
+    }
+
+    /// Sets the depth buffer of the current frame
+    /// ([`bindings::NVSDK_NGX_Parameter_FI_Depth2`]).
+    pub fn set_depth_buffer(&mut self, description: VkImageResourceDescription) {
+        self.depth = Some(description.into());
+    }
+
+    /// Sets the depth buffer of the previous frame
+    /// ([`bindings::NVSDK_NGX_Parameter_FI_Depth1`]).
+    pub fn set_previous_depth_buffer(&mut self, description: VkImageResourceDescription) {
+        self.previous_depth = Some(description.into());
+    }
+
+    /// Sets the motion vectors of the current frame
+    /// ([`bindings::NVSDK_NGX_Parameter_FI_MotionVectors2`]).
+    /// In case the `scale` argument is omitted, the `1.0f32` scaling is
+    /// used.
+    pub fn set_motions_vectors(
+        &mut self,
+        description: VkImageResourceDescription,
+        scale: Option<[f32; 2]>,
+    ) {
+        self.motion_vectors = Some(description.into());
+        self.motion_vectors_scale = scale.unwrap_or([1.0, 1.0]);
+    }
+
+    /// Sets the motion vectors of the previous frame
+    /// ([`bindings::NVSDK_NGX_Parameter_FI_MotionVectors1`]).
+    pub fn set_previous_motions_vectors(&mut self, description: VkImageResourceDescription) {
+        self.previous_motion_vectors = Some(description.into());
+    }
+
+    /// Sets the color of the frame without the UI
+    /// ([`FRAME_GENERATION_HUDLESS_COLOR`], whose name is unverified).
+    pub fn set_hudless_color(&mut self, description: VkImageResourceDescription) {
+        self.hudless_color = Some(description.into());
+    }
+
+    /// Sets the color of the UI, with its alpha
+    /// ([`FRAME_GENERATION_UI_COLOR`], whose name is unverified).
+    pub fn set_ui_color(&mut self, description: VkImageResourceDescription) {
+        self.ui_color = Some(description.into());
+    }
+
+    /// Sets the image the generated frame at `index` is written to
+    /// ([`bindings::NVSDK_NGX_Parameter_FI_Output1`] to
+    /// [`bindings::NVSDK_NGX_Parameter_FI_Output3`]). Returns an error
+    /// if the `index` isn't below 3.
+    pub fn set_output(
+        &mut self,
+        index: usize,
+        mut description: VkImageResourceDescription,
+    ) -> Result {
+        let output = self.outputs.get_mut(index).ok_or_else(|| {
+            crate::Error::Other(format!(
+                "The frame generation has no output {index}, there are 3."
+            ))
+        })?;
+        description.set_writable();
+        *output = Some(description.into());
+        Ok(())
+    }
+
+    /// Sets the jitter offsets (like TAA).
+    pub fn set_jitter_offsets(&mut self, x: f32, y: f32) {
+        self.jitter_offsets = [x, y];
+    }
+
+    /// Sets the camera matrices of the current frame.
+    pub fn set_camera_matrices(&mut self, matrices: FrameGenerationCameraMatrices) {
+        self.camera_matrices = matrices;
+    }
+
+    /// Sets/unsets the reset flag, which discards the history, for
+    /// example on camera cuts ([`bindings::NVSDK_NGX_Parameter_Reset`]).
+    pub fn set_reset(&mut self, should_reset: bool) {
+        self.reset = should_reset;
+    }
+
+    /// Sets the number of frames to generate between two rendered
+    /// frames, from 1 to 3 ([`bindings::NVSDK_NGX_Parameter_NumFrames`]).
+    /// An output must be set for each of them.
+    pub fn set_generated_frames(&mut self, generated_frames: u32) {
+        self.generated_frames = generated_frames;
+    }
+
+    /// Sets the evaluation parameters in the parameter map of the
+    /// feature, failing if the backbuffer or the output of a generated
+    /// frame isn't set. The resources and the matrices are set as
+    /// pointers into `self`, so they are only valid until `self` is
+    /// moved or changed.
+    fn apply(&mut self, parameters: &ParameterMap) -> Result {
+        if self.backbuffer.is_none() {
+            return Err(crate::Error::Other(
+                "The backbuffer of the frame generation isn't set.".to_owned(),
+            ));
+        }
+        let generated_frames = self.generated_frames as usize;
+        if !(1..=self.outputs.len()).contains(&generated_frames) {
+            return Err(crate::Error::Other(format!(
+                "The frame generation can't generate {generated_frames} frames, only 1 to 3."
+            )));
+        }
+        if let Some(index) = self.outputs[..generated_frames]
+            .iter()
+            .position(Option::is_none)
+        {
+            return Err(crate::Error::Other(format!(
+                "The output {index} of the frame generation isn't set."
+            )));
+        }
+
+        let [output1, output2, output3] = &mut self.outputs;
+        let resources = [
+            (
+                &keys::NVSDK_NGX_Parameter_FI_Color1,
+                &mut self.previous_backbuffer,
+            ),
+            (&keys::NVSDK_NGX_Parameter_FI_Color2, &mut self.backbuffer),
+            (
+                &keys::NVSDK_NGX_Parameter_FI_Depth1,
+                &mut self.previous_depth,
+            ),
+            (&keys::NVSDK_NGX_Parameter_FI_Depth2, &mut self.depth),
+            (
+                &keys::NVSDK_NGX_Parameter_FI_MotionVectors1,
+                &mut self.previous_motion_vectors,
+            ),
+            (
+                &keys::NVSDK_NGX_Parameter_FI_MotionVectors2,
+                &mut self.motion_vectors,
+            ),
+            (&FRAME_GENERATION_HUDLESS_COLOR, &mut self.hudless_color),
+            (&FRAME_GENERATION_UI_COLOR, &mut self.ui_color),
+            (&keys::NVSDK_NGX_Parameter_FI_Output1, output1),
+            (&keys::NVSDK_NGX_Parameter_FI_Output2, output2),
+            (&keys::NVSDK_NGX_Parameter_FI_Output3, output3),
+        ];
+        for (key, resource) in resources {
+            parameters.set(key, resource_ptr(resource).cast());
+        }
+        let matrices = [
+            (
+                &keys::NVSDK_NGX_Parameter_DLSS_WorldToViewMatrix,
+                &mut self.camera_matrices.world_to_view,
+            ),
+            (
+                &keys::NVSDK_NGX_Parameter_DLSS_ViewToClipMatrix,
+                &mut self.camera_matrices.view_to_clip,
+            ),
+            (
+                &keys::NVSDK_NGX_Parameter_DLSS_INV_VIEW_PROJECTION_MATRIX,
+                &mut self.camera_matrices.inverse_view_projection,
+            ),
+            (
+                &keys::NVSDK_NGX_Parameter_DLSS_CLIP_TO_PREV_CLIP_MATRIX,
+                &mut self.camera_matrices.clip_to_prev_clip,
+            ),
+        ];
+        for (key, matrix) in matrices {
+            parameters.set(key, matrix.as_mut_ptr().cast());
+        }
+        parameters.set(
+            &keys::NVSDK_NGX_Parameter_MV_Scale_X,
+            self.motion_vectors_scale[0],
+        );
+        parameters.set(
+            &keys::NVSDK_NGX_Parameter_MV_Scale_Y,
+            self.motion_vectors_scale[1],
+        );
+        parameters.set(
+            &keys::NVSDK_NGX_Parameter_Jitter_Offset_X,
+            self.jitter_offsets[0],
+        );
+        parameters.set(
+            &keys::NVSDK_NGX_Parameter_Jitter_Offset_Y,
+            self.jitter_offsets[1],
+        );
+        parameters.set(&keys::NVSDK_NGX_Parameter_Reset, i32::from(self.reset));
+        parameters.set(&keys::NVSDK_NGX_Parameter_NumFrames, self.generated_frames);
+        Ok(())
+    }
+}
+
+/// A frame generation (or "DLSS-G") feature, driven through the frame
+/// interpolation parameters of the NGX SDK headers.
+#[derive(Debug)]
+pub struct FrameGenerationFeature {
+    feature: Feature,
+    parameters: FrameGenerationEvaluationParameters,
+    resolution: vk::Extent2D,
+}
+
+impl FrameGenerationFeature {
+    /// Creates a new frame generation feature.
+    pub fn new(feature: Feature, resolution: vk::Extent2D) -> Result<Self> {
+        if !feature.is_frame_generation() {
+            return Err(crate::error::Error::Other(
+                "Attempt to create a frame generation feature with another feature.".to_owned(),
+            ));
+        }
+
+        Ok(Self {
+            feature,
+            parameters: FrameGenerationEvaluationParameters::new(),
+            resolution,
+        })
+    }
+
+    /// Returns the inner feature object.
+    pub fn get_inner(&self) -> &Feature {
+        &self.feature
+    }
+
+    /// Returns the inner feature object (mutable).
+    pub fn get_inner_mut(&mut self) -> &mut Feature {
+        &mut self.feature
+    }
+
+    /// Returns the resolution of the backbuffer and of the generated
+    /// frames.
+    pub const fn get_resolution(&self) -> vk::Extent2D {
+        self.resolution
+    }
+
+    /// Returns the evaluation parameters.
+    pub fn get_evaluation_parameters_mut(&mut self) -> &mut FrameGenerationEvaluationParameters {
+        &mut self.parameters
+    }
+
+    /// See [`Feature::destroy`].
+    pub fn destroy(self) -> Result {
+        self.feature.destroy()
+    }
+
+    /// Evaluates the feature, generating the frames between the
+    /// previous backbuffer and the current one.
+    pub fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result {
+        self.parameters.apply(self.feature.get_parameters())?;
+        self.feature.evaluate(command_buffer)
+    }
+}
+
+/// The factor the image super resolution upscales the images by.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ImageSuperResolutionScale {
+    /// Doubles the width and the height.
+    #[default]
+    X2 = 2,
+    /// Upscales the width and the height four times.
+    X4 = 4,
+    /// Upscales the width and the height eight times.
+    X8 = 8,
+}
+
+impl ImageSuperResolutionScale {
+    /// Returns the size of the image upscaled from the `input` size.
+    pub const fn upscale(self, input: vk::Extent2D) -> vk::Extent2D {
+        vk::Extent2D {
+            width: input.width * self as u32,
+            height: input.height * self as u32,
+        }
+    }
+}
+
+/// Create parameters for the image super resolution feature.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ImageSuperResolutionCreateParameters {
+    /// The width of the images to upscale.
+    pub width: u32,
+    /// The height of the images to upscale.
+    pub height: u32,
+    /// The factor to upscale the images by.
+    pub scale: ImageSuperResolutionScale,
+}
+
+impl ImageSuperResolutionCreateParameters {
+    /// Creates a new set of create parameters for the image super
+    /// resolution feature.
+    pub fn new(width: u32, height: u32, scale: ImageSuperResolutionScale) -> Self {
+        Self {
+            width,
+            height,
+            scale,
+        }
+    }
+
+    /// Sets the create parameters in the parameter map the feature is
+    /// created with.
+    fn apply(&self, parameters: &ParameterMap) {
+        parameters.set(&keys::NVSDK_NGX_Parameter_Width, self.width);
+        parameters.set(&keys::NVSDK_NGX_Parameter_Height, self.height);
+        parameters.set(&keys::NVSDK_NGX_Parameter_Scale, self.scale as u32);
+    }
+}
+
+/// The image super resolution evaluation parameters.
+#[derive(Debug, Default)]
+pub struct ImageSuperResolutionEvaluationParameters {
+    input: Option<NVSDK_NGX_Resource_VK>,
+    output: Option<NVSDK_NGX_Resource_VK>,
+}
+
+impl ImageSuperResolutionEvaluationParameters {
+    /// Creates a new set of evaluation parameters for the image super
+    /// resolution.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the image to upscale.
+    pub fn set_color_input(&mut self, description: VkImageResourceDescription) {
+        self.input = Some(description.into());
+    }
+
+    /// Sets the image the upscaled image is written to.
+    pub fn set_color_output(&mut self, mut description: VkImageResourceDescription) {
+        description.set_writable();
+        self.output = Some(description.into());
+    }
+
+    /// Sets the evaluation parameters in the parameter map of the
+    /// feature. The resources are set as pointers into `self`, so they
+    /// are only valid until `self` is moved or changed.
+    fn apply(&mut self, parameters: &ParameterMap) {
+        parameters.set(
+            &keys::NVSDK_NGX_Parameter_Color,
+            resource_ptr(&mut self.input).cast(),
+        );
+        parameters.set(
+            &keys::NVSDK_NGX_Parameter_Output,
+            resource_ptr(&mut self.output).cast(),
+        );
+    }
+}
+
+/// An image super resolution (or "ISR") feature, which upscales still
+/// images, like textures or screenshots.
+#[derive(Debug)]
+pub struct ImageSuperResolutionFeature {
+    feature: Feature,
+    parameters: ImageSuperResolutionEvaluationParameters,
+    input_resolution: vk::Extent2D,
+    scale: ImageSuperResolutionScale,
+}
+
+impl ImageSuperResolutionFeature {
+    /// Creates a new image super resolution feature.
+    pub fn new(
+        feature: Feature,
+        input_resolution: vk::Extent2D,
+        scale: ImageSuperResolutionScale,
+    ) -> Result<Self> {
+        if !feature.is_image_super_resolution() {
+            return Err(crate::error::Error::Other(
+                "Attempt to create an image super resolution feature with another feature."
+                    .to_owned(),
+            ));
+        }
+
+        Ok(Self {
+            feature,
+            parameters: ImageSuperResolutionEvaluationParameters::new(),
+            input_resolution,
+            scale,
+        })
+    }
+
+    /// Returns the inner feature object.
+    pub fn get_inner(&self) -> &Feature {
+        &self.feature
+    }
+
+    /// Returns the inner feature object (mutable).
+    pub fn get_inner_mut(&mut self) -> &mut Feature {
+        &mut self.feature
+    }
+
+    /// Returns the resolution of the images to upscale.
+    pub const fn get_input_resolution(&self) -> vk::Extent2D {
+        self.input_resolution
+    }
+
+    /// Returns the resolution of the upscaled images.
+    pub const fn get_output_resolution(&self) -> vk::Extent2D {
+        self.scale.upscale(self.input_resolution)
+    }
+
+    /// Returns the factor the images are upscaled by.
+    pub const fn get_scale(&self) -> ImageSuperResolutionScale {
+        self.scale
+    }
+
+    /// Returns the evaluation parameters.
+    pub fn get_evaluation_parameters_mut(
+        &mut self,
+    ) -> &mut ImageSuperResolutionEvaluationParameters {
+        &mut self.parameters
+    }
+
+    /// See [`Feature::destroy`].
+    pub fn destroy(self) -> Result {
+        self.feature.destroy()
+    }
+
+    /// Evaluates the feature.
+    pub fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result {
+        self.parameters.apply(self.feature.get_parameters());
+        self.feature.evaluate(command_buffer)
+    }
+
+    /// Evaluates the feature, reporting the progress to the `progress`
+    /// callback, which may cancel the evaluation, see
+    /// [`Feature::evaluate_with_progress`].
+    pub fn evaluate_with_progress<F>(
+        &mut self,
+        command_buffer: vk::CommandBuffer,
+        progress: F,
+    ) -> Result
+    where
+        F: FnMut(f32) -> EvaluationControl,
+    {
+        self.parameters.apply(self.feature.get_parameters());
+        self.feature
+            .evaluate_with_progress(command_buffer, progress)
+    }
+
+    /// Upscales the `input` image into the `output` one, which must be
+    /// of the [`Self::get_input_resolution`] and the
+    /// [`Self::get_output_resolution`].
+    pub fn upscale(
+        &mut self,
+        command_buffer: vk::CommandBuffer,
+        input: VkImageResourceDescription,
+        output: VkImageResourceDescription,
+    ) -> Result {
+        let extent = |description: &VkImageResourceDescription| vk::Extent2D {
+            width: description.width,
+            height: description.height,
+        };
+        if extent(&input) != self.input_resolution
+            || extent(&output) != self.get_output_resolution()
+        {
+            return Err(crate::Error::Other(format!(
+                "Can't upscale a {}x{} image into a {}x{} one, the feature upscales {}x{} images by {}.",
+                input.width,
+                input.height,
+                output.width,
+                output.height,
+                self.input_resolution.width,
+                self.input_resolution.height,
+                self.scale as u32,
+            )));
+        }
+        self.parameters.set_color_input(input);
+        self.parameters.set_color_output(output);
+        self.evaluate(command_buffer)
+    }
+}
+
+/// The key of the mask of the regions to fill, for the in-painting. The
+/// NGX SDK headers don't declare it, so the name is unverified.
+pub const IN_PAINTING_MASK: ParameterKey<*mut std::ffi::c_void> =
+    ParameterKey::from_bytes_with_nul(b"Mask\0");
+
+/// Create parameters for the in-painting feature.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InPaintingCreateParameters {
+    /// The width of the images.
+    pub width: u32,
+    /// The height of the images.
+    pub height: u32,
+}
+
+impl InPaintingCreateParameters {
+    /// Creates a new set of create parameters for the in-painting
+    /// feature.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+
+    /// Sets the create parameters in the parameter map the feature is
+    /// created with.
+    fn apply(&self, parameters: &ParameterMap) {
+        parameters.set(&keys::NVSDK_NGX_Parameter_Width, self.width);
+        parameters.set(&keys::NVSDK_NGX_Parameter_Height, self.height);
+    }
+}
+
+/// The in-painting evaluation parameters.
+#[derive(Debug, Default)]
+pub struct InPaintingEvaluationParameters {
+    color: Option<NVSDK_NGX_Resource_VK>,
+    mask: Option<NVSDK_NGX_Resource_VK>,
+    output: Option<NVSDK_NGX_Resource_VK>,
+}
+
+impl InPaintingEvaluationParameters {
+    /// Creates a new set of evaluation parameters for the in-painting.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the image to fill the masked regions of.
+    pub fn set_color_input(&mut self, description: VkImageResourceDescription) {
+        self.color = Some(description.into());
+    }
+
+    /// Sets the mask of the regions to fill ([`IN_PAINTING_MASK`], whose
+    /// name is unverified).
+    pub fn set_mask(&mut self, description: VkImageResourceDescription) {
+        self.mask = Some(description.into());
+    }
+
+    /// Sets the image the filled image is written to.
+    pub fn set_color_output(&mut self, mut description: VkImageResourceDescription) {
+        description.set_writable();
+        self.output = Some(description.into());
+    }
+
+    /// Sets the evaluation parameters in the parameter map of the
+    /// feature, failing if the color, the mask or the output isn't set.
+    /// The resources are set as pointers into `self`, so they are only
+    /// valid until `self` is moved or changed.
+    fn apply(&mut self, parameters: &ParameterMap) -> Result {
+        let resources = [
+            (&keys::NVSDK_NGX_Parameter_Color, &mut self.color, "color"),
+            (&IN_PAINTING_MASK, &mut self.mask, "mask"),
+            (
+                &keys::NVSDK_NGX_Parameter_Output,
+                &mut self.output,
+                "output",
+            ),
+        ];
+        if let Some((_, _, name)) = resources.iter().find(|(_, resource, _)| resource.is_none()) {
+            return Err(crate::Error::Other(format!(
+                "The {name} of the in-painting isn't set."
+            )));
+        }
+        for (key, resource, _) in resources {
+            parameters.set(key, resource_ptr(resource).cast());
+        }
+        Ok(())
+    }
+}
+
+/// An in-painting feature, which fills the masked regions of images.
+#[derive(Debug)]
+pub struct InPaintingFeature {
+    feature: Feature,
+    parameters: InPaintingEvaluationParameters,
+    resolution: vk::Extent2D,
+}
+
+impl InPaintingFeature {
+    /// Creates a new in-painting feature.
+    pub fn new(feature: Feature, resolution: vk::Extent2D) -> Result<Self> {
+        if !feature.is_in_painting() {
+            return Err(crate::error::Error::Other(
+                "Attempt to create an in-painting feature with another feature.".to_owned(),
+            ));
+        }
+
+        Ok(Self {
+            feature,
+            parameters: InPaintingEvaluationParameters::new(),
+            resolution,
+        })
+    }
+
+    /// Returns the inner feature object.
+    pub fn get_inner(&self) -> &Feature {
+        &self.feature
+    }
+
+    /// Returns the inner feature object (mutable).
+    pub fn get_inner_mut(&mut self) -> &mut Feature {
+        &mut self.feature
+    }
+
+    /// Returns the resolution of the images.
+    pub const fn get_resolution(&self) -> vk::Extent2D {
+        self.resolution
+    }
+
+    /// Returns the evaluation parameters.
+    pub fn get_evaluation_parameters_mut(&mut self) -> &mut InPaintingEvaluationParameters {
+        &mut self.parameters
+    }
+
+    /// See [`Feature::destroy`].
+    pub fn destroy(self) -> Result {
+        self.feature.destroy()
+    }
+
+    /// Evaluates the feature.
+    pub fn evaluate(&mut self, command_buffer: vk::CommandBuffer) -> Result {
+        self.parameters.apply(self.feature.get_parameters())?;
+        self.feature.evaluate(command_buffer)
+    }
+
+    /// Evaluates the feature, reporting the progress to the `progress`
+    /// callback, which may cancel the evaluation, see
+    /// [`Feature::evaluate_with_progress`].
+    pub fn evaluate_with_progress<F>(
+        &mut self,
+        command_buffer: vk::CommandBuffer,
+        progress: F,
+    ) -> Result
+    where
+        F: FnMut(f32) -> EvaluationControl,
+    {
+        self.parameters.apply(self.feature.get_parameters())?;
+        self.feature
+            .evaluate_with_progress(command_buffer, progress)
+    }
+}
+
+/// Contains information common to all features, presently only a list
+/// of the paths the feature libraries (snippets) can be located in,
+/// other than the application directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureCommonInfo {
+    /// The directories to look for the feature libraries in.
+    pub paths: Vec<std::path::PathBuf>,
+}
+
+impl FeatureCommonInfo {
+    /// Creates the common info with the paths to look for the feature
+    /// libraries in.
+    pub fn new(paths: Vec<std::path::PathBuf>) -> Self {
+        Self { paths }
+    }
+
+    /// Returns the common info with the directory of the snippets of
+    /// the [`DlssLibraryType::selected`] at build time, as resolved by
+    /// [`DlssLibraryType::snippet_path`] and used by [`System::new`].
+    pub fn selected() -> Self {
+        Self::new(
+            DlssLibraryType::selected()
+                .snippet_path()
+                .into_iter()
+                .collect(),
+        )
+    }
+}
+
+/// The [`FeatureCommonInfo`] converted for NGX, which must outlive the
+/// NGX call it is passed to.
+struct RawFeatureCommonInfo {
+    _paths: Vec<widestring::WideCString>,
+    _path_pointers: Vec<*const bindings::wchar_t>,
+    info: bindings::NVSDK_NGX_FeatureCommonInfo,
+}
+
+impl RawFeatureCommonInfo {
+    fn new(common_info: &FeatureCommonInfo) -> Result<Self> {
+        let paths = common_info
+            .paths
+            .iter()
+            .map(|path| {
+                widestring::WideCString::from_os_str(path).map_err(|e| {
+                    crate::Error::Other(format!("Invalid snippet path {}: {e}", path.display()))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let path_pointers: Vec<*const bindings::wchar_t> =
+            paths.iter().map(|path| path.as_ptr().cast()).collect();
+        let mut info: bindings::NVSDK_NGX_FeatureCommonInfo = unsafe { std::mem::zeroed() };
+        // The pointers point into the heap buffers, which stay in place
+        // when the vectors are moved.
+        info.PathListInfo.Path = path_pointers.as_ptr();
+        info.PathListInfo.Length = path_pointers.len() as u32;
+        Ok(Self {
+            _paths: paths,
+            _path_pointers: path_pointers,
+            info,
+        })
+    }
+}
+
+/// Identifies the application to NGX.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApplicationIdentifier {
+    /// The application id provided by NVIDIA.
+    ApplicationId(u64),
+    /// A custom project id of an application made with a custom engine,
+    /// like the one [`System::new`] uses.
+    ProjectId {
+        /// The project id.
+        project_id: uuid::Uuid,
+        /// The version of the engine.
+        engine_version: String,
+    },
+}
+
+/// A reason for a feature to be unsupported, see
+/// [`FeatureRequirement::unsupported_reasons`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FeatureUnsupportedReason {
+    /// The feature doesn't provide a way to check whether it is
+    /// supported.
+    CheckNotPresent,
+    /// The driver is too old for the feature.
+    DriverVersionUnsupported,
+    /// The GPU doesn't support the feature.
+    AdapterUnsupported,
+    /// The operating system is older than
+    /// [`FeatureRequirement::min_os_version`].
+    OsVersionBelowMinimumSupported,
+    /// The feature isn't implemented for the platform.
+    NotImplemented,
+    /// A flag unknown to the NGX headers the crate was built with.
+    Unknown(u32),
+}
+
+/// The requirements of a feature and whether a physical device meets
+/// them, see [`FeatureDiscoveryBuilder::get_requirements`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureRequirement {
+    /// The raw support flags, which are empty if the feature is
+    /// supported.
+    pub support: bindings::NVSDK_NGX_Feature_Support_Result,
+    /// The minimum GPU architecture required, as the NVAPI
+    /// architecture id (`NV_GPU_ARCHITECTURE_ID`).
+    pub min_hw_architecture: u32,
+    /// The minimum operating system version required, if reported.
+    pub min_os_version: String,
+}
+
+impl FeatureRequirement {
+    /// Returns `true` if the feature is supported by the physical
+    /// device.
+    pub fn is_supported(&self) -> bool {
+        self.support
+            == bindings::NVSDK_NGX_Feature_Support_Result::NVSDK_NGX_FeatureSupportResult_Supported
+    }
+
+    /// Returns the reasons the feature isn't supported, which are empty
+    /// if it is.
+    pub fn unsupported_reasons(&self) -> Vec<FeatureUnsupportedReason> {
+        use bindings::NVSDK_NGX_Feature_Support_Result as Support;
+
+        let known = [
+            (
+                Support::NVSDK_NGX_FeatureSupportResult_CheckNotPresent,
+                FeatureUnsupportedReason::CheckNotPresent,
+            ),
+            (
+                Support::NVSDK_NGX_FeatureSupportResult_DriverVersionUnsupported,
+                FeatureUnsupportedReason::DriverVersionUnsupported,
+            ),
+            (
+                Support::NVSDK_NGX_FeatureSupportResult_AdapterUnsupported,
+                FeatureUnsupportedReason::AdapterUnsupported,
+            ),
+            (
+                Support::NVSDK_NGX_FeatureSupportResult_OSVersionBelowMinimumSupported,
+                FeatureUnsupportedReason::OsVersionBelowMinimumSupported,
+            ),
+            (
+                Support::NVSDK_NGX_FeatureSupportResult_NotImplemented,
+                FeatureUnsupportedReason::NotImplemented,
+            ),
+        ];
+        let mut remaining = self.support.0;
+        let mut reasons = Vec::new();
+        for (flag, reason) in known {
+            if remaining & flag.0 != 0 {
+                remaining &= !flag.0;
+                reasons.push(reason);
+            }
+        }
+        if remaining != 0 {
+            reasons.push(FeatureUnsupportedReason::Unknown(remaining));
+        }
+        reasons
+    }
+}
+
+impl From<bindings::NVSDK_NGX_FeatureRequirement> for FeatureRequirement {
+    fn from(value: bindings::NVSDK_NGX_FeatureRequirement) -> Self {
+        let min_os_version: Vec<u8> = value
+            .MinOSVersion
+            .iter()
+            .take_while(|c| **c != 0)
+            .map(|c| *c as u8)
+            .collect();
+        Self {
+            support: value.FeatureSupported,
+            min_hw_architecture: value.MinHWArchitecture,
+            min_os_version: String::from_utf8_lossy(&min_os_version).into_owned(),
+        }
+    }
+}
+
+/// Describes a feature to discover the requirements of, before the
+/// logical device and the [`System`] are created.
+#[derive(Debug, Clone)]
+pub struct FeatureDiscoveryBuilder {
+    /// The feature which is being queried for availability.
+    feature_type: bindings::NVSDK_NGX_Feature,
+    /// Unique Id provided by NVIDIA corresponding to a particular
+    /// Application or alternatively custom Id set by Engine.
+    application_identifier: Option<ApplicationIdentifier>,
+    /// Folder to store logs and other temporary files (write access
+    /// required), normally this would be a location in Documents or
+    /// ProgramData.
+    application_data_path: Option<std::path::PathBuf>,
+    /// Contains information common to all features, presently only a
+    /// list of all paths feature dlls can be located in, other than the
+    /// default path - application directory.
+    common_info: FeatureCommonInfo,
+}
+
+impl FeatureDiscoveryBuilder {
+    /// Creates a new feature discovery builder for the `feature_type`.
+    /// The application identifier and the data path must be provided,
+    /// while the common info defaults to [`FeatureCommonInfo::selected`].
+    pub fn new(feature_type: bindings::NVSDK_NGX_Feature) -> Self {
+        Self {
+            feature_type,
+            application_identifier: None,
+            application_data_path: None,
+            common_info: FeatureCommonInfo::selected(),
+        }
+    }
+
+    /// Sets the application identifier.
+    pub fn application_identifier(mut self, identifier: ApplicationIdentifier) -> Self {
+        self.application_identifier = Some(identifier);
+        self
+    }
+
+    /// Sets the folder to store the logs and other temporary files in.
+    pub fn application_data_path(mut self, path: &std::path::Path) -> Self {
+        self.application_data_path = Some(path.to_owned());
+        self
+    }
+
+    /// Sets the information common to all features.
+    pub fn common_info(mut self, common_info: FeatureCommonInfo) -> Self {
+        self.common_info = common_info;
+        self
+    }
+
+    /// Obtains the requirements for the requested feature on the
+    /// `physical_device`, which don't require NGX to be initialised.
+    pub fn get_requirements(
+        &self,
+        instance: vk::Instance,
+        physical_device: vk::PhysicalDevice,
+    ) -> Result<FeatureRequirement> {
+        let library = Library::get()?;
+        let mut requirement: bindings::NVSDK_NGX_FeatureRequirement = unsafe { std::mem::zeroed() };
+        self.with_discovery_info(|discovery_info| {
+            Result::from(unsafe {
+                (library.NVSDK_NGX_VULKAN_GetFeatureRequirements)(
+                    instance.as_pointer_mut(),
+                    physical_device.as_pointer_mut(),
+                    discovery_info,
+                    &mut requirement,
+                )
+            })
+            .context(|| {
+                ErrorContext::new("NVSDK_NGX_VULKAN_GetFeatureRequirements")
+                    .feature(self.feature_type)
+            })
+        })
+        .map(|_| requirement.into())
+    }
+
+    /// Returns the Vulkan instance extensions the requested feature
+    /// needs, so that the instance can be created with them.
+    pub fn get_instance_extension_requirements(&self) -> Result<Vec<vk::ExtensionProperties>> {
+        let library = Library::get()?;
+        let mut count = 0u32;
+        let mut properties: *mut bindings::VkExtensionProperties = std::ptr::null_mut();
+        self.with_discovery_info(|discovery_info| {
+            Result::from(unsafe {
+                (library.NVSDK_NGX_VULKAN_GetFeatureInstanceExtensionRequirements)(
+                    discovery_info,
+                    &mut count,
+                    &mut properties,
+                )
+            })
+            .context(|| {
+                ErrorContext::new("NVSDK_NGX_VULKAN_GetFeatureInstanceExtensionRequirements")
+                    .feature(self.feature_type)
+            })
+        })?;
+        Ok(unsafe { convert_extension_properties(properties, count) })
+    }
+
+    /// Returns the Vulkan device extensions the requested feature needs
+    /// on the `physical_device`, so that the logical device can be
+    /// created with them.
+    pub fn get_device_extension_requirements(
+        &self,
+        instance: vk::Instance,
+        physical_device: vk::PhysicalDevice,
+    ) -> Result<Vec<vk::ExtensionProperties>> {
+        let library = Library::get()?;
+        let mut count = 0u32;
+        let mut properties: *mut bindings::VkExtensionProperties = std::ptr::null_mut();
+        self.with_discovery_info(|discovery_info| {
+            Result::from(unsafe {
+                (library.NVSDK_NGX_VULKAN_GetFeatureDeviceExtensionRequirements)(
+                    instance.as_pointer_mut(),
+                    physical_device.as_pointer_mut(),
+                    discovery_info,
+                    &mut count,
+                    &mut properties,
+                )
+            })
+            .context(|| {
+                ErrorContext::new("NVSDK_NGX_VULKAN_GetFeatureDeviceExtensionRequirements")
+                    .feature(self.feature_type)
+            })
+        })?;
+        Ok(unsafe { convert_extension_properties(properties, count) })
+    }
+
+    /// Calls `f` with the discovery info for NGX, which is only valid
+    /// during the call.
+    fn with_discovery_info<T>(
+        &self,
+        f: impl FnOnce(&bindings::NVSDK_NGX_FeatureDiscoveryInfo) -> Result<T>,
+    ) -> Result<T> {
+        let identifier = self.application_identifier.as_ref().ok_or_else(|| {
+            crate::Error::Other("The feature discovery requires an application identifier.".into())
+        })?;
+        let application_data_path = self.application_data_path.as_ref().ok_or_else(|| {
+            crate::Error::Other("The feature discovery requires an application data path.".into())
+        })?;
+        let application_data_path = widestring::WideCString::from_os_str(application_data_path)
+            .map_err(|e| {
+                crate::Error::Other(format!(
+                    "Invalid application data path {}: {e}",
+                    application_data_path.display()
+                ))
+            })?;
+        let common_info = RawFeatureCommonInfo::new(&self.common_info)?;
+
+        let mut raw_identifier: bindings::NVSDK_NGX_Application_Identifier =
+            unsafe { std::mem::zeroed() };
+        let project_id;
+        let engine_version;
+        match identifier {
+            ApplicationIdentifier::ApplicationId(id) => {
+                raw_identifier.IdentifierType = bindings::NVSDK_NGX_Application_Identifier_Type::NVSDK_NGX_Application_Identifier_Type_Application_Id;
+                raw_identifier.v.ApplicationId = *id;
+            }
+            ApplicationIdentifier::ProjectId {
+                project_id: id,
+                engine_version: version,
+            } => {
+                project_id = std::ffi::CString::new(id.to_string()).unwrap();
+                engine_version = std::ffi::CString::new(version.as_str())
+                    .map_err(|e| crate::Error::Other(format!("Invalid engine version: {e}")))?;
+                raw_identifier.IdentifierType = bindings::NVSDK_NGX_Application_Identifier_Type::NVSDK_NGX_Application_Identifier_Type_Project_Id;
+                raw_identifier.v.ProjectDesc = bindings::NVSDK_NGX_ProjectIdDescription {
+                    ProjectId: project_id.as_ptr(),
+                    EngineType: bindings::NVSDK_NGX_EngineType::NVSDK_NGX_ENGINE_TYPE_CUSTOM,
+                    EngineVersion: engine_version.as_ptr(),
+                };
+            }
+        }
+
+        f(&bindings::NVSDK_NGX_FeatureDiscoveryInfo {
+            SDKVersion: bindings::NVSDK_NGX_Version::NVSDK_NGX_Version_API,
+            FeatureID: self.feature_type,
+            Identifier: raw_identifier,
+            ApplicationDataPath: application_data_path.as_ptr().cast(),
+            FeatureInfo: &common_info.info,
+        })
+    }
+}
+
+/// The PCI vendor id of NVIDIA.
+pub const NVIDIA_VENDOR_ID: u32 = 0x10DE;
+
+/// How a physical device supports one of the features requested from a
+/// [`PhysicalDeviceSelector`].
+#[derive(Debug, Clone)]
+pub struct FeatureSupport {
+    /// The feature.
+    pub feature: bindings::NVSDK_NGX_Feature,
+    /// The requirements of the feature, or the error querying them.
+    pub requirement: Result<FeatureRequirement>,
+    /// The device extensions the feature requires which the physical
+    /// device doesn't expose.
+    pub missing_extensions: Vec<std::ffi::CString>,
+}
+
+impl FeatureSupport {
+    /// Returns `true` if the feature can be used on the physical device.
+    pub fn is_supported(&self) -> bool {
+        matches!(&self.requirement, Ok(r) if r.is_supported()) && self.missing_extensions.is_empty()
+    }
+}
+
+/// A physical device ranked by [`PhysicalDeviceSelector::rank`].
+#[derive(Debug, Clone)]
+pub struct PhysicalDeviceCandidate {
+    /// The physical device.
+    pub physical_device: vk::PhysicalDevice,
+    /// The name of the device.
+    pub name: String,
+    /// The type of the device.
+    pub device_type: vk::PhysicalDeviceType,
+    /// The raw driver version, as encoded by the NVIDIA driver.
+    pub driver_version: u32,
+    /// The support of each of the requested features, in the order
+    /// they were requested.
+    pub features: Vec<FeatureSupport>,
+}
+
+impl PhysicalDeviceCandidate {
+    /// Returns the major and minor NVIDIA driver version.
+    pub fn get_driver_version(&self) -> (u32, u32) {
+        decode_nvidia_driver_version(self.driver_version)
+    }
+
+    /// Returns `true` if all the requested features can be used on the
+    /// physical device.
+    pub fn is_supported(&self) -> bool {
+        self.features.iter().all(FeatureSupport::is_supported)
+    }
+
+    /// Returns the number of the requested features which can be used
+    /// on the physical device.
+    pub fn supported_feature_count(&self) -> usize {
+        self.features.iter().filter(|f| f.is_supported()).count()
+    }
+}
+
+/// Decodes the version of the NVIDIA driver into the major and minor
+/// version.
+fn decode_nvidia_driver_version(version: u32) -> (u32, u32) {
+    ((version >> 22) & 0x3FF, (version >> 14) & 0xFF)
+}
+
+/// Walks the physical devices of an instance and ranks the NVIDIA ones
+/// by how well they support a set of features, so that a device can be
+/// picked before the logical device and the [`System`] are created.
+#[derive(Debug, Clone, Default)]
+pub struct PhysicalDeviceSelector {
+    features: Vec<FeatureDiscoveryBuilder>,
+    min_driver_version: Option<(u32, u32)>,
+}
+
+impl PhysicalDeviceSelector {
+    /// Creates a selector without any features requested.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests the feature described by the `discovery`.
+    pub fn feature(mut self, discovery: FeatureDiscoveryBuilder) -> Self {
+        self.features.push(discovery);
+        self
+    }
+
+    /// Leaves out the devices with a driver older than the `major`
+    /// and `minor` version.
+    pub fn min_driver_version(mut self, major: u32, minor: u32) -> Self {
+        self.min_driver_version = Some((major, minor));
+        self
+    }
+
+    /// Returns the NVIDIA physical devices with a driver recent enough,
+    /// the ones supporting all the requested features first, then the
+    /// ones supporting the most of them. The discrete GPUs come before
+    /// the other ones supporting as many features.
+    pub fn rank(&self, instance: &ash::Instance) -> Result<Vec<PhysicalDeviceCandidate>> {
+        let physical_devices = unsafe { instance.enumerate_physical_devices() }.map_err(|e| {
+            crate::Error::Other(format!("Couldn't enumerate the physical devices: {e}"))
+        })?;
+
+        let mut candidates = Vec::new();
+        for physical_device in physical_devices {
+            let properties = unsafe { instance.get_physical_device_properties(physical_device) };
+            if properties.vendor_id != NVIDIA_VENDOR_ID {
+                continue;
+            }
+            let driver_version = decode_nvidia_driver_version(properties.driver_version);
+            if matches!(self.min_driver_version, Some(min) if driver_version < min) {
+                continue;
+            }
+
+            let features = self
+                .features
+                .iter()
+                .map(|discovery| Self::get_feature_support(discovery, instance, physical_device))
+                .collect();
+            let name = unsafe { std::ffi::CStr::from_ptr(properties.device_name.as_ptr()) };
+            candidates.push(PhysicalDeviceCandidate {
+                physical_device,
+                name: name.to_string_lossy().into_owned(),
+                device_type: properties.device_type,
+                driver_version: properties.driver_version,
+                features,
+            });
+        }
+
+        // The sort is stable, so the devices are in the order of the
+        // enumeration otherwise.
+        candidates.sort_by_key(|c| {
+            (
+                !c.is_supported(),
+                std::cmp::Reverse(c.supported_feature_count()),
+                c.device_type != vk::PhysicalDeviceType::DISCRETE_GPU,
+            )
+        });
+        Ok(candidates)
+    }
+
+    /// Returns the best of the [`Self::rank`]ed devices, if it supports
+    /// all the requested features.
+    pub fn select(&self, instance: &ash::Instance) -> Result<Option<PhysicalDeviceCandidate>> {
+        Ok(self
+            .rank(instance)?
+            .into_iter()
+            .next()
+            .filter(PhysicalDeviceCandidate::is_supported))
+    }
+
+    fn get_feature_support(
+        discovery: &FeatureDiscoveryBuilder,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+    ) -> FeatureSupport {
+        let mut missing_extensions = Vec::new();
+        let requirement = discovery
+            .get_requirements(instance.handle(), physical_device)
+            .and_then(|requirement| {
+                let required = discovery
+                    .get_device_extension_requirements(instance.handle(), physical_device)?;
+                let merged = MergedExtensions::device(
+                    instance,
+                    physical_device,
+                    &[],
+                    &extension_names(&required),
+                )?;
+                missing_extensions = merged.get_missing().to_vec();
+                Ok(requirement)
+            });
+        FeatureSupport {
+            feature: discovery.feature_type,
+            requirement,
+            missing_extensions,
+        }
+    }
+}
+
+/// Copies the extension properties NGX returned, which it keeps owning.
+unsafe fn convert_extension_properties(
+    properties: *const bindings::VkExtensionProperties,
+    count: u32,
+) -> Vec<vk::ExtensionProperties> {
+    if properties.is_null() {
+        return Vec::new();
+    }
+    std::slice::from_raw_parts(properties, count as usize)
+        .iter()
+        .map(|p| vk::ExtensionProperties {
+            extension_name: p.extensionName,
+            spec_version: p.specVersion,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn features() {
+        // TODO: initialise vulkan and be able to do this.
+        // dbg!(super::FeatureParameters::get_capability_parameters(&system).unwrap());
+    }
+
+    #[test]
+    fn get_required_extensions() {
+        assert!(super::RequiredExtensions::get().is_ok());
+    }
+
+    #[test]
+    fn snippet_path() {
+        use super::DlssLibraryType;
+        use std::path::PathBuf;
+
+        let sdk_path = DlssLibraryType::Release.sdk_snippet_path();
+        let executable_directory = std::env::temp_dir().join("nvngx-snippet-path");
+        let executable = executable_directory.join("application");
+        assert_eq!(
+            DlssLibraryType::Release.resolve_snippet_path(None, Some(executable.clone())),
+            sdk_path
+        );
+        assert_eq!(
+            DlssLibraryType::Release.resolve_snippet_path(Some("".into()), None),
+            sdk_path
+        );
+
+        std::fs::create_dir_all(executable_directory.join("rel")).unwrap();
+        assert_eq!(
+            DlssLibraryType::Release.resolve_snippet_path(None, Some(executable.clone())),
+            Some(executable_directory.join("rel"))
+        );
+        assert_eq!(
+            DlssLibraryType::Development.resolve_snippet_path(None, Some(executable.clone())),
+            DlssLibraryType::Development.sdk_snippet_path()
+        );
+        assert_eq!(
+            DlssLibraryType::Release
+                .resolve_snippet_path(Some("/opt/snippets".into()), Some(executable)),
+            Some(PathBuf::from("/opt/snippets"))
+        );
+        std::fs::remove_dir_all(executable_directory).unwrap();
+    }
+
+    #[test]
+    fn feature_unsupported_reasons() {
+        use super::{FeatureRequirement, FeatureUnsupportedReason};
+        use crate::bindings::NVSDK_NGX_Feature_Support_Result as Support;
+
+        let mut requirement = FeatureRequirement {
+            support: Support::NVSDK_NGX_FeatureSupportResult_Supported,
+            min_hw_architecture: 0,
+            min_os_version: String::new(),
+        };
+        assert!(requirement.is_supported());
+        assert!(requirement.unsupported_reasons().is_empty());
+
+        requirement.support = Support::NVSDK_NGX_FeatureSupportResult_AdapterUnsupported
+            | Support::NVSDK_NGX_FeatureSupportResult_DriverVersionUnsupported
+            | Support(1 << 20);
+        assert!(!requirement.is_supported());
+        assert_eq!(
+            requirement.unsupported_reasons(),
+            vec![
+                FeatureUnsupportedReason::DriverVersionUnsupported,
+                FeatureUnsupportedReason::AdapterUnsupported,
+                FeatureUnsupportedReason::Unknown(1 << 20),
+            ]
+        );
+    }
+
+    fn extension(name: &str) -> ash::vk::ExtensionProperties {
+        let mut properties = ash::vk::ExtensionProperties::default();
+        for (destination, source) in properties.extension_name.iter_mut().zip(name.bytes()) {
+            *destination = source as std::ffi::c_char;
+        }
+        properties
+    }
+
+    #[test]
+    fn merged_extensions() {
+        use std::ffi::{CStr, CString};
+
+        let swapchain = CString::new("VK_KHR_swapchain").unwrap();
+        let binary_import = CString::new("VK_NVX_binary_import").unwrap();
+        let image_view_handle = CString::new("VK_NVX_image_view_handle").unwrap();
+        let merged = super::MergedExtensions::new(
+            &[&swapchain, &swapchain, &binary_import],
+            &[
+                binary_import.clone(),
+                image_view_handle.clone(),
+                image_view_handle.clone(),
+            ],
+            &[
+                extension("VK_KHR_swapchain"),
+                extension("VK_NVX_binary_import"),
+            ],
+        );
+        assert_eq!(merged.get_names(), &[swapchain, binary_import]);
+        assert_eq!(merged.get_missing(), &[image_view_handle]);
+        assert!(!merged.is_complete());
+        let names: Vec<_> = merged
+            .as_ptrs()
+            .iter()
+            .map(|p| unsafe { CStr::from_ptr(*p) }.to_owned())
+            .collect();
+        assert_eq!(names, merged.get_names());
+    }
+
+    #[test]
+    fn merged_instance_extensions() {
+        use ash::vk;
+
+        unsafe extern "system" fn enumerate_instance_extension_properties(
+            _layer_name: *const std::ffi::c_char,
+            count: *mut u32,
+            properties: *mut vk::ExtensionProperties,
+        ) -> vk::Result {
+            if properties.is_null() {
+                *count = 1;
+            } else {
+                *properties = extension("VK_KHR_surface");
+            }
+            vk::Result::SUCCESS
+        }
+
+        unsafe extern "system" fn get_instance_proc_addr(
+            _instance: vk::Instance,
+            name: *const std::ffi::c_char,
+        ) -> vk::PFN_vkVoidFunction {
+            let name = std::ffi::CStr::from_ptr(name);
+            if name.to_bytes() == b"vkEnumerateInstanceExtensionProperties" {
+                let function: vk::PFN_vkEnumerateInstanceExtensionProperties =
+                    enumerate_instance_extension_properties;
+                Some(std::mem::transmute::<
+                    vk::PFN_vkEnumerateInstanceExtensionProperties,
+                    unsafe extern "system" fn(),
+                >(function))
+            } else {
+                None
+            }
+        }
+
+        let entry = unsafe {
+            ash::Entry::from_static_fn(vk::StaticFn {
+                get_instance_proc_addr,
+            })
+        };
+        let required = super::RequiredExtensions {
+            device: Vec::new(),
+            instance: vec![
+                "VK_KHR_surface".to_owned(),
+                "VK_KHR_get_physical_device_properties2".to_owned(),
+            ],
+        };
+        let merged = required.merge_instance_extensions(&entry, &[]).unwrap();
+        assert_eq!(
+            merged.get_names(),
+            &[std::ffi::CString::new("VK_KHR_surface").unwrap()]
+        );
+        assert_eq!(
+            merged.get_missing(),
+            &[std::ffi::CString::new("VK_KHR_get_physical_device_properties2").unwrap()]
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    mod mock {
+        use super::super::{
+            ash_handle_to_pointer_mut, feature_progress_callback, get_device_proc_addr,
+            is_progress_on_another_thread, Loader,
+        };
+        use crate::bindings::{
+            self, NVSDK_NGX_Feature, NVSDK_NGX_Feature_Support_Result, NVSDK_NGX_PerfQuality_Value,
+            NVSDK_NGX_Resource_VK, NVSDK_NGX_Result,
+        };
+        use crate::mock::{self, Function, Value};
+        use crate::{
+            keys, ApplicationIdentifier, DlssLibraryType, Error, EvaluationControl,
+            FeatureCommonInfo, FeatureDiscoveryBuilder, FeatureParameters, FeatureRequirement,
+            FrameGenerationCameraMatrices, FrameGenerationCreateParameters, FrameGenerationFeature,
+            ImageSuperResolutionCreateParameters, ImageSuperResolutionScale,
+            InPaintingCreateParameters, ParameterKey, ParameterValue, PhysicalDeviceSelector,
+            RayReconstructionCreateParameters, SuperSamplingOptimalSettings, System,
+            SystemParameters, VkImageResourceDescription, FRAME_GENERATION_HUDLESS_COLOR,
+            FRAME_GENERATION_UI_COLOR, IN_PAINTING_MASK,
+        };
+        use ash::vk::{self, Handle};
+
+        fn fake_static_fn() -> vk::StaticFn {
+            unsafe extern "system" fn get_instance_proc_addr(
+                _instance: vk::Instance,
+                _name: *const std::ffi::c_char,
+            ) -> vk::PFN_vkVoidFunction {
+                None
+            }
+
+            vk::StaticFn {
+                get_instance_proc_addr,
+            }
+        }
+
+        fn assert_internal_error<T: std::fmt::Debug>(
+            result: crate::Result<T>,
+            expected: NVSDK_NGX_Result,
+        ) {
+            match result {
+                Err(e) => assert_eq!(e.result(), Some(expected)),
+                other => panic!("expected {expected:?}, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn parameters_round_trip() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let parameters = FeatureParameters::new(&system).unwrap();
+
+            parameters.set_u32(bindings::NVSDK_NGX_Parameter_Width, 1920);
+            parameters.set_f32(bindings::NVSDK_NGX_Parameter_Sharpness, 0.5);
+            parameters.set_bool(bindings::NVSDK_NGX_Parameter_Reset, true);
+            parameters.set_u64(bindings::NVSDK_NGX_Parameter_NumFrames, 1 << 40);
+            assert_eq!(
+                parameters
+                    .get_u32(bindings::NVSDK_NGX_Parameter_Width)
+                    .unwrap(),
+                1920
+            );
+            assert_eq!(
+                parameters
+                    .get_f32(bindings::NVSDK_NGX_Parameter_Sharpness)
+                    .unwrap(),
+                0.5
+            );
+            assert!(parameters
+                .get_bool(bindings::NVSDK_NGX_Parameter_Reset)
+                .unwrap());
+            assert_eq!(
+                parameters
+                    .get_u64(bindings::NVSDK_NGX_Parameter_NumFrames)
+                    .unwrap(),
+                1 << 40
+            );
+            assert!(parameters
+                .get_u32(bindings::NVSDK_NGX_Parameter_Height)
+                .is_err());
+
+            parameters.set(&keys::NVSDK_NGX_Parameter_OutWidth, 1280);
+            assert_eq!(
+                parameters.get(&keys::NVSDK_NGX_Parameter_OutWidth).unwrap(),
+                1280
+            );
+            let custom = ParameterKey::<f32>::custom("Custom.Scale").unwrap();
+            parameters.set(&custom, 0.25);
+            assert_eq!(parameters.get(&custom).unwrap(), 0.25);
+            assert_eq!(parameters.get_f32(b"Custom.Scale\0").unwrap(), 0.25);
+
+            drop(parameters);
+            assert_eq!(mock::live_parameter_maps(), 0);
+        }
+
+        #[test]
+        fn parameters_fall_back_on_old_drivers() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            mock::set_result(
+                Function::AllocateParameters,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+            );
+            mock::set_result(
+                Function::GetCapabilityParameters,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+            );
+
+            let parameters = system.allocate_parameters().unwrap();
+            assert!(matches!(parameters, SystemParameters::Ngx(_)));
+            parameters.set(&keys::NVSDK_NGX_Parameter_Width, 1920);
+            let capability_parameters = system.get_capability_parameters().unwrap();
+            assert!(matches!(capability_parameters, SystemParameters::Ngx(_)));
+            assert!(capability_parameters.supports_super_sampling().is_ok());
+            assert!(FeatureParameters::supports_super_sampling_static(&system).is_ok());
+            // Both are the map of NGX.
+            assert_eq!(
+                capability_parameters
+                    .get(&keys::NVSDK_NGX_Parameter_Width)
+                    .unwrap(),
+                1920
+            );
+            assert_eq!(mock::call_count(Function::GetParameters), 3);
+            assert_eq!(
+                system.get_parameters().unwrap().as_ptr(),
+                capability_parameters.as_ptr()
+            );
+
+            drop(parameters);
+            drop(capability_parameters);
+            assert_eq!(mock::call_count(Function::DestroyParameters), 0);
+            assert_eq!(mock::live_parameter_maps(), 0);
+
+            mock::set_result(
+                Function::AllocateParameters,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_NotInitialized,
+            );
+            assert_internal_error(
+                system.allocate_parameters(),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_NotInitialized,
+            );
+            assert_eq!(mock::call_count(Function::GetParameters), 4);
+        }
+
+        #[test]
+        fn parameters_dump() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            parameters.set(&keys::NVSDK_NGX_Parameter_Width, 1920);
+            parameters.set(&keys::NVSDK_NGX_Parameter_DLSS_Pre_Exposure, 0.5);
+            parameters.set(
+                &keys::NVSDK_NGX_Parameter_DLSS_Hint_Render_Preset_Quality,
+                3,
+            );
+
+            let dump = parameters.dump();
+            assert_eq!(dump["Width"], ParameterValue::U32(1920));
+            assert_eq!(dump["DLSS.Pre.Exposure"], ParameterValue::F32(0.5));
+            assert_eq!(
+                dump["DLSS.Hint.Render.Preset.Quality"],
+                ParameterValue::U32(3)
+            );
+            assert_eq!(dump["SuperSampling.Available"], ParameterValue::Bool(true));
+            assert!(!dump.contains_key("Height"));
+
+            let debug = format!("{parameters:?}");
+            assert!(debug.contains("\"Width\": U32(1920)"), "{debug}");
+        }
+
+        #[test]
+        fn unterminated_parameter_name() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            assert!(matches!(parameters.get_u32(b"Width"), Err(Error::Other(_))));
+            assert!(matches!(
+                parameters.get_ptr(b"Width\0\0"),
+                Err(Error::Other(_))
+            ));
+        }
+
+        #[test]
+        #[should_panic]
+        fn unterminated_parameter_name_set() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            parameters.set_u32(b"Width", 1920);
+        }
+
+        #[test]
+        fn parameters_keep_the_system_alive() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            assert!(system.shutdown().is_err());
+            assert!(mock::is_initialised());
+            assert!(parameters.supports_super_sampling().is_ok());
+
+            drop(parameters);
+            assert!(!mock::is_initialised());
+            assert_eq!(mock::live_parameter_maps(), 0);
+        }
+
+        #[test]
+        fn system_initialisation_failure() {
+            mock::reset();
+            mock::set_result(
+                Function::Init,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_FeatureNotSupported,
+            );
+            assert_internal_error(
+                mock::create_system(),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_FeatureNotSupported,
+            );
+            assert!(!mock::is_initialised());
+        }
+
+        #[test]
+        fn snippet_path() {
+            mock::reset();
+            let path = std::env::temp_dir().join("dev");
+            let system = crate::System::new_with_snippet_path(
+                None,
+                "0.0.0",
+                &std::env::temp_dir(),
+                &unsafe { ash::Entry::from_static_fn(fake_static_fn()) },
+                &unsafe { ash::Instance::load(&fake_static_fn(), vk::Instance::null()) },
+                vk::PhysicalDevice::null(),
+                mock::fake_device(),
+                Some(&path),
+            )
+            .unwrap();
+            assert_eq!(mock::feature_paths(), vec![path]);
+
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            assert_eq!(
+                parameters.get_dlss_library_type().unwrap(),
+                DlssLibraryType::Release
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_IsDevSnippetBranch,
+                Value::I32(1),
+            );
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            assert_eq!(
+                parameters.get_dlss_library_type().unwrap(),
+                DlssLibraryType::Development
+            );
+        }
+
+        fn create_system(instance: vk::Instance, device: vk::Device) -> crate::Result<System> {
+            System::new(
+                None,
+                env!("CARGO_PKG_VERSION"),
+                &std::env::temp_dir(),
+                &unsafe { ash::Entry::from_static_fn(fake_static_fn()) },
+                &unsafe { ash::Instance::load(&fake_static_fn(), instance) },
+                vk::PhysicalDevice::null(),
+                device,
+            )
+        }
+
+        #[test]
+        fn several_systems() {
+            fn assert_send<T: Send>() {}
+            assert_send::<System>();
+
+            mock::reset();
+            let first_instance = vk::Instance::from_raw(1);
+            let second_instance = vk::Instance::from_raw(2);
+            let first_device = mock::fake_device();
+            let second_device = mock::fake_device();
+            let first = create_system(first_instance, first_device).unwrap();
+            let second = create_system(second_instance, second_device).unwrap();
+
+            let loader = Loader::find_device(first_device).unwrap();
+            assert_eq!(loader.instance, first_instance);
+            let loader = Loader::find_device(second_device).unwrap();
+            assert_eq!(loader.instance, second_instance);
+            let loader = Loader::find_instance(second_instance).unwrap();
+            assert_eq!(loader.device, second_device);
+
+            assert!(matches!(
+                create_system(first_instance, first_device),
+                Err(Error::Other(_))
+            ));
+            assert_eq!(
+                Loader::find_device(first_device).unwrap().instance,
+                first_instance
+            );
+
+            drop(first);
+            assert!(Loader::find_device(first_device).is_none());
+            assert!(Loader::find_device(second_device).is_some());
+            let _first = create_system(first_instance, first_device).unwrap();
+            drop(second);
+        }
+
+        #[test]
+        fn failed_system_unregisters_its_loader() {
+            mock::reset();
+            mock::set_result(
+                Function::Init,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_PlatformError,
+            );
+            let device = mock::fake_device();
+            assert!(create_system(vk::Instance::from_raw(1), device).is_err());
+            assert!(Loader::find_device(device).is_none());
+            let name = b"vkGetDeviceQueue\0";
+            let address = unsafe {
+                get_device_proc_addr(
+                    ash_handle_to_pointer_mut::<_, std::ffi::c_void>(&device),
+                    name.as_ptr().cast(),
+                )
+            };
+            assert!(address.is_none());
+        }
+
+        #[test]
+        fn super_sampling_support() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            assert!(FeatureParameters::supports_super_sampling_static(&system).is_ok());
+
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSampling_Available,
+                Value::I32(0),
+            );
+            assert_eq!(
+                FeatureParameters::supports_super_sampling_static(&system),
+                Err(Error::FeatureUnavailable(
+                    NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling
+                ))
+            );
+
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSampling_NeedsUpdatedDriver,
+                Value::I32(1),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSampling_MinDriverVersionMajor,
+                Value::U32(535),
+            );
+            let error = FeatureParameters::supports_super_sampling_static(&system).unwrap_err();
+            assert!(error.is_driver_outdated());
+            assert_eq!(
+                error,
+                Error::DriverOutdated {
+                    feature: NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+                    min_driver_version_major: 535,
+                    min_driver_version_minor: 0,
+                }
+            );
+        }
+
+        #[test]
+        fn optimal_settings() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+
+            let settings = SuperSamplingOptimalSettings::get_optimal_settings(
+                &parameters,
+                3840,
+                2160,
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxPerf,
+            )
+            .unwrap();
+            assert_eq!(
+                (settings.render_width, settings.render_height),
+                (1920, 1080)
+            );
+            assert_eq!(settings.dynamic_max_render_width, 1920);
+            assert_eq!(settings.dynamic_min_render_height, 1080);
+
+            // The ultra quality isn't supported by default.
+            let error = SuperSamplingOptimalSettings::get_optimal_settings(
+                &parameters,
+                3840,
+                2160,
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_UltraQuality,
+            )
+            .unwrap_err();
+            assert!(error.is_unsupported());
+
+            mock::set_result(
+                Function::OptimalSettings,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter,
+            );
+            assert_internal_error(
+                SuperSamplingOptimalSettings::get_optimal_settings(
+                    &parameters,
+                    3840,
+                    2160,
+                    NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxPerf,
+                ),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter,
+            );
+        }
+
+        #[test]
+        fn optimal_settings_out_of_date() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            mock::remove_capability(bindings::NVSDK_NGX_Parameter_DLSSOptimalSettingsCallback);
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            assert_internal_error(
+                SuperSamplingOptimalSettings::get_optimal_settings(
+                    &parameters,
+                    3840,
+                    2160,
+                    NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxPerf,
+                ),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+            );
+            // The ray reconstruction has a callback of its own.
+            assert!(
+                SuperSamplingOptimalSettings::get_ray_reconstruction_optimal_settings(
+                    &parameters,
+                    3840,
+                    2160,
+                    NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxPerf,
+                )
+                .is_ok()
+            );
+            mock::remove_capability(bindings::NVSDK_NGX_Parameter_DLSSDOptimalSettingsCallback);
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            assert_internal_error(
+                SuperSamplingOptimalSettings::get_ray_reconstruction_optimal_settings(
+                    &parameters,
+                    3840,
+                    2160,
+                    NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxPerf,
+                ),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+            );
+        }
+
+        #[test]
+        fn super_sampling_create_and_evaluate() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            let settings = SuperSamplingOptimalSettings::get_optimal_settings(
+                &parameters,
+                3840,
+                2160,
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxQuality,
+            )
+            .unwrap();
+            let mut feature = system
+                .create_super_sampling_feature(
+                    vk::CommandBuffer::null(),
+                    parameters,
+                    settings.into(),
+                )
+                .unwrap();
+
+            let created = mock::created_features();
+            assert_eq!(created.len(), 1);
+            assert_eq!(
+                created[0].get(bindings::NVSDK_NGX_Parameter_OutWidth),
+                Some(Value::U32(3840))
+            );
+            assert_eq!(
+                created[0].get(bindings::NVSDK_NGX_Parameter_Width),
+                Some(Value::U32(2561))
+            );
+
+            feature
+                .get_evaluation_parameters_mut()
+                .set_jitter_offsets(0.25, -0.25);
+            feature.evaluate(vk::CommandBuffer::null()).unwrap();
+            let evaluations = mock::evaluations();
+            assert_eq!(evaluations.len(), 1);
+            assert_eq!(evaluations[0].id, created[0].id);
+            assert_eq!(
+                evaluations[0].get(bindings::NVSDK_NGX_Parameter_Jitter_Offset_X),
+                Some(Value::F32(0.25))
+            );
+            assert_eq!(
+                evaluations[0].get(bindings::NVSDK_NGX_Parameter_MV_Scale_X),
+                Some(Value::F32(1.0))
+            );
+
+            mock::set_result(
+                Function::EvaluateFeature,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter,
+            );
+            assert_internal_error(
+                feature.evaluate(vk::CommandBuffer::null()),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter,
+            );
+
+            drop(feature);
+            assert_eq!(mock::released_features(), vec![created[0].id]);
+            drop(system);
+            assert!(!mock::is_initialised());
+        }
+
+        fn create_super_sampling_feature(system: &System) -> crate::SuperSamplingFeature {
+            let parameters = FeatureParameters::get_capability_parameters(system).unwrap();
+            let settings = SuperSamplingOptimalSettings::get_optimal_settings(
+                &parameters,
+                1920,
+                1080,
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_Balanced,
+            )
+            .unwrap();
+            system
+                .create_super_sampling_feature(
+                    vk::CommandBuffer::null(),
+                    parameters,
+                    settings.into(),
+                )
+                .unwrap()
+        }
+
+        #[test]
+        fn features_keep_system_initialised() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let feature = create_super_sampling_feature(&system);
+            drop(system);
+            assert!(mock::is_initialised());
+
+            drop(feature);
+            assert_eq!(mock::released_features().len(), 1);
+            assert!(!mock::is_initialised());
+        }
+
+        #[test]
+        fn shared_parameters() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let first = system
+                .create_image_super_resolution_feature(
+                    vk::CommandBuffer::null(),
+                    FeatureParameters::new(&system).unwrap(),
+                    image_super_resolution_create_parameters(),
+                )
+                .unwrap();
+            let second = system
+                .create_image_super_resolution_feature(
+                    vk::CommandBuffer::null(),
+                    first.get_inner().get_shared_parameters(),
+                    image_super_resolution_create_parameters(),
+                )
+                .unwrap();
+            assert_eq!(
+                first.get_inner().get_parameters().as_ptr(),
+                second.get_inner().get_parameters().as_ptr()
+            );
+
+            first
+                .get_inner()
+                .get_parameters()
+                .set(&keys::NVSDK_NGX_Parameter_Reset, 1);
+            assert_eq!(
+                second
+                    .get_inner()
+                    .get_parameters()
+                    .get(&keys::NVSDK_NGX_Parameter_Reset)
+                    .unwrap(),
+                1
+            );
+
+            drop(first);
+            assert_eq!(mock::live_parameter_maps(), 1);
+            drop(second);
+            assert_eq!(mock::live_parameter_maps(), 0);
+            assert_eq!(mock::call_count(Function::DestroyParameters), 1);
+        }
+
+        #[test]
+        fn ray_reconstruction_create_and_evaluate() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            let settings = SuperSamplingOptimalSettings::get_ray_reconstruction_optimal_settings(
+                &parameters,
+                3840,
+                2160,
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_MaxPerf,
+            )
+            .unwrap();
+            assert_eq!(
+                (settings.render_width, settings.render_height),
+                (1920, 1080)
+            );
+            let mut create_parameters = RayReconstructionCreateParameters::from(settings);
+            create_parameters.set_roughness_mode(
+                bindings::NVSDK_NGX_DLSS_Roughness_Mode::NVSDK_NGX_DLSS_Roughness_Mode_Packed,
+            );
+            create_parameters
+                .set_depth_type(bindings::NVSDK_NGX_DLSS_Depth_Type::NVSDK_NGX_DLSS_Depth_Type_HW);
+            let mut feature = system
+                .create_ray_reconstruction_feature(
+                    vk::CommandBuffer::null(),
+                    parameters,
+                    create_parameters,
+                )
+                .unwrap();
+            assert_eq!(
+                feature.get_target_resolution(),
+                vk::Extent2D {
+                    width: 3840,
+                    height: 2160
+                }
+            );
+
+            let created = mock::created_features();
+            assert_eq!(created.len(), 1);
+            assert_eq!(
+                created[0].feature,
+                NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction
+            );
+            assert_eq!(
+                created[0].get(bindings::NVSDK_NGX_Parameter_OutWidth),
+                Some(Value::U32(3840))
+            );
+            assert_eq!(
+                created[0].get(bindings::NVSDK_NGX_Parameter_DLSS_Denoise_Mode),
+                Some(Value::I32(1))
+            );
+            assert_eq!(
+                created[0].get(bindings::NVSDK_NGX_Parameter_DLSS_Roughness_Mode),
+                Some(Value::I32(1))
+            );
+            assert_eq!(
+                created[0].get(bindings::NVSDK_NGX_Parameter_DLSS_Use_HW_Depth),
+                Some(Value::I32(1))
+            );
+
+            let mut world_to_view = [0.0; 16];
+            world_to_view[0] = 2.0;
+            let parameters = feature.get_evaluation_parameters_mut();
+            parameters.set_color_input(VkImageResourceDescription::default());
+            parameters.set_normals(VkImageResourceDescription::default());
+            parameters.set_specular_hit_distance(VkImageResourceDescription::default());
+            parameters.set_jitter_offsets(0.25, -0.25);
+            parameters.set_matrices(world_to_view, [0.0; 16]);
+            feature.evaluate(vk::CommandBuffer::null()).unwrap();
+
+            let evaluations = mock::evaluations();
+            assert_eq!(evaluations.len(), 1);
+            assert_eq!(evaluations[0].id, created[0].id);
+            let pointer = |name: &[u8]| match evaluations[0].get(name) {
+                Some(Value::Pointer(pointer)) => pointer,
+                value => panic!("{name:?} is {value:?}"),
+            };
+            assert!(!pointer(bindings::NVSDK_NGX_Parameter_Color).is_null());
+            assert!(!pointer(bindings::NVSDK_NGX_Parameter_DLSS_Input_Normals).is_null());
+            assert!(pointer(bindings::NVSDK_NGX_Parameter_DLSS_Input_Roughness).is_null());
+            assert!(!pointer(bindings::NVSDK_NGX_Parameter_RayTracingHitDistance).is_null());
+            assert!(pointer(bindings::NVSDK_NGX_Parameter_MotionVectorsReflection).is_null());
+            let matrix = pointer(bindings::NVSDK_NGX_Parameter_DLSS_WorldToViewMatrix);
+            assert_eq!(unsafe { *matrix.cast::<[f32; 16]>() }, world_to_view);
+            assert_eq!(
+                evaluations[0].get(bindings::NVSDK_NGX_Parameter_Jitter_Offset_X),
+                Some(Value::F32(0.25))
+            );
+
+            // The guides must be images.
+            let mut resource = NVSDK_NGX_Resource_VK::from(VkImageResourceDescription::default());
+            resource.Type =
+                bindings::NVSDK_NGX_Resource_VK_Type::NVSDK_NGX_RESOURCE_VK_TYPE_VK_BUFFER;
+            feature.get_evaluation_parameters_mut().diffuse_albedo = Some(resource);
+            assert_internal_error(
+                feature.evaluate(vk::CommandBuffer::null()),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter,
+            );
+            assert_eq!(mock::evaluations().len(), 1);
+        }
+
+        #[test]
+        fn supports_ray_reconstruction() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            assert!(FeatureParameters::supports_ray_reconstruction_static(&system).is_err());
+
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSamplingDenoising_Available,
+                Value::I32(1),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSamplingDenoising_NeedsUpdatedDriver,
+                Value::I32(0),
+            );
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            assert!(parameters.supports_ray_reconstruction().is_ok());
+            assert!(!parameters.is_ray_reconstruction_initialised());
+
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSamplingDenoising_FeatureInitResult,
+                Value::I32(NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_FeatureNotSupported.0 as i32),
+            );
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            assert!(!parameters.is_ray_reconstruction_initialised());
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_SuperSamplingDenoising_FeatureInitResult,
+                Value::I32(NVSDK_NGX_Result::NVSDK_NGX_Result_Success.0 as i32),
+            );
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            assert!(parameters.is_ray_reconstruction_initialised());
+        }
+
+        #[test]
+        fn image_super_resolution_upscale() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_ImageSuperResolution_Available,
+                Value::I32(1),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_ImageSuperResolution_NeedsUpdatedDriver,
+                Value::I32(0),
+            );
+            FeatureParameters::supports_image_super_resolution_static(&system).unwrap();
+
+            let image = |width, height| VkImageResourceDescription {
+                width,
+                height,
+                ..Default::default()
+            };
+            let mut feature = system
+                .record_upscale_image(
+                    vk::CommandBuffer::null(),
+                    image(64, 32),
+                    image(256, 128),
+                    ImageSuperResolutionScale::X4,
+                )
+                .unwrap();
+            assert_eq!(
+                feature.get_output_resolution(),
+                vk::Extent2D {
+                    width: 256,
+                    height: 128
+                }
+            );
+
+            let created = mock::created_features();
+            assert_eq!(created.len(), 1);
+            assert_eq!(
+                created[0].feature,
+                NVSDK_NGX_Feature::NVSDK_NGX_Feature_ImageSuperResolution
+            );
+            assert_eq!(
+                created[0].get(bindings::NVSDK_NGX_Parameter_Width),
+                Some(Value::U32(64))
+            );
+            assert_eq!(
+                created[0].get(bindings::NVSDK_NGX_Parameter_Scale),
+                Some(Value::U32(4))
+            );
+            assert_eq!(mock::evaluations()[0].id, created[0].id);
+            // The recorded work still uses the feature.
+            assert!(mock::released_features().is_empty());
+
+            // The images must have the sizes the feature was created for.
+            let error = feature
+                .upscale(vk::CommandBuffer::null(), image(64, 32), image(128, 64))
+                .unwrap_err();
+            assert!(matches!(error, Error::Other(_)));
+            assert_eq!(mock::evaluations().len(), 1);
+            feature
+                .upscale(vk::CommandBuffer::null(), image(64, 32), image(256, 128))
+                .unwrap();
+            // The resources are read while the feature isn't moved.
+            let evaluations = mock::evaluations();
+            assert_eq!(evaluations.len(), 2);
+            let resource = |name: &[u8]| match evaluations[1].get(name) {
+                Some(Value::Pointer(pointer)) => unsafe {
+                    *pointer.cast::<NVSDK_NGX_Resource_VK>()
+                },
+                value => panic!("{name:?} is {value:?}"),
+            };
+            let input = resource(bindings::NVSDK_NGX_Parameter_Color);
+            assert!(!input.ReadWrite);
+            assert_eq!(unsafe { input.Resource.ImageViewInfo.Width }, 64);
+            let output = resource(bindings::NVSDK_NGX_Parameter_Output);
+            assert!(output.ReadWrite);
+            assert_eq!(unsafe { output.Resource.ImageViewInfo.Width }, 256);
+
+            mock::set_evaluation_progress(&[0.5, 1.0]);
+            let mut progress = Vec::new();
+            feature
+                .evaluate_with_progress(vk::CommandBuffer::null(), |value| {
+                    progress.push(value);
+                    EvaluationControl::Continue
+                })
+                .unwrap();
+            assert_eq!(progress, [0.5, 1.0]);
+            assert_eq!(mock::evaluations().len(), 3);
+
+            drop(feature);
+            assert_eq!(mock::released_features(), vec![created[0].id]);
+        }
+
+        /// The Vulkan calls made by [`System::upscale_image`] on the
+        /// device of [`fake_ash_device`], in order.
+        fn device_calls() -> Vec<&'static str> {
+            DEVICE_CALLS.with(|calls| calls.borrow().clone())
+        }
+
+        /// Makes the `call` on the device of [`fake_ash_device`] fail
+        /// with [`vk::Result::ERROR_DEVICE_LOST`].
+        fn fail_device_call(call: &'static str) {
+            FAILING_DEVICE_CALLS.with(|calls| calls.borrow_mut().push(call));
+        }
+
+        thread_local! {
+            static DEVICE_CALLS: std::cell::RefCell<Vec<&'static str>> =
+                const { std::cell::RefCell::new(Vec::new()) };
+            static FAILING_DEVICE_CALLS: std::cell::RefCell<Vec<&'static str>> =
+                const { std::cell::RefCell::new(Vec::new()) };
+        }
+
+        /// Returns a logical device whose command buffer, fence and queue
+        /// functions only record that they were called, and fail if
+        /// made to with [`fail_device_call`].
+        fn fake_ash_device(device: vk::Device) -> ash::Device {
+            fn record(call: &'static str) -> vk::Result {
+                DEVICE_CALLS.with(|calls| calls.borrow_mut().push(call));
+                if FAILING_DEVICE_CALLS.with(|calls| calls.borrow().contains(&call)) {
+                    vk::Result::ERROR_DEVICE_LOST
+                } else {
+                    vk::Result::SUCCESS
+                }
+            }
+
+            unsafe extern "system" fn allocate_command_buffers(
+                _device: vk::Device,
+                _allocate_info: *const vk::CommandBufferAllocateInfo,
+                command_buffers: *mut vk::CommandBuffer,
+            ) -> vk::Result {
+                *command_buffers = vk::CommandBuffer::from_raw(1);
+                record("vkAllocateCommandBuffers")
+            }
+
+            unsafe extern "system" fn free_command_buffers(
+                _device: vk::Device,
+                _command_pool: vk::CommandPool,
+                _count: u32,
+                _command_buffers: *const vk::CommandBuffer,
+            ) {
+                let _ = record("vkFreeCommandBuffers");
+            }
+
+            unsafe extern "system" fn begin_command_buffer(
+                _command_buffer: vk::CommandBuffer,
+                _begin_info: *const vk::CommandBufferBeginInfo,
+            ) -> vk::Result {
+                record("vkBeginCommandBuffer")
+            }
+
+            unsafe extern "system" fn end_command_buffer(
+                _command_buffer: vk::CommandBuffer,
+            ) -> vk::Result {
+                record("vkEndCommandBuffer")
+            }
+
+            unsafe extern "system" fn create_fence(
+                _device: vk::Device,
+                _create_info: *const vk::FenceCreateInfo,
+                _allocator: *const vk::AllocationCallbacks,
+                fence: *mut vk::Fence,
+            ) -> vk::Result {
+                *fence = vk::Fence::from_raw(1);
+                record("vkCreateFence")
+            }
+
+            unsafe extern "system" fn destroy_fence(
+                _device: vk::Device,
+                _fence: vk::Fence,
+                _allocator: *const vk::AllocationCallbacks,
+            ) {
+                let _ = record("vkDestroyFence");
+            }
+
+            unsafe extern "system" fn queue_submit(
+                _queue: vk::Queue,
+                _count: u32,
+                _submits: *const vk::SubmitInfo,
+                _fence: vk::Fence,
+            ) -> vk::Result {
+                record("vkQueueSubmit")
+            }
+
+            unsafe extern "system" fn wait_for_fences(
+                _device: vk::Device,
+                _count: u32,
+                _fences: *const vk::Fence,
+                _wait_all: vk::Bool32,
+                _timeout: u64,
+            ) -> vk::Result {
+                // The feature must be alive while the GPU uses it.
+                let feature = mock::created_features().last().map(|feature| feature.id);
+                if !mock::released_features()
+                    .iter()
+                    .any(|id| Some(*id) == feature)
+                {
+                    record("vkWaitForFences")
+                } else {
+                    record("vkWaitForFences after the feature was released")
+                }
+            }
+
+            unsafe extern "system" fn queue_wait_idle(_queue: vk::Queue) -> vk::Result {
+                record("vkQueueWaitIdle")
+            }
+
+            unsafe extern "system" fn get_device_proc_addr(
+                _device: vk::Device,
+                name: *const std::ffi::c_char,
+            ) -> vk::PFN_vkVoidFunction {
+                let allocate_command_buffers: vk::PFN_vkAllocateCommandBuffers =
+                    allocate_command_buffers;
+                let free_command_buffers: vk::PFN_vkFreeCommandBuffers = free_command_buffers;
+                let begin_command_buffer: vk::PFN_vkBeginCommandBuffer = begin_command_buffer;
+                let end_command_buffer: vk::PFN_vkEndCommandBuffer = end_command_buffer;
+                let create_fence: vk::PFN_vkCreateFence = create_fence;
+                let destroy_fence: vk::PFN_vkDestroyFence = destroy_fence;
+                let queue_submit: vk::PFN_vkQueueSubmit = queue_submit;
+                let wait_for_fences: vk::PFN_vkWaitForFences = wait_for_fences;
+                let queue_wait_idle: vk::PFN_vkQueueWaitIdle = queue_wait_idle;
+                let function = match std::ffi::CStr::from_ptr(name).to_bytes() {
+                    b"vkAllocateCommandBuffers" => allocate_command_buffers as *const (),
+                    b"vkFreeCommandBuffers" => free_command_buffers as *const (),
+                    b"vkBeginCommandBuffer" => begin_command_buffer as *const (),
+                    b"vkEndCommandBuffer" => end_command_buffer as *const (),
+                    b"vkCreateFence" => create_fence as *const (),
+                    b"vkDestroyFence" => destroy_fence as *const (),
+                    b"vkQueueSubmit" => queue_submit as *const (),
+                    b"vkWaitForFences" => wait_for_fences as *const (),
+                    b"vkQueueWaitIdle" => queue_wait_idle as *const (),
+                    _ => return None,
+                };
+                Some(std::mem::transmute::<*const (), unsafe extern "system" fn()>(function))
+            }
+
+            let instance_fn = vk::InstanceFnV1_0::load(|name| match name.to_bytes() {
+                b"vkGetDeviceProcAddr" => {
+                    let get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr = get_device_proc_addr;
+                    get_device_proc_addr as *const std::ffi::c_void
+                }
+                _ => std::ptr::null(),
+            });
+            unsafe { ash::Device::load(&instance_fn, device) }
+        }
+
+        #[test]
+        fn image_super_resolution_upscale_and_wait() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let image = |width, height| VkImageResourceDescription {
+                width,
+                height,
+                ..Default::default()
+            };
+
+            let other_device = fake_ash_device(mock::fake_device());
+            let error = system
+                .upscale_image(
+                    &other_device,
+                    vk::Queue::null(),
+                    vk::CommandPool::null(),
+                    image(64, 32),
+                    image(128, 64),
+                    ImageSuperResolutionScale::X2,
+                )
+                .unwrap_err();
+            assert!(matches!(error, Error::Other(_)));
+            assert!(device_calls().is_empty());
+
+            let device = fake_ash_device(system.handle.device);
+            system
+                .upscale_image(
+                    &device,
+                    vk::Queue::null(),
+                    vk::CommandPool::null(),
+                    image(64, 32),
+                    image(128, 64),
+                    ImageSuperResolutionScale::X2,
+                )
+                .unwrap();
+            assert_eq!(
+                device_calls(),
+                [
+                    "vkAllocateCommandBuffers",
+                    "vkBeginCommandBuffer",
+                    "vkEndCommandBuffer",
+                    "vkCreateFence",
+                    "vkQueueSubmit",
+                    "vkWaitForFences",
+                    "vkDestroyFence",
+                    "vkFreeCommandBuffers",
+                ]
+            );
+            let created = mock::created_features();
+            assert_eq!(created.len(), 1);
+            assert_eq!(mock::evaluations().len(), 1);
+            assert_eq!(mock::released_features(), vec![created[0].id]);
+        }
+
+        #[test]
+        fn image_super_resolution_upscale_wait_failure() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let device = fake_ash_device(system.handle.device);
+            let upscale = || {
+                system.upscale_image(
+                    &device,
+                    vk::Queue::null(),
+                    vk::CommandPool::null(),
+                    VkImageResourceDescription {
+                        width: 64,
+                        height: 32,
+                        ..Default::default()
+                    },
+                    VkImageResourceDescription {
+                        width: 128,
+                        height: 64,
+                        ..Default::default()
+                    },
+                    ImageSuperResolutionScale::X2,
+                )
+            };
+
+            // Once the queue is idle, everything is freed.
+            fail_device_call("vkWaitForFences");
+            assert!(matches!(upscale(), Err(Error::Other(_))));
+            assert_eq!(
+                device_calls()[5..],
+                [
+                    "vkWaitForFences",
+                    "vkQueueWaitIdle",
+                    "vkDestroyFence",
+                    "vkFreeCommandBuffers",
+                ]
+            );
+            let created = mock::created_features();
+            assert_eq!(mock::released_features(), vec![created[0].id]);
+
+            // Otherwise, what the GPU may still use is leaked.
+            fail_device_call("vkQueueWaitIdle");
+            assert!(matches!(upscale(), Err(Error::Other(_))));
+            assert_eq!(device_calls()[14..], ["vkWaitForFences", "vkQueueWaitIdle"]);
+            assert_eq!(mock::created_features().len(), 2);
+            assert_eq!(mock::released_features(), vec![created[0].id]);
+        }
+
+        #[test]
+        fn in_painting_create_and_evaluate() {
+            mock::reset();
+            mock::set_evaluation_progress(&[0.25, 0.5, 0.75, 1.0]);
+            let system = mock::create_system().unwrap();
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_InPainting_Available,
+                Value::I32(1),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_InPainting_NeedsUpdatedDriver,
+                Value::I32(0),
+            );
+            FeatureParameters::supports_in_painting_static(&system).unwrap();
+
+            let mut feature = system
+                .create_in_painting_feature(
+                    vk::CommandBuffer::null(),
+                    FeatureParameters::new(&system).unwrap(),
+                    InPaintingCreateParameters::new(640, 480),
+                )
+                .unwrap();
+            let created = mock::created_features();
+            assert_eq!(
+                created[0].feature,
+                NVSDK_NGX_Feature::NVSDK_NGX_Feature_InPainting
+            );
+            assert_eq!(
+                created[0].get(bindings::NVSDK_NGX_Parameter_Height),
+                Some(Value::U32(480))
+            );
+
+            feature
+                .get_evaluation_parameters_mut()
+                .set_color_input(VkImageResourceDescription::default());
+            let error = feature.evaluate(vk::CommandBuffer::null()).unwrap_err();
+            assert!(matches!(error, Error::Other(_)));
+            assert!(mock::evaluations().is_empty());
+
+            feature
+                .get_evaluation_parameters_mut()
+                .set_color_output(VkImageResourceDescription::default());
+            let error = feature.evaluate(vk::CommandBuffer::null()).unwrap_err();
+            assert!(matches!(error, Error::Other(_)));
+            assert!(mock::evaluations().is_empty());
+
+            feature
+                .get_evaluation_parameters_mut()
+                .set_mask(VkImageResourceDescription::default());
+            let mut progress = Vec::new();
+            feature
+                .evaluate_with_progress(vk::CommandBuffer::null(), |value| {
+                    progress.push(value);
+                    if value < 0.5 {
+                        EvaluationControl::Continue
+                    } else {
+                        EvaluationControl::Cancel
+                    }
+                })
+                .unwrap();
+            assert_eq!(progress, [0.25, 0.5]);
+            let evaluations = mock::evaluations();
+            assert_eq!(evaluations.len(), 1);
+            assert!(evaluations[0].cancelled);
+            let pointer = |name: &[u8]| match evaluations[0].get(name) {
+                Some(Value::Pointer(pointer)) => pointer,
+                value => panic!("{name:?} is {value:?}"),
+            };
+            assert!(!pointer(bindings::NVSDK_NGX_Parameter_Color).is_null());
+            assert!(!pointer(IN_PAINTING_MASK.name()).is_null());
+            let output = pointer(bindings::NVSDK_NGX_Parameter_Output);
+            assert!(unsafe { (*output.cast::<NVSDK_NGX_Resource_VK>()).ReadWrite });
+        }
+
+        fn frame_generation_create_parameters() -> FrameGenerationCreateParameters {
+            FrameGenerationCreateParameters::new(1920, 1080)
+        }
+
+        #[test]
+        fn frame_generation_create_and_evaluate() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let mut feature = system
+                .create_frame_generation_feature(
+                    vk::CommandBuffer::null(),
+                    FeatureParameters::new(&system).unwrap(),
+                    frame_generation_create_parameters(),
+                )
+                .unwrap();
+            assert_eq!(
+                feature.get_resolution(),
+                vk::Extent2D {
+                    width: 1920,
+                    height: 1080
+                }
+            );
+            let created = mock::created_features();
+            assert_eq!(created.len(), 1);
+            assert_eq!(
+                created[0].feature,
+                NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration
+            );
+            assert_eq!(
+                created[0].get(bindings::NVSDK_NGX_Parameter_Width),
+                Some(Value::U32(1920))
+            );
+
+            // The backbuffer and the outputs are required.
+            assert!(matches!(
+                feature.evaluate(vk::CommandBuffer::null()),
+                Err(Error::Other(_))
+            ));
+            let parameters = feature.get_evaluation_parameters_mut();
+            parameters.set_backbuffer(VkImageResourceDescription::default());
+            parameters.set_previous_backbuffer(VkImageResourceDescription::default());
+            parameters.set_depth_buffer(VkImageResourceDescription::default());
+            parameters.set_motions_vectors(VkImageResourceDescription::default(), None);
+            parameters.set_hudless_color(VkImageResourceDescription::default());
+            parameters.set_generated_frames(2);
+            parameters
+                .set_output(0, VkImageResourceDescription::default())
+                .unwrap();
+            assert!(parameters
+                .set_output(3, VkImageResourceDescription::default())
+                .is_err());
+            assert!(matches!(
+                feature.evaluate(vk::CommandBuffer::null()),
+                Err(Error::Other(_))
+            ));
+            assert!(mock::evaluations().is_empty());
+
+            let parameters = feature.get_evaluation_parameters_mut();
+            parameters
+                .set_output(1, VkImageResourceDescription::default())
+                .unwrap();
+            let mut matrices = FrameGenerationCameraMatrices::default();
+            matrices.clip_to_prev_clip[0] = 2.0;
+            parameters.set_camera_matrices(matrices);
+            parameters.set_reset(true);
+            feature.evaluate(vk::CommandBuffer::null()).unwrap();
+
+            let evaluations = mock::evaluations();
+            assert_eq!(evaluations.len(), 1);
+            assert_eq!(evaluations[0].id, created[0].id);
+            let pointer = |name: &[u8]| match evaluations[0].get(name) {
+                Some(Value::Pointer(pointer)) => pointer,
+                value => panic!("{name:?} is {value:?}"),
+            };
+            for name in [
+                &bindings::NVSDK_NGX_Parameter_FI_Color1[..],
+                bindings::NVSDK_NGX_Parameter_FI_Color2,
+                bindings::NVSDK_NGX_Parameter_FI_Depth2,
+                bindings::NVSDK_NGX_Parameter_FI_MotionVectors2,
+                FRAME_GENERATION_HUDLESS_COLOR.name(),
+            ] {
+                assert!(!pointer(name).is_null());
+            }
+            for name in [
+                &bindings::NVSDK_NGX_Parameter_FI_Depth1[..],
+                bindings::NVSDK_NGX_Parameter_FI_MotionVectors1,
+                bindings::NVSDK_NGX_Parameter_FI_Output3,
+                FRAME_GENERATION_UI_COLOR.name(),
+            ] {
+                assert!(pointer(name).is_null());
+            }
+            for name in [
+                bindings::NVSDK_NGX_Parameter_FI_Output1,
+                bindings::NVSDK_NGX_Parameter_FI_Output2,
+            ] {
+                let output = pointer(name);
+                assert!(unsafe { (*output.cast::<NVSDK_NGX_Resource_VK>()).ReadWrite });
+            }
+            let matrix = pointer(bindings::NVSDK_NGX_Parameter_DLSS_CLIP_TO_PREV_CLIP_MATRIX);
+            assert_eq!(unsafe { *matrix.cast::<f32>() }, 2.0);
+            assert_eq!(
+                evaluations[0].get(bindings::NVSDK_NGX_Parameter_NumFrames),
+                Some(Value::U32(2))
+            );
+            assert_eq!(
+                evaluations[0].get(bindings::NVSDK_NGX_Parameter_Reset),
+                Some(Value::I32(1))
+            );
+
+            let error = FrameGenerationFeature::new(
+                system
+                    .create_feature(
+                        vk::CommandBuffer::null(),
+                        NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+                        None,
+                    )
+                    .unwrap(),
+                vk::Extent2D::default(),
+            )
+            .unwrap_err();
+            assert!(matches!(error, Error::Other(_)));
+        }
+
+        fn image_super_resolution_create_parameters() -> ImageSuperResolutionCreateParameters {
+            ImageSuperResolutionCreateParameters::new(640, 480, ImageSuperResolutionScale::X2)
+        }
+
+        #[test]
+        fn supports_frame_generation() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            assert!(parameters.supports_frame_generation().is_err());
+
+            // The older drivers report it under the frame interpolation
+            // name only.
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameInterpolation_Available,
+                Value::I32(1),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameInterpolation_NeedsUpdatedDriver,
+                Value::I32(0),
+            );
+            assert!(FeatureParameters::supports_frame_generation_static(&system).is_ok());
+
+            // The frame generation name takes precedence.
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameGeneration_Available,
+                Value::I32(0),
+            );
+            assert!(matches!(
+                FeatureParameters::supports_frame_generation_static(&system),
+                Err(Error::FeatureUnavailable(
+                    NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration
+                ))
+            ));
+            mock::remove_capability(bindings::NVSDK_NGX_Parameter_FrameInterpolation_Available);
+            mock::remove_capability(
+                bindings::NVSDK_NGX_Parameter_FrameInterpolation_NeedsUpdatedDriver,
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameGeneration_Available,
+                Value::I32(1),
+            );
+            assert!(FeatureParameters::supports_frame_generation_static(&system).is_ok());
+            mock::remove_capability(bindings::NVSDK_NGX_Parameter_FrameGeneration_Available);
+
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameInterpolation_NeedsUpdatedDriver,
+                Value::I32(1),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameInterpolation_MinDriverVersionMajor,
+                Value::U32(535),
+            );
+            mock::set_capability(
+                bindings::NVSDK_NGX_Parameter_FrameInterpolation_MinDriverVersionMinor,
+                Value::U32(98),
+            );
+            match FeatureParameters::supports_frame_generation_static(&system).unwrap_err() {
+                Error::DriverOutdated {
+                    feature,
+                    min_driver_version_major,
+                    min_driver_version_minor,
+                } => {
+                    assert_eq!(
+                        feature,
+                        NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration
+                    );
+                    assert_eq!(
+                        (min_driver_version_major, min_driver_version_minor),
+                        (535, 98)
+                    );
+                }
+                error => panic!("Unexpected error: {error}"),
+            }
+        }
+
+        #[test]
+        fn evaluation_progress() {
+            mock::reset();
+            mock::set_evaluation_progress(&[0.25, 0.5, 1.0]);
+            let system = mock::create_system().unwrap();
+            let feature = system
+                .create_image_super_resolution_feature(
+                    vk::CommandBuffer::null(),
+                    FeatureParameters::new(&system).unwrap(),
+                    image_super_resolution_create_parameters(),
+                )
+                .unwrap();
+
+            let mut progress = Vec::new();
+            feature
+                .get_inner()
+                .evaluate_with_progress(vk::CommandBuffer::null(), |value| {
+                    progress.push(value);
+                    EvaluationControl::Continue
+                })
+                .unwrap();
+            assert_eq!(progress, [0.25, 0.5, 1.0]);
+            assert!(!mock::evaluations()[0].cancelled);
+
+            progress.clear();
+            feature
+                .get_inner()
+                .evaluate_with_progress(vk::CommandBuffer::null(), |value| {
+                    progress.push(value);
+                    if value < 0.5 {
+                        EvaluationControl::Continue
+                    } else {
+                        EvaluationControl::Cancel
+                    }
+                })
+                .unwrap();
+            assert_eq!(progress, [0.25, 0.5]);
+            assert!(mock::evaluations()[1].cancelled);
+
+            // Without a callback, the progress is only logged.
+            feature
+                .get_inner()
+                .evaluate(vk::CommandBuffer::null())
+                .unwrap();
+            assert!(!mock::evaluations()[2].cancelled);
+        }
+
+        #[test]
+        fn evaluation_progress_panic() {
+            mock::reset();
+            mock::set_evaluation_progress(&[0.5, 1.0]);
+            let system = mock::create_system().unwrap();
+            let feature = system
+                .create_image_super_resolution_feature(
+                    vk::CommandBuffer::null(),
+                    FeatureParameters::new(&system).unwrap(),
+                    image_super_resolution_create_parameters(),
+                )
+                .unwrap();
+
+            let mut calls = 0;
+            let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                feature
+                    .get_inner()
+                    .evaluate_with_progress(vk::CommandBuffer::null(), |_| {
+                        calls += 1;
+                        panic!("progress");
+                    })
+            }))
+            .unwrap_err();
+            assert_eq!(panic.downcast_ref::<&str>(), Some(&"progress"));
+            assert_eq!(calls, 1);
+            assert!(mock::evaluations()[0].cancelled);
+
+            // The next evaluations aren't affected.
+            feature
+                .get_inner()
+                .evaluate_with_progress(vk::CommandBuffer::null(), |_| EvaluationControl::Continue)
+                .unwrap();
+            assert!(!mock::evaluations()[1].cancelled);
+        }
+
+        #[test]
+        fn evaluation_progress_other_thread() {
+            mock::reset();
+            mock::set_evaluation_progress(&[0.5, 1.0]);
+            let system = mock::create_system().unwrap();
+            let feature = system
+                .create_image_super_resolution_feature(
+                    vk::CommandBuffer::null(),
+                    FeatureParameters::new(&system).unwrap(),
+                    image_super_resolution_create_parameters(),
+                )
+                .unwrap();
+
+            let mut progress = Vec::new();
+            feature
+                .get_inner()
+                .evaluate_with_progress(vk::CommandBuffer::null(), |value| {
+                    progress.push(value);
+                    // The progress reported on another thread is only
+                    // logged, and can't cancel the evaluation.
+                    std::thread::spawn(|| {
+                        assert!(is_progress_on_another_thread());
+                        let mut should_cancel = false;
+                        unsafe { feature_progress_callback(0.75, &mut should_cancel) };
+                        assert!(!should_cancel);
+                    })
+                    .join()
+                    .unwrap();
+                    EvaluationControl::Continue
+                })
+                .unwrap();
+            assert_eq!(progress, [0.5, 1.0]);
+        }
+
+        #[test]
+        fn evaluation_progress_threads() {
+            let threads: Vec<_> = (0..4)
+                .map(|thread| {
+                    std::thread::spawn(move || {
+                        let steps: Vec<f32> = (1..=thread + 1).map(|step| step as f32).collect();
+                        mock::reset();
+                        mock::set_evaluation_progress(&steps);
+                        let system = mock::create_system().unwrap();
+                        let feature = system
+                            .create_image_super_resolution_feature(
+                                vk::CommandBuffer::null(),
+                                FeatureParameters::new(&system).unwrap(),
+                                image_super_resolution_create_parameters(),
+                            )
+                            .unwrap();
+                        let mut progress = Vec::new();
+                        for _ in 0..100 {
+                            progress.clear();
+                            feature
+                                .get_inner()
+                                .evaluate_with_progress(vk::CommandBuffer::null(), |value| {
+                                    progress.push(value);
+                                    EvaluationControl::Continue
+                                })
+                                .unwrap();
+                            assert_eq!(progress, steps);
+                        }
+                    })
+                })
+                .collect();
+            for thread in threads {
+                thread.join().unwrap();
+            }
+        }
+
+        #[test]
+        fn explicit_teardown() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            let feature = create_super_sampling_feature(&system);
+            let id = mock::created_features()[0].id;
+            assert!(matches!(system.shutdown(), Err(Error::Other(_))));
+            assert!(mock::is_initialised());
+            feature.destroy().unwrap();
+            assert_eq!(mock::released_features(), vec![id]);
+            assert!(!mock::is_initialised());
+
+            let system = mock::create_system().unwrap();
+            let feature = create_super_sampling_feature(&system);
+            mock::set_result(
+                Function::ReleaseFeature,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter,
+            );
+            assert_internal_error(
+                feature.destroy(),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter,
+            );
+            mock::set_result(
+                Function::Shutdown,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_PlatformError,
+            );
+            assert_internal_error(
+                system.shutdown(),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_PlatformError,
+            );
+        }
+
+        #[test]
+        fn feature_discovery() {
+            mock::reset();
+            let project_id = uuid::Uuid::new_v4();
+            let data_path = std::env::temp_dir();
+            let snippet_path = std::path::PathBuf::from("/opt/ngx/snippets");
+            let discover = || {
+                FeatureDiscoveryBuilder::new(NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration)
+                    .application_identifier(ApplicationIdentifier::ProjectId {
+                        project_id,
+                        engine_version: "1.0".to_owned(),
+                    })
+                    .application_data_path(&data_path)
+                    .common_info(FeatureCommonInfo::new(vec![snippet_path.clone()]))
+                    .get_requirements(vk::Instance::null(), vk::PhysicalDevice::null())
+            };
+            assert!(discover().unwrap().is_supported());
+            assert!(!mock::is_initialised());
+
+            let discoveries = mock::discoveries();
+            assert_eq!(discoveries.len(), 1);
+            assert_eq!(
+                discoveries[0].feature,
+                NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration
+            );
+            assert_eq!(
+                discoveries[0].identifier,
+                ApplicationIdentifier::ProjectId {
+                    project_id,
+                    engine_version: "1.0".to_owned(),
+                }
+            );
+            assert_eq!(discoveries[0].application_data_path, data_path);
+            assert_eq!(discoveries[0].feature_paths, vec![snippet_path.clone()]);
+
+            let requirement = FeatureRequirement {
+                support: NVSDK_NGX_Feature_Support_Result::NVSDK_NGX_FeatureSupportResult_OSVersionBelowMinimumSupported,
+                min_hw_architecture: 0x190,
+                min_os_version: "10.0.19041".to_owned(),
+            };
+            mock::set_feature_requirement(
+                NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration,
+                requirement.clone(),
+            );
+            assert_eq!(discover().unwrap(), requirement);
+
+            mock::set_result(
+                Function::GetFeatureRequirements,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+            );
+            assert_internal_error(
+                discover(),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate,
+            );
+        }
+
+        #[test]
+        fn feature_extension_requirements() {
+            fn names(properties: &[vk::ExtensionProperties]) -> Vec<(String, u32)> {
+                properties
+                    .iter()
+                    .map(|p| {
+                        let name = unsafe { std::ffi::CStr::from_ptr(p.extension_name.as_ptr()) };
+                        (name.to_string_lossy().into_owned(), p.spec_version)
+                    })
+                    .collect()
+            }
+
+            mock::reset();
+            mock::set_feature_extension_requirements(
+                NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration,
+                &[("VK_KHR_get_physical_device_properties2", 2)],
+                &[
+                    ("VK_KHR_timeline_semaphore", 2),
+                    ("VK_NVX_binary_import", 1),
+                ],
+            );
+            let discovery =
+                FeatureDiscoveryBuilder::new(NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration)
+                    .application_identifier(ApplicationIdentifier::ApplicationId(1))
+                    .application_data_path(&std::env::temp_dir());
+            assert_eq!(
+                names(&discovery.get_instance_extension_requirements().unwrap()),
+                vec![("VK_KHR_get_physical_device_properties2".to_owned(), 2)]
+            );
+            assert_eq!(
+                names(
+                    &discovery
+                        .get_device_extension_requirements(
+                            vk::Instance::null(),
+                            vk::PhysicalDevice::null()
+                        )
+                        .unwrap()
+                ),
+                vec![
+                    ("VK_KHR_timeline_semaphore".to_owned(), 2),
+                    ("VK_NVX_binary_import".to_owned(), 1),
+                ]
+            );
+            assert_eq!(mock::discoveries().len(), 2);
+
+            let discovery =
+                FeatureDiscoveryBuilder::new(NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling)
+                    .application_identifier(ApplicationIdentifier::ApplicationId(1))
+                    .application_data_path(&std::env::temp_dir());
+            assert!(discovery
+                .get_device_extension_requirements(vk::Instance::null(), vk::PhysicalDevice::null())
+                .unwrap()
+                .is_empty());
+
+            mock::set_result(
+                Function::GetFeatureInstanceExtensionRequirements,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_FeatureNotSupported,
+            );
+            assert_internal_error(
+                discovery.get_instance_extension_requirements(),
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_FeatureNotSupported,
+            );
+        }
+
+        /// A physical device as (handle, vendor id, type, driver version,
+        /// whether it has the extension required by frame generation).
+        type FakePhysicalDevice = (u64, u32, vk::PhysicalDeviceType, (u32, u32), bool);
+
+        /// The physical devices in the order they are enumerated.
+        const PHYSICAL_DEVICES: [FakePhysicalDevice; 5] = [
+            (
+                1,
+                0x8086,
+                vk::PhysicalDeviceType::INTEGRATED_GPU,
+                (1, 0),
+                true,
+            ),
+            (
+                5,
+                0x10DE,
+                vk::PhysicalDeviceType::VIRTUAL_GPU,
+                (535, 0),
+                true,
+            ),
+            (
+                3,
+                0x10DE,
+                vk::PhysicalDeviceType::DISCRETE_GPU,
+                (535, 0),
+                false,
+            ),
+            (
+                4,
+                0x10DE,
+                vk::PhysicalDeviceType::DISCRETE_GPU,
+                (470, 0),
+                true,
+            ),
+            (
+                2,
+                0x10DE,
+                vk::PhysicalDeviceType::DISCRETE_GPU,
+                (535, 0),
+                true,
+            ),
+        ];
+
+        fn fake_physical_device(physical_device: vk::PhysicalDevice) -> FakePhysicalDevice {
+            PHYSICAL_DEVICES
+                .into_iter()
+                .find(|d| d.0 == physical_device.as_raw())
+                .unwrap()
+        }
+
+        fn fake_instance() -> ash::Instance {
+            unsafe extern "system" fn enumerate_physical_devices(
+                _instance: vk::Instance,
+                count: *mut u32,
+                physical_devices: *mut vk::PhysicalDevice,
+            ) -> vk::Result {
+                if physical_devices.is_null() {
+                    *count = PHYSICAL_DEVICES.len() as u32;
+                } else {
+                    for (i, device) in PHYSICAL_DEVICES.iter().enumerate() {
+                        *physical_devices.add(i) = vk::PhysicalDevice::from_raw(device.0);
+                    }
+                }
+                vk::Result::SUCCESS
+            }
+
+            unsafe extern "system" fn get_physical_device_properties(
+                physical_device: vk::PhysicalDevice,
+                properties: *mut vk::PhysicalDeviceProperties,
+            ) {
+                let (handle, vendor_id, device_type, (major, minor), _) =
+                    fake_physical_device(physical_device);
+                let mut result = vk::PhysicalDeviceProperties {
+                    vendor_id,
+                    device_type,
+                    driver_version: (major << 22) | (minor << 14),
+                    ..Default::default()
+                };
+                let name = format!("GPU {handle}");
+                for (destination, source) in result.device_name.iter_mut().zip(name.bytes()) {
+                    *destination = source as std::ffi::c_char;
+                }
+                *properties = result;
+            }
+
+            unsafe extern "system" fn enumerate_device_extension_properties(
+                physical_device: vk::PhysicalDevice,
+                _layer_name: *const std::ffi::c_char,
+                count: *mut u32,
+                properties: *mut vk::ExtensionProperties,
+            ) -> vk::Result {
+                let has_extension = fake_physical_device(physical_device).4;
+                if properties.is_null() {
+                    *count = has_extension as u32;
+                } else if has_extension {
+                    let mut extension = vk::ExtensionProperties::default();
+                    for (destination, source) in extension
+                        .extension_name
+                        .iter_mut()
+                        .zip(b"VK_NVX_binary_import".iter())
+                    {
+                        *destination = *source as std::ffi::c_char;
+                    }
+                    *properties = extension;
+                }
+                vk::Result::SUCCESS
+            }
+
+            unsafe extern "system" fn get_instance_proc_addr(
+                _instance: vk::Instance,
+                name: *const std::ffi::c_char,
+            ) -> vk::PFN_vkVoidFunction {
+                let enumerate_physical_devices: vk::PFN_vkEnumeratePhysicalDevices =
+                    enumerate_physical_devices;
+                let get_physical_device_properties: vk::PFN_vkGetPhysicalDeviceProperties =
+                    get_physical_device_properties;
+                let enumerate_device_extension_properties: vk::PFN_vkEnumerateDeviceExtensionProperties =
+                    enumerate_device_extension_properties;
+                let function = match std::ffi::CStr::from_ptr(name).to_bytes() {
+                    b"vkEnumeratePhysicalDevices" => enumerate_physical_devices as *const (),
+                    b"vkGetPhysicalDeviceProperties" => get_physical_device_properties as *const (),
+                    b"vkEnumerateDeviceExtensionProperties" => {
+                        enumerate_device_extension_properties as *const ()
+                    }
+                    _ => return None,
+                };
+                Some(std::mem::transmute::<*const (), unsafe extern "system" fn()>(function))
+            }
+
+            let static_fn = vk::StaticFn {
+                get_instance_proc_addr,
+            };
+            unsafe { ash::Instance::load(&static_fn, vk::Instance::from_raw(1)) }
+        }
+
+        #[test]
+        fn physical_device_ranking() {
+            mock::reset();
+            mock::set_feature_extension_requirements(
+                NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration,
+                &[],
+                &[("VK_NVX_binary_import", 1)],
+            );
+            let discovery = |feature| {
+                FeatureDiscoveryBuilder::new(feature)
+                    .application_identifier(ApplicationIdentifier::ApplicationId(1))
+                    .application_data_path(&std::env::temp_dir())
+            };
+            let selector = PhysicalDeviceSelector::new()
+                .feature(discovery(
+                    NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+                ))
+                .feature(discovery(
+                    NVSDK_NGX_Feature::NVSDK_NGX_Feature_FrameGeneration,
+                ))
+                .min_driver_version(520, 0);
+            let instance = fake_instance();
+
+            let candidates = selector.rank(&instance).unwrap();
+            let handles: Vec<_> = candidates
+                .iter()
+                .map(|c| c.physical_device.as_raw())
+                .collect();
+            assert_eq!(handles, vec![2, 5, 3]);
+            assert_eq!(candidates[0].name, "GPU 2");
+            assert_eq!(candidates[0].get_driver_version(), (535, 0));
+            assert!(candidates[0].is_supported());
+            assert!(candidates[1].is_supported());
+            assert!(!candidates[2].is_supported());
+            assert_eq!(candidates[2].supported_feature_count(), 1);
+            assert_eq!(
+                candidates[2].features[1].missing_extensions,
+                vec![std::ffi::CString::new("VK_NVX_binary_import").unwrap()]
+            );
+
+            let selected = selector.select(&instance).unwrap().unwrap();
+            assert_eq!(selected.physical_device.as_raw(), 2);
+
+            mock::set_feature_requirement(
+                NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+                FeatureRequirement {
+                    support: NVSDK_NGX_Feature_Support_Result::NVSDK_NGX_FeatureSupportResult_AdapterUnsupported,
+                    min_hw_architecture: 0,
+                    min_os_version: String::new(),
+                },
+            );
+            assert!(selector.select(&instance).unwrap().is_none());
+        }
+
+        #[test]
+        fn feature_discovery_requires_identification() {
+            mock::reset();
+            let result =
+                FeatureDiscoveryBuilder::new(NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling)
+                    .application_data_path(&std::env::temp_dir())
+                    .get_requirements(vk::Instance::null(), vk::PhysicalDevice::null());
+            assert!(matches!(result, Err(Error::Other(_))));
+
+            let requirement =
+                FeatureDiscoveryBuilder::new(NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling)
+                    .application_identifier(ApplicationIdentifier::ApplicationId(42))
+                    .application_data_path(&std::env::temp_dir())
+                    .get_requirements(vk::Instance::null(), vk::PhysicalDevice::null())
+                    .unwrap();
+            assert!(requirement.is_supported());
+            assert_eq!(
+                mock::discoveries()[0].identifier,
+                ApplicationIdentifier::ApplicationId(42)
+            );
+        }
+
+        #[test]
+        fn super_sampling_not_supported() {
+            mock::reset();
+            let system = mock::create_system().unwrap();
+            mock::set_result(
+                Function::CreateFeature,
+                NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_FeatureNotSupported,
+            );
+            let parameters = FeatureParameters::get_capability_parameters(&system).unwrap();
+            let settings = SuperSamplingOptimalSettings::get_optimal_settings(
+                &parameters,
+                1920,
+                1080,
+                NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_DLAA,
+            )
+            .unwrap();
+            let error = system
+                .create_super_sampling_feature(
+                    vk::CommandBuffer::null(),
+                    parameters,
+                    settings.into(),
+                )
+                .unwrap_err();
+            assert_eq!(error.without_context(), &Error::FeatureNotSupported);
+            let context = error.context().unwrap();
+            assert_eq!(context.get_function(), "NVSDK_NGX_VULKAN_CreateFeature1");
+            assert_eq!(
+                context.get_feature(),
+                Some(NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling)
+            );
+            assert_eq!(context.get_render_resolution(), Some([1920, 1080]));
+            assert_eq!(context.get_target_resolution(), Some([1920, 1080]));
+            assert_eq!(
+                context.get_quality(),
+                Some(NVSDK_NGX_PerfQuality_Value::NVSDK_NGX_PerfQuality_Value_DLAA)
+            );
+            assert!(mock::created_features().is_empty());
+            assert_eq!(mock::live_parameter_maps(), 0);
+        }
     }
 }
@@ -0,0 +1,616 @@
+//! Rust ports of the inline helpers from `nvsdk_ngx_helpers.h` and
+//! `nvsdk_ngx_helpers_vk.h`, and of their DLSSD counterparts from
+//! `nvsdk_ngx_helpers_dlssd.h` and `nvsdk_ngx_helpers_dlssd_vk.h`,
+//! built on top of the parameter map accessors.
+
+use super::{FeatureHandle, FeatureParameterName, HandleToPointer, ParameterMap};
+use crate::bindings::{
+    self, NVSDK_NGX_Coordinates, NVSDK_NGX_DLSSD_Create_Params, NVSDK_NGX_DLSS_Create_Params,
+    NVSDK_NGX_Feature, NVSDK_NGX_Feature_Create_Params, NVSDK_NGX_GBufferType, NVSDK_NGX_Parameter,
+    NVSDK_NGX_PerfQuality_Value, NVSDK_NGX_Resource_VK, NVSDK_NGX_Resource_VK_Type,
+    NVSDK_NGX_Result, NVSDK_NGX_VK_DLSSD_Eval_Params, NVSDK_NGX_VK_DLSS_Eval_Params,
+};
+use crate::error::{ErrorContext, ResultExt};
+use crate::library::Library;
+use crate::Result;
+use ash::vk;
+
+/// The optimal settings as returned by [`dlss_get_optimal_settings`]
+/// and [`dlssd_get_optimal_settings`].
+#[derive(Debug, Default, Copy, Clone)]
+pub(super) struct DlssOptimalSettings {
+    pub(super) render_optimal_width: u32,
+    pub(super) render_optimal_height: u32,
+    pub(super) render_max_width: u32,
+    pub(super) render_max_height: u32,
+    pub(super) render_min_width: u32,
+    pub(super) render_min_height: u32,
+}
+
+/// The port of `NGX_DLSS_GET_OPTIMAL_SETTINGS`.
+pub(super) fn dlss_get_optimal_settings(
+    parameters: &ParameterMap,
+    user_selected_width: u32,
+    user_selected_height: u32,
+    perf_quality_value: NVSDK_NGX_PerfQuality_Value,
+) -> Result<DlssOptimalSettings> {
+    get_optimal_settings(
+        "NGX_DLSS_GET_OPTIMAL_SETTINGS",
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+        bindings::NVSDK_NGX_Parameter_DLSSOptimalSettingsCallback,
+        parameters,
+        user_selected_width,
+        user_selected_height,
+        perf_quality_value,
+    )
+}
+
+/// The port of `NGX_DLSSD_GET_OPTIMAL_SETTINGS`.
+pub(super) fn dlssd_get_optimal_settings(
+    parameters: &ParameterMap,
+    user_selected_width: u32,
+    user_selected_height: u32,
+    perf_quality_value: NVSDK_NGX_PerfQuality_Value,
+) -> Result<DlssOptimalSettings> {
+    get_optimal_settings(
+        "NGX_DLSSD_GET_OPTIMAL_SETTINGS",
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction,
+        bindings::NVSDK_NGX_Parameter_DLSSDOptimalSettingsCallback,
+        parameters,
+        user_selected_width,
+        user_selected_height,
+        perf_quality_value,
+    )
+}
+
+/// Calls the optimal settings callback of the DLSS or DLSSD `feature`,
+/// stored in the `callback_name` parameter, on behalf of the `helper`.
+fn get_optimal_settings(
+    helper: &'static str,
+    feature: NVSDK_NGX_Feature,
+    callback_name: &FeatureParameterName,
+    parameters: &ParameterMap,
+    user_selected_width: u32,
+    user_selected_height: u32,
+    perf_quality_value: NVSDK_NGX_PerfQuality_Value,
+) -> Result<DlssOptimalSettings> {
+    type OptimalSettingsCallback =
+        unsafe extern "C" fn(*mut NVSDK_NGX_Parameter) -> NVSDK_NGX_Result;
+
+    let callback = parameters
+        .get_ptr(callback_name)
+        .unwrap_or(std::ptr::null_mut());
+    if callback.is_null() {
+        // Possibly an older DLSS which doesn't support the API.
+        return Err(
+            crate::Error::from(NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_OutOfDate)
+                .with_context(ErrorContext::new(helper).feature(feature)),
+        );
+    }
+    let callback: OptimalSettingsCallback = unsafe { std::mem::transmute(callback) };
+
+    parameters.set_u32(bindings::NVSDK_NGX_Parameter_Width, user_selected_width);
+    parameters.set_u32(bindings::NVSDK_NGX_Parameter_Height, user_selected_height);
+    parameters.set_i32(
+        bindings::NVSDK_NGX_Parameter_PerfQualityValue,
+        perf_quality_value as i32,
+    );
+    // Some older DLSS versions still expect this value to be set.
+    parameters.set_i32(bindings::NVSDK_NGX_Parameter_RTXValue, 0);
+
+    Result::from(unsafe { callback(parameters.as_ptr()) }).context(|| {
+        ErrorContext::new(helper)
+            .feature(feature)
+            .target_resolution([user_selected_width, user_selected_height])
+            .quality(perf_quality_value)
+    })?;
+
+    let render_optimal_width = parameters
+        .get_u32(bindings::NVSDK_NGX_Parameter_OutWidth)
+        .unwrap_or_default();
+    let render_optimal_height = parameters
+        .get_u32(bindings::NVSDK_NGX_Parameter_OutHeight)
+        .unwrap_or_default();
+    // The sharpness is deprecated, so it isn't read. The dynamic
+    // resolution bounds default to the optimal render size, as older
+    // versions of DLSS don't report them.
+    let get_or_optimal = |name, optimal| parameters.get_u32(name).unwrap_or(optimal);
+    Ok(DlssOptimalSettings {
+        render_optimal_width,
+        render_optimal_height,
+        render_max_width: get_or_optimal(
+            bindings::NVSDK_NGX_Parameter_DLSS_Get_Dynamic_Max_Render_Width,
+            render_optimal_width,
+        ),
+        render_max_height: get_or_optimal(
+            bindings::NVSDK_NGX_Parameter_DLSS_Get_Dynamic_Max_Render_Height,
+            render_optimal_height,
+        ),
+        render_min_width: get_or_optimal(
+            bindings::NVSDK_NGX_Parameter_DLSS_Get_Dynamic_Min_Render_Width,
+            render_optimal_width,
+        ),
+        render_min_height: get_or_optimal(
+            bindings::NVSDK_NGX_Parameter_DLSS_Get_Dynamic_Min_Render_Height,
+            render_optimal_height,
+        ),
+    })
+}
+
+/// The port of `NGX_VULKAN_CREATE_DLSS_EXT1`.
+pub(super) fn vulkan_create_dlss_ext1(
+    device: vk::Device,
+    command_buffer: vk::CommandBuffer,
+    creation_node_mask: u32,
+    visibility_node_mask: u32,
+    handle: &mut FeatureHandle,
+    parameters: &ParameterMap,
+    create_parameters: &NVSDK_NGX_DLSS_Create_Params,
+) -> Result {
+    create_dlss_feature(
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+        device,
+        command_buffer,
+        creation_node_mask,
+        visibility_node_mask,
+        handle,
+        parameters,
+        &create_parameters.Feature,
+        create_parameters.InFeatureCreateFlags,
+        create_parameters.InEnableOutputSubrects,
+    )
+}
+
+/// The port of `NGX_VULKAN_CREATE_DLSSD_EXT1`.
+pub(super) fn vulkan_create_dlssd_ext1(
+    device: vk::Device,
+    command_buffer: vk::CommandBuffer,
+    creation_node_mask: u32,
+    visibility_node_mask: u32,
+    handle: &mut FeatureHandle,
+    parameters: &ParameterMap,
+    create_parameters: &NVSDK_NGX_DLSSD_Create_Params,
+) -> Result {
+    let p = create_parameters;
+    parameters.set_i32(
+        bindings::NVSDK_NGX_Parameter_DLSS_Denoise_Mode,
+        p.InDenoiseMode as i32,
+    );
+    parameters.set_i32(
+        bindings::NVSDK_NGX_Parameter_DLSS_Roughness_Mode,
+        p.InRoughnessMode as i32,
+    );
+    parameters.set_i32(
+        bindings::NVSDK_NGX_Parameter_DLSS_Use_HW_Depth,
+        p.InUseHWDepth as i32,
+    );
+    create_dlss_feature(
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction,
+        device,
+        command_buffer,
+        creation_node_mask,
+        visibility_node_mask,
+        handle,
+        parameters,
+        &NVSDK_NGX_Feature_Create_Params {
+            InWidth: p.InWidth,
+            InHeight: p.InHeight,
+            InTargetWidth: p.InTargetWidth,
+            InTargetHeight: p.InTargetHeight,
+            InPerfQualityValue: p.InPerfQualityValue,
+        },
+        p.InFeatureCreateFlags,
+        p.InEnableOutputSubrects,
+    )
+}
+
+/// Sets the create parameters shared by DLSS and DLSSD, and creates the
+/// `feature`.
+#[allow(clippy::too_many_arguments)]
+fn create_dlss_feature(
+    feature: NVSDK_NGX_Feature,
+    device: vk::Device,
+    command_buffer: vk::CommandBuffer,
+    creation_node_mask: u32,
+    visibility_node_mask: u32,
+    handle: &mut FeatureHandle,
+    parameters: &ParameterMap,
+    create_parameters: &NVSDK_NGX_Feature_Create_Params,
+    feature_create_flags: i32,
+    enable_output_subrects: bool,
+) -> Result {
+    parameters.set_u32(
+        bindings::NVSDK_NGX_Parameter_CreationNodeMask,
+        creation_node_mask,
+    );
+    parameters.set_u32(
+        bindings::NVSDK_NGX_Parameter_VisibilityNodeMask,
+        visibility_node_mask,
+    );
+    parameters.set_u32(
+        bindings::NVSDK_NGX_Parameter_Width,
+        create_parameters.InWidth,
+    );
+    parameters.set_u32(
+        bindings::NVSDK_NGX_Parameter_Height,
+        create_parameters.InHeight,
+    );
+    parameters.set_u32(
+        bindings::NVSDK_NGX_Parameter_OutWidth,
+        create_parameters.InTargetWidth,
+    );
+    parameters.set_u32(
+        bindings::NVSDK_NGX_Parameter_OutHeight,
+        create_parameters.InTargetHeight,
+    );
+    parameters.set_i32(
+        bindings::NVSDK_NGX_Parameter_PerfQualityValue,
+        create_parameters.InPerfQualityValue as i32,
+    );
+    parameters.set_i32(
+        bindings::NVSDK_NGX_Parameter_DLSS_Feature_Create_Flags,
+        feature_create_flags,
+    );
+    parameters.set_bool(
+        bindings::NVSDK_NGX_Parameter_DLSS_Enable_Output_Subrects,
+        enable_output_subrects,
+    );
+
+    Result::from(unsafe {
+        (Library::loaded().NVSDK_NGX_VULKAN_CreateFeature1)(
+            device.as_pointer_mut(),
+            command_buffer.as_pointer_mut(),
+            feature,
+            parameters.as_ptr(),
+            &mut handle.0 as *mut _,
+        )
+    })
+    .context(|| {
+        ErrorContext::new("NVSDK_NGX_VULKAN_CreateFeature1")
+            .feature(feature)
+            .render_resolution([create_parameters.InWidth, create_parameters.InHeight])
+            .target_resolution([
+                create_parameters.InTargetWidth,
+                create_parameters.InTargetHeight,
+            ])
+            .quality(create_parameters.InPerfQualityValue)
+    })
+}
+
+/// Returns an error if the `resource` is set, but isn't an image view.
+/// The port of `NVSDK_NGX_ENSURE_VK_IMAGEVIEW`.
+fn ensure_image_view(resource: *const NVSDK_NGX_Resource_VK) -> Result {
+    match unsafe { resource.as_ref() } {
+        Some(resource)
+            if resource.Type
+                != NVSDK_NGX_Resource_VK_Type::NVSDK_NGX_RESOURCE_VK_TYPE_VK_IMAGEVIEW =>
+        {
+            Err(NVSDK_NGX_Result::NVSDK_NGX_Result_FAIL_InvalidParameter.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns `value`, or `1.0` if it is `0.0`, which is the default for
+/// the scales which are optional.
+fn non_zero_or_one(value: f32) -> f32 {
+    if value == 0.0 {
+        1.0
+    } else {
+        value
+    }
+}
+
+/// Sets the evaluation parameters which DLSS and DLSSD have under the
+/// same names in their evaluation parameters, `$p`, returning early on
+/// an invalid resource.
+macro_rules! set_dlss_evaluation_parameters {
+    ($parameters:expr, $p:expr) => {{
+        let parameters: &ParameterMap = $parameters;
+        let p = $p;
+        let attributes = &p.GBufferSurface.pInAttrib;
+
+        for resource in [
+            p.pInMotionVectors,
+            p.pInDepth,
+            p.pInTransparencyMask,
+            p.pInExposureTexture,
+            p.pInBiasCurrentColorMask,
+        ]
+        .into_iter()
+        .chain(attributes.iter().copied())
+        .chain([
+            p.pInMotionVectors3D,
+            p.pInIsParticleMask,
+            p.pInAnimatedTextureMask,
+            p.pInDepthHighRes,
+            p.pInPositionViewSpace,
+            p.pInRayTracingHitDistance,
+            p.pInMotionVectorsReflections,
+        ]) {
+            ensure_image_view(resource)?;
+        }
+
+        parameters.set_ptr(bindings::NVSDK_NGX_Parameter_Depth, p.pInDepth);
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_MotionVectors,
+            p.pInMotionVectors,
+        );
+        parameters.set_f32(
+            bindings::NVSDK_NGX_Parameter_Jitter_Offset_X,
+            p.InJitterOffsetX,
+        );
+        parameters.set_f32(
+            bindings::NVSDK_NGX_Parameter_Jitter_Offset_Y,
+            p.InJitterOffsetY,
+        );
+        parameters.set_i32(bindings::NVSDK_NGX_Parameter_Reset, p.InReset);
+        parameters.set_f32(
+            bindings::NVSDK_NGX_Parameter_MV_Scale_X,
+            non_zero_or_one(p.InMVScaleX),
+        );
+        parameters.set_f32(
+            bindings::NVSDK_NGX_Parameter_MV_Scale_Y,
+            non_zero_or_one(p.InMVScaleY),
+        );
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_TransparencyMask,
+            p.pInTransparencyMask,
+        );
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_ExposureTexture,
+            p.pInExposureTexture,
+        );
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_DLSS_Input_Bias_Current_Color_Mask,
+            p.pInBiasCurrentColorMask,
+        );
+
+        let g_buffer_attributes: [(&[u8], usize); 16] = [
+            (
+                bindings::NVSDK_NGX_Parameter_GBuffer_Albedo,
+                NVSDK_NGX_GBufferType::NVSDK_NGX_GBUFFER_ALBEDO as usize,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_GBuffer_Roughness,
+                NVSDK_NGX_GBufferType::NVSDK_NGX_GBUFFER_ROUGHNESS as usize,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_GBuffer_Metallic,
+                NVSDK_NGX_GBufferType::NVSDK_NGX_GBUFFER_METALLIC as usize,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_GBuffer_Specular,
+                NVSDK_NGX_GBufferType::NVSDK_NGX_GBUFFER_SPECULAR as usize,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_GBuffer_Subsurface,
+                NVSDK_NGX_GBufferType::NVSDK_NGX_GBUFFER_SUBSURFACE as usize,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_GBuffer_Normals,
+                NVSDK_NGX_GBufferType::NVSDK_NGX_GBUFFER_NORMALS as usize,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_GBuffer_ShadingModelId,
+                NVSDK_NGX_GBufferType::NVSDK_NGX_GBUFFER_SHADINGMODELID as usize,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_GBuffer_MaterialId,
+                NVSDK_NGX_GBufferType::NVSDK_NGX_GBUFFER_MATERIALID as usize,
+            ),
+            (bindings::NVSDK_NGX_Parameter_GBuffer_Atrrib_8, 8),
+            (bindings::NVSDK_NGX_Parameter_GBuffer_Atrrib_9, 9),
+            (bindings::NVSDK_NGX_Parameter_GBuffer_Atrrib_10, 10),
+            (bindings::NVSDK_NGX_Parameter_GBuffer_Atrrib_11, 11),
+            (bindings::NVSDK_NGX_Parameter_GBuffer_Atrrib_12, 12),
+            (bindings::NVSDK_NGX_Parameter_GBuffer_Atrrib_13, 13),
+            (bindings::NVSDK_NGX_Parameter_GBuffer_Atrrib_14, 14),
+            (bindings::NVSDK_NGX_Parameter_GBuffer_Atrrib_15, 15),
+        ];
+        for (name, index) in g_buffer_attributes {
+            parameters.set_ptr(name, attributes[index]);
+        }
+
+        parameters.set_u32(
+            bindings::NVSDK_NGX_Parameter_TonemapperType,
+            p.InToneMapperType as u32,
+        );
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_MotionVectors3D,
+            p.pInMotionVectors3D,
+        );
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_IsParticleMask,
+            p.pInIsParticleMask,
+        );
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_AnimatedTextureMask,
+            p.pInAnimatedTextureMask,
+        );
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_DepthHighRes,
+            p.pInDepthHighRes,
+        );
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_Position_ViewSpace,
+            p.pInPositionViewSpace,
+        );
+        parameters.set_f32(
+            bindings::NVSDK_NGX_Parameter_FrameTimeDeltaInMsec,
+            p.InFrameTimeDeltaInMsec,
+        );
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_RayTracingHitDistance,
+            p.pInRayTracingHitDistance,
+        );
+        parameters.set_ptr(
+            bindings::NVSDK_NGX_Parameter_MotionVectorsReflection,
+            p.pInMotionVectorsReflections,
+        );
+
+        let subrect_bases: [(&[u8], &[u8], NVSDK_NGX_Coordinates); 6] = [
+            (
+                bindings::NVSDK_NGX_Parameter_DLSS_Input_Color_Subrect_Base_X,
+                bindings::NVSDK_NGX_Parameter_DLSS_Input_Color_Subrect_Base_Y,
+                p.InColorSubrectBase,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_DLSS_Input_Depth_Subrect_Base_X,
+                bindings::NVSDK_NGX_Parameter_DLSS_Input_Depth_Subrect_Base_Y,
+                p.InDepthSubrectBase,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_DLSS_Input_MV_SubrectBase_X,
+                bindings::NVSDK_NGX_Parameter_DLSS_Input_MV_SubrectBase_Y,
+                p.InMVSubrectBase,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_DLSS_Input_Translucency_SubrectBase_X,
+                bindings::NVSDK_NGX_Parameter_DLSS_Input_Translucency_SubrectBase_Y,
+                p.InTranslucencySubrectBase,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_DLSS_Input_Bias_Current_Color_SubrectBase_X,
+                bindings::NVSDK_NGX_Parameter_DLSS_Input_Bias_Current_Color_SubrectBase_Y,
+                p.InBiasCurrentColorSubrectBase,
+            ),
+            (
+                bindings::NVSDK_NGX_Parameter_DLSS_Output_Subrect_Base_X,
+                bindings::NVSDK_NGX_Parameter_DLSS_Output_Subrect_Base_Y,
+                p.InOutputSubrectBase,
+            ),
+        ];
+        for (x_name, y_name, base) in subrect_bases {
+            parameters.set_u32(x_name, base.X);
+            parameters.set_u32(y_name, base.Y);
+        }
+
+        parameters.set_u32(
+            bindings::NVSDK_NGX_Parameter_DLSS_Render_Subrect_Dimensions_Width,
+            p.InRenderSubrectDimensions.Width,
+        );
+        parameters.set_u32(
+            bindings::NVSDK_NGX_Parameter_DLSS_Render_Subrect_Dimensions_Height,
+            p.InRenderSubrectDimensions.Height,
+        );
+        parameters.set_f32(
+            bindings::NVSDK_NGX_Parameter_DLSS_Pre_Exposure,
+            non_zero_or_one(p.InPreExposure),
+        );
+        parameters.set_f32(
+            bindings::NVSDK_NGX_Parameter_DLSS_Exposure_Scale,
+            non_zero_or_one(p.InExposureScale),
+        );
+        parameters.set_i32(
+            bindings::NVSDK_NGX_Parameter_DLSS_Indicator_Invert_X_Axis,
+            p.InIndicatorInvertXAxis,
+        );
+        parameters.set_i32(
+            bindings::NVSDK_NGX_Parameter_DLSS_Indicator_Invert_Y_Axis,
+            p.InIndicatorInvertYAxis,
+        );
+    }};
+}
+
+/// The port of `NGX_VULKAN_EVALUATE_DLSS_EXT`.
+pub(super) fn vulkan_evaluate_dlss_ext(
+    command_buffer: vk::CommandBuffer,
+    handle: &FeatureHandle,
+    parameters: &ParameterMap,
+    evaluation_parameters: &NVSDK_NGX_VK_DLSS_Eval_Params,
+) -> Result {
+    let p = evaluation_parameters;
+    for resource in [p.Feature.pInColor, p.Feature.pInOutput] {
+        ensure_image_view(resource)?;
+    }
+    set_dlss_evaluation_parameters!(parameters, p);
+
+    parameters.set_ptr(bindings::NVSDK_NGX_Parameter_Color, p.Feature.pInColor);
+    parameters.set_ptr(bindings::NVSDK_NGX_Parameter_Output, p.Feature.pInOutput);
+    parameters.set_f32(
+        bindings::NVSDK_NGX_Parameter_Sharpness,
+        p.Feature.InSharpness,
+    );
+
+    evaluate_dlss_feature(
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_SuperSampling,
+        command_buffer,
+        handle,
+        parameters,
+    )
+}
+
+/// The port of `NGX_VULKAN_EVALUATE_DLSSD_EXT`. The alpha upscaling
+/// and the subrect bases of the guide buffers aren't ported.
+pub(super) fn vulkan_evaluate_dlssd_ext(
+    command_buffer: vk::CommandBuffer,
+    handle: &FeatureHandle,
+    parameters: &ParameterMap,
+    evaluation_parameters: &NVSDK_NGX_VK_DLSSD_Eval_Params,
+) -> Result {
+    let p = evaluation_parameters;
+    for resource in [
+        p.pInColor,
+        p.pInOutput,
+        p.pInDiffuseAlbedo,
+        p.pInSpecularAlbedo,
+        p.pInNormals,
+        p.pInRoughness,
+    ] {
+        ensure_image_view(resource)?;
+    }
+    set_dlss_evaluation_parameters!(parameters, p);
+
+    parameters.set_ptr(bindings::NVSDK_NGX_Parameter_Color, p.pInColor);
+    parameters.set_ptr(bindings::NVSDK_NGX_Parameter_Output, p.pInOutput);
+    parameters.set_ptr(
+        bindings::NVSDK_NGX_Parameter_DLSS_Input_DiffuseAlbedo,
+        p.pInDiffuseAlbedo,
+    );
+    parameters.set_ptr(
+        bindings::NVSDK_NGX_Parameter_DLSS_Input_SpecularAlbedo,
+        p.pInSpecularAlbedo,
+    );
+    parameters.set_ptr(
+        bindings::NVSDK_NGX_Parameter_DLSS_Input_Normals,
+        p.pInNormals,
+    );
+    parameters.set_ptr(
+        bindings::NVSDK_NGX_Parameter_DLSS_Input_Roughness,
+        p.pInRoughness,
+    );
+    parameters.set_ptr(
+        bindings::NVSDK_NGX_Parameter_DLSS_WorldToViewMatrix,
+        p.pInWorldToViewMatrix,
+    );
+    parameters.set_ptr(
+        bindings::NVSDK_NGX_Parameter_DLSS_ViewToClipMatrix,
+        p.pInViewToClipMatrix,
+    );
+
+    evaluate_dlss_feature(
+        NVSDK_NGX_Feature::NVSDK_NGX_Feature_RayReconstruction,
+        command_buffer,
+        handle,
+        parameters,
+    )
+}
+
+/// Evaluates the DLSS or DLSSD `feature` with the parameters set.
+fn evaluate_dlss_feature(
+    feature: NVSDK_NGX_Feature,
+    command_buffer: vk::CommandBuffer,
+    handle: &FeatureHandle,
+    parameters: &ParameterMap,
+) -> Result {
+    Result::from(unsafe {
+        (Library::loaded().NVSDK_NGX_VULKAN_EvaluateFeature_C)(
+            command_buffer.as_pointer_mut(),
+            handle.0,
+            parameters.as_ptr(),
+            None,
+        )
+    })
+    .context(|| ErrorContext::new("NVSDK_NGX_VULKAN_EvaluateFeature_C").feature(feature))
+}